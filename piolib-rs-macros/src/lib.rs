@@ -0,0 +1,79 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, LitStr};
+
+use piolib_rs::pioasm::assembler::{assemble_program, PioAsmError};
+
+/// Assemble PIO source at compile time
+///
+/// Expands to a `piolib_rs::pioasm::assembler::PioStaticProgram` constant expression. The source
+/// may omit `.program`, in which case the program is named `program`.
+///
+/// ```ignore
+/// const BLINK: PioStaticProgram = pio_asm!(".side_set 1\n loop: out pins, 1 side 0 [2]\n jmp loop side 1");
+/// ```
+#[proc_macro]
+pub fn pio_asm(input: TokenStream) -> TokenStream {
+    let source = parse_macro_input!(input as LitStr);
+    match assemble_program(&source.value()) {
+        Ok(program) => {
+            let name = &program.name;
+            let instructions = &program.instructions;
+            let count = instructions.len();
+            let origin = program.origin.map_or(-1, |origin| origin as i8);
+            let pio_version = program.pio_version;
+            let wrap_target = program.wrap_target;
+            let wrap = program.wrap;
+            let bit_count = program.side_set.bit_count;
+            let optional = program.side_set.optional;
+            let pindirs = program.side_set.pindirs;
+            quote! {{
+                const INSTRUCTIONS: [u16; #count] = [#(#instructions),*];
+                ::piolib_rs::pioasm::assembler::PioStaticProgram {
+                    name: #name,
+                    program: ::piolib_rs::include::piolib::pio_program::from_parts(&INSTRUCTIONS, #origin, #pio_version),
+                    wrap_target: #wrap_target,
+                    wrap: #wrap,
+                    side_set: ::piolib_rs::include::hardware::pio_instructions::PioSideSet {
+                        bit_count: #bit_count,
+                        optional: #optional,
+                        pindirs: #pindirs,
+                    },
+                }
+            }}
+            .into()
+        }
+        Err(err) => {
+            let message = err.to_string();
+            let span = error_span(&source, &err);
+            quote::quote_spanned!(span=> ::core::compile_error!(#message)).into()
+        }
+    }
+}
+
+/// Span of the character the error points at, when the compiler can resolve spans inside literals
+fn error_span(source: &LitStr, err: &PioAsmError) -> Span {
+    let literal = source.token();
+    let repr = literal.to_string();
+    let value = source.value();
+    // only plain and raw strings without escapes map one-to-one onto the source text
+    let prefix = repr.find('"').map_or(0, |quote| quote + 1);
+    if repr.get(prefix..prefix + value.len()) != Some(value.as_str()) {
+        return source.span();
+    }
+    let line_start: usize = value
+        .split_inclusive('\n')
+        .take(err.line - 1)
+        .map(str::len)
+        .sum();
+    let column_offset: usize = value[line_start..]
+        .chars()
+        .take(err.column - 1)
+        .map(char::len_utf8)
+        .sum();
+    let offset = prefix + line_start + column_offset;
+    literal
+        .subspan(offset..offset + 1)
+        .unwrap_or_else(|| source.span())
+}
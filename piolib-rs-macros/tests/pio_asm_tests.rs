@@ -0,0 +1,51 @@
+use piolib_rs::include::hardware::pio_instructions::*;
+use piolib_rs::pioasm::assembler::{assemble_program, PioStaticProgram};
+use piolib_rs_macros::pio_asm;
+
+const BLINK: PioStaticProgram = pio_asm!(
+    ".side_set 1
+    loop:
+        out pins, 1 side 0 [2]
+        jmp loop    side 1"
+);
+
+#[test]
+fn pio_asm_without_program_directive() {
+    assert_eq!("program", BLINK.name);
+    assert_eq!(PioSideSet { bit_count: 1, optional: false, pindirs: false }, BLINK.side_set);
+    assert_eq!((0, 1), (BLINK.wrap_target, BLINK.wrap));
+    let expected = [
+        pio_encode_out(pio_src_dest::pio_pins, 1) | pio_encode_sideset(1, 0) | pio_encode_delay(2),
+        pio_encode_jmp(0) | pio_encode_sideset(1, 1),
+    ];
+    let assembled = assemble_program(".side_set 1\nloop: out pins, 1 side 0 [2]\njmp loop side 1").unwrap();
+    assert_eq!(expected.map(|i| i as u16).to_vec(), assembled.instructions);
+    assert_eq!(assembled.instructions, BLINK.program.instructions());
+    assert_eq!(None, BLINK.program.origin());
+}
+#[test]
+fn pio_asm_with_program_and_wrap() {
+    const SQUARE: PioStaticProgram = pio_asm!(
+        r"
+        .program squarewave
+        .origin 8
+            set pindirs, 1
+        .wrap_target
+            set pins, 1 [1]
+            set pins, 0
+        .wrap
+        "
+    );
+    assert_eq!("squarewave", SQUARE.name);
+    assert_eq!((1, 2), (SQUARE.wrap_target, SQUARE.wrap));
+    assert_eq!(PioSideSet::default(), SQUARE.side_set);
+    assert_eq!(Some(8), SQUARE.program.origin());
+    assert_eq!(3, SQUARE.program.length());
+}
+/// The message gives the line and column of the offending token; the span narrows to that token
+/// only where the compiler resolves spans inside literals, and is the whole literal on stable
+#[test]
+fn pio_asm_reports_errors_at_the_source() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
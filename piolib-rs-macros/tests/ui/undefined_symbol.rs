@@ -0,0 +1,12 @@
+use piolib_rs::pioasm::assembler::PioStaticProgram;
+use piolib_rs_macros::pio_asm;
+
+const BAD: PioStaticProgram = pio_asm!(
+    ".program bad
+    loop:
+        jmp nowhere"
+);
+
+fn main() {
+    let _ = BAD;
+}
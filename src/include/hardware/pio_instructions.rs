@@ -522,3 +522,378 @@ pub fn pio_encode_set(dest: pio_src_dest, value: u32) -> u32 {
 pub fn pio_encode_nop() -> u32 {
     pio_encode_mov(pio_src_dest::pio_y, pio_src_dest::pio_y)
 }
+
+/// Condition field of a `JMP` instruction (bits 7:5)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioJmpCondition {
+    Always = 0,
+    NotX = 1,
+    XDec = 2,
+    NotY = 3,
+    YDec = 4,
+    XNeY = 5,
+    Pin = 6,
+    NotOsre = 7,
+}
+
+/// Source field of a `WAIT` instruction (bits 6:5)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioWaitSource {
+    Gpio = 0,
+    Pin = 1,
+    Irq = 2,
+}
+
+/// Source field of an `IN` instruction (bits 7:5)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioInSource {
+    Pins = 0,
+    X = 1,
+    Y = 2,
+    Null = 3,
+    Isr = 6,
+    Osr = 7,
+}
+
+/// Destination field of an `OUT` instruction (bits 7:5)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioOutDestination {
+    Pins = 0,
+    X = 1,
+    Y = 2,
+    Null = 3,
+    Pindirs = 4,
+    Pc = 5,
+    Isr = 6,
+    Exec = 7,
+}
+
+/// Destination field of a `MOV` instruction (bits 7:5)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioMovDestination {
+    Pins = 0,
+    X = 1,
+    Y = 2,
+    Exec = 4,
+    Pc = 5,
+    Isr = 6,
+    Osr = 7,
+}
+
+/// Operation field of a `MOV` instruction (bits 4:3)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioMovOp {
+    None = 0,
+    Invert = 1,
+    BitReverse = 2,
+}
+
+/// Source field of a `MOV` instruction (bits 2:0)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioMovSource {
+    Pins = 0,
+    X = 1,
+    Y = 2,
+    Null = 3,
+    Status = 5,
+    Isr = 6,
+    Osr = 7,
+}
+
+/// Destination field of a `SET` instruction (bits 7:5)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PioSetDestination {
+    Pins = 0,
+    X = 1,
+    Y = 2,
+    Pindirs = 4,
+}
+
+macro_rules! pio_field_from_bits {
+    ($t: ident { $($v: ident),* $(,)? }) => {
+        impl $t {
+            fn from_bits(bits: u32) -> Option<Self> {
+                $(if bits == $t::$v as u32 {
+                    return Some($t::$v);
+                })*
+                None
+            }
+        }
+    };
+}
+pio_field_from_bits!(PioJmpCondition { Always, NotX, XDec, NotY, YDec, XNeY, Pin, NotOsre });
+pio_field_from_bits!(PioWaitSource { Gpio, Pin, Irq });
+pio_field_from_bits!(PioInSource { Pins, X, Y, Null, Isr, Osr });
+pio_field_from_bits!(PioOutDestination { Pins, X, Y, Null, Pindirs, Pc, Isr, Exec });
+pio_field_from_bits!(PioMovDestination { Pins, X, Y, Exec, Pc, Isr, Osr });
+pio_field_from_bits!(PioMovOp { None, Invert, BitReverse });
+pio_field_from_bits!(PioMovSource { Pins, X, Y, Null, Status, Isr, Osr });
+pio_field_from_bits!(PioSetDestination { Pins, X, Y, Pindirs });
+
+/// A single PIO instruction, excluding the shared delay/side-set field (bits 12:8)
+///
+/// `index` of `Wait` and `Irq` is the raw 5-bit index field, so for IRQs bit 4 selects relative
+/// addressing exactly as produced by `_pio_encode_irq`. Bit counts of `In` and `Out` are 1-32.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PioInstruction {
+    Jmp { condition: PioJmpCondition, address: u8 },
+    Wait { polarity: bool, source: PioWaitSource, index: u8 },
+    In { source: PioInSource, bit_count: u8 },
+    Out { destination: PioOutDestination, bit_count: u8 },
+    Push { if_full: bool, block: bool },
+    Pull { if_empty: bool, block: bool },
+    Mov { destination: PioMovDestination, op: PioMovOp, source: PioMovSource },
+    Irq { clear: bool, wait: bool, index: u8 },
+    Set { destination: PioSetDestination, data: u8 },
+}
+
+impl PioInstruction {
+    /// Whether every field fits its encoding: addresses, indexes and SET data below 32, and bit
+    /// counts 1-32
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            PioInstruction::Jmp { address, .. } => address < 32,
+            PioInstruction::Wait { index, .. } | PioInstruction::Irq { index, .. } => index < 32,
+            PioInstruction::In { bit_count, .. } | PioInstruction::Out { bit_count, .. } => {
+                (1..=32).contains(&bit_count)
+            }
+            PioInstruction::Set { data, .. } => data < 32,
+            PioInstruction::Push { .. } | PioInstruction::Pull { .. } | PioInstruction::Mov { .. } => true,
+        }
+    }
+
+    /// Encode the instruction with 0 delay and no side set value
+    ///
+    /// Fields out of range (see [`is_valid`](Self::is_valid)) are truncated to their bits unless
+    /// the PIO_INSTRUCTIONS parameter assertions are enabled; [`try_encode`](Self::try_encode)
+    /// checks them instead.
+    pub fn encode(&self) -> u16 {
+        let encoded = match *self {
+            PioInstruction::Jmp { condition, address } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_jmp,
+                condition as u32,
+                address as u32,
+            ),
+            PioInstruction::Wait { polarity, source, index } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_wait,
+                crate::bool_to_bit!(polarity) << 2 | source as u32,
+                index as u32,
+            ),
+            PioInstruction::In { source, bit_count } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_in,
+                source as u32,
+                bit_count as u32,
+            ),
+            PioInstruction::Out { destination, bit_count } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_out,
+                destination as u32,
+                bit_count as u32,
+            ),
+            PioInstruction::Push { if_full, block } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_push,
+                crate::bool_to_bit!(if_full) << 1 | crate::bool_to_bit!(block),
+                0,
+            ),
+            PioInstruction::Pull { if_empty, block } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_pull,
+                crate::bool_to_bit!(if_empty) << 1 | crate::bool_to_bit!(block),
+                0,
+            ),
+            PioInstruction::Mov { destination, op, source } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_mov,
+                destination as u32,
+                (op as u32) << 3 | source as u32,
+            ),
+            PioInstruction::Irq { clear, wait, index } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_irq,
+                crate::bool_to_bit!(clear) << 1 | crate::bool_to_bit!(wait),
+                index as u32,
+            ),
+            PioInstruction::Set { destination, data } => _pio_encode_instr_and_args(
+                pio_instr_bits::pio_instr_bits_set,
+                destination as u32,
+                data as u32,
+            ),
+        };
+        encoded as u16
+    }
+
+    /// Encode the instruction, or `None` if a field is out of range
+    pub fn try_encode(&self) -> Option<u16> {
+        self.is_valid().then(|| self.encode())
+    }
+
+    /// Decode the instruction part of `instr`, ignoring the delay/side-set field
+    ///
+    /// Returns `None` if any field holds a reserved value.
+    pub fn decode(instr: u16) -> Option<Self> {
+        let instr = instr as u32;
+        let major = _pio_major_instr_bits(instr);
+        let arg1 = (instr >> 5) & 0x7;
+        let arg2 = instr & 0x1f;
+        let bit = |n: u32| (instr >> n) & 1 != 0;
+        let bit_count = if arg2 == 0 { 32 } else { arg2 as u8 };
+        if major == pio_instr_bits::pio_instr_bits_jmp as u32 {
+            Some(PioInstruction::Jmp {
+                condition: PioJmpCondition::from_bits(arg1)?,
+                address: arg2 as u8,
+            })
+        } else if major == pio_instr_bits::pio_instr_bits_wait as u32 {
+            Some(PioInstruction::Wait {
+                polarity: bit(7),
+                source: PioWaitSource::from_bits(arg1 & 0x3)?,
+                index: arg2 as u8,
+            })
+        } else if major == pio_instr_bits::pio_instr_bits_in as u32 {
+            Some(PioInstruction::In { source: PioInSource::from_bits(arg1)?, bit_count })
+        } else if major == pio_instr_bits::pio_instr_bits_out as u32 {
+            Some(PioInstruction::Out { destination: PioOutDestination::from_bits(arg1)?, bit_count })
+        } else if major == pio_instr_bits::pio_instr_bits_push as u32 {
+            if arg2 != 0 {
+                None
+            } else if bit(7) {
+                Some(PioInstruction::Pull { if_empty: bit(6), block: bit(5) })
+            } else {
+                Some(PioInstruction::Push { if_full: bit(6), block: bit(5) })
+            }
+        } else if major == pio_instr_bits::pio_instr_bits_mov as u32 {
+            Some(PioInstruction::Mov {
+                destination: PioMovDestination::from_bits(arg1)?,
+                op: PioMovOp::from_bits(arg2 >> 3)?,
+                source: PioMovSource::from_bits(arg2 & 0x7)?,
+            })
+        } else if major == pio_instr_bits::pio_instr_bits_irq as u32 {
+            if bit(7) {
+                None
+            } else {
+                Some(PioInstruction::Irq { clear: bit(6), wait: bit(5), index: arg2 as u8 })
+            }
+        } else {
+            Some(PioInstruction::Set {
+                destination: PioSetDestination::from_bits(arg1)?,
+                data: arg2 as u8,
+            })
+        }
+    }
+}
+
+/// Side-set configuration of a program, as would be specified via `.side_set` in pioasm
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PioSideSet {
+    /// number of side set bits, not including the enable bit of an optional side set
+    pub bit_count: u8,
+    pub optional: bool,
+    pub pindirs: bool,
+}
+
+impl PioSideSet {
+    /// Number of bits of the delay/side-set field used for side set, including the enable bit
+    pub fn total_bit_count(&self) -> u8 {
+        self.bit_count + self.optional as u8
+    }
+
+    /// Largest delay that can be encoded alongside this side set, 0 if it takes more than 5 bits
+    pub fn max_delay(&self) -> u8 {
+        0x1fu8.checked_shr(self.total_bit_count() as u32).unwrap_or(0)
+    }
+}
+
+/// A complete 16-bit instruction word: the instruction plus its raw delay/side-set field
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PioInstructionWord {
+    pub instruction: PioInstruction,
+    /// bits 12:8 of the instruction; see `PioSideSet` for how they are split
+    pub delay_side_set: u8,
+}
+
+impl PioInstructionWord {
+    pub fn new(instruction: PioInstruction) -> Self {
+        PioInstructionWord { instruction, delay_side_set: 0 }
+    }
+
+    /// Build a word from a delay and an optional side set value under the given side-set configuration
+    ///
+    /// A delay or side set value too wide for `side_set` is truncated unless the PIO_INSTRUCTIONS
+    /// parameter assertions are enabled; [`try_with_delay_side_set`](Self::try_with_delay_side_set)
+    /// checks them instead.
+    pub fn with_delay_side_set(
+        instruction: PioInstruction,
+        side_set: &PioSideSet,
+        delay: u8,
+        side_set_value: Option<u8>,
+    ) -> Self {
+        crate::valid_params_if!(PIO_INSTRUCTIONS, delay <= side_set.max_delay());
+        crate::valid_params_if!(PIO_INSTRUCTIONS, side_set.optional || side_set.bit_count == 0 || side_set_value.is_some());
+        let mut bits = delay as u32;
+        if let Some(value) = side_set_value {
+            crate::valid_params_if!(PIO_INSTRUCTIONS, side_set.bit_count > 0);
+            bits |= if side_set.optional {
+                pio_encode_sideset_opt(side_set.bit_count as u32, value as u32)
+            } else {
+                pio_encode_sideset(side_set.bit_count as u32, value as u32)
+            } >> 8;
+        }
+        PioInstructionWord { instruction, delay_side_set: bits as u8 }
+    }
+
+    /// Like [`with_delay_side_set`](Self::with_delay_side_set), but `None` if the instruction,
+    /// delay or side set value does not fit
+    pub fn try_with_delay_side_set(
+        instruction: PioInstruction,
+        side_set: &PioSideSet,
+        delay: u8,
+        side_set_value: Option<u8>,
+    ) -> Option<Self> {
+        let side_set_fits = match side_set_value {
+            Some(value) => side_set.bit_count > 0 && (value as u32) < 1 << side_set.bit_count,
+            None => side_set.optional || side_set.bit_count == 0,
+        };
+        (instruction.is_valid() && side_set.total_bit_count() <= 5 && delay <= side_set.max_delay() && side_set_fits)
+            .then(|| Self::with_delay_side_set(instruction, side_set, delay, side_set_value))
+    }
+
+    /// Encode the word; out of range fields are truncated as by [`PioInstruction::encode`], and
+    /// only the low 5 bits of `delay_side_set` are used
+    pub fn encode(&self) -> u16 {
+        self.instruction.encode() | (pio_encode_delay(self.delay_side_set as u32 & 0x1f) as u16)
+    }
+
+    /// Encode the word, or `None` if a field is out of range
+    pub fn try_encode(&self) -> Option<u16> {
+        (self.delay_side_set < 32).then_some(())?;
+        self.instruction.try_encode().map(|_| self.encode())
+    }
+
+    /// Decode a word; returns `None` if it uses a reserved encoding
+    pub fn decode(instr: u16) -> Option<Self> {
+        Some(PioInstructionWord {
+            instruction: PioInstruction::decode(instr)?,
+            delay_side_set: ((instr >> 8) & 0x1f) as u8,
+        })
+    }
+
+    /// Side set value carried by this word, or `None` if there is none (or the optional enable bit is clear)
+    ///
+    /// A side set of more than 5 bits cannot be encoded, so it also gives `None`.
+    pub fn side_set(&self, side_set: &PioSideSet) -> Option<u8> {
+        let shift = 5u8.checked_sub(side_set.total_bit_count())?;
+        if side_set.bit_count == 0 || (side_set.optional && self.delay_side_set & 0x10 == 0) {
+            return None;
+        }
+        let value = self.delay_side_set >> shift;
+        Some(value & ((1 << side_set.bit_count) - 1))
+    }
+
+    /// Delay cycles carried by this word; 0 when a side set of more than 5 bits leaves no room
+    pub fn delay(&self, side_set: &PioSideSet) -> u8 {
+        self.delay_side_set & side_set.max_delay()
+    }
+}
@@ -0,0 +1,126 @@
+//! Descriptions of registers and their fields, the types of the `*_ACCESS` constants and
+//! register tables of the generated `regs` modules
+
+use std::fmt;
+
+/// How software may access a register field, the `*_ACCESS` constants of the `regs` modules
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Read-only
+    RO,
+    /// Read-write
+    RW,
+    /// Write-only
+    WO,
+    /// Self-clearing: writing 1 triggers an action, reads return 0
+    SC,
+    /// Write 1 to clear
+    WC,
+    /// Reading pops a FIFO
+    RF,
+    /// Writing pushes to a FIFO or forces a flag
+    WF,
+}
+
+impl Access {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Access::RO => "RO",
+            Access::RW => "RW",
+            Access::WO => "WO",
+            Access::SC => "SC",
+            Access::WC => "WC",
+            Access::RF => "RF",
+            Access::WF => "WF",
+        }
+    }
+
+    /// Whether reading returns the field's state
+    pub const fn is_readable(self) -> bool {
+        matches!(self, Access::RO | Access::RW | Access::WC | Access::RF)
+    }
+
+    /// Whether writing has any effect
+    pub const fn is_writable(self) -> bool {
+        !matches!(self, Access::RO | Access::RF)
+    }
+
+    /// Whether an access changes state beyond storing the written value
+    pub const fn has_side_effects(self) -> bool {
+        matches!(self, Access::SC | Access::WC | Access::RF | Access::WF)
+    }
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A field of a [`RegisterInfo`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Name without the register prefix, e.g. `"WRAP_TOP"`
+    pub name: &'static str,
+    pub bits: u32,
+    pub msb: u32,
+    pub lsb: u32,
+    /// `None` where the reset value is undefined, such as FIFO contents
+    pub reset: Option<u32>,
+    pub access: Access,
+    pub description: &'static str,
+}
+
+impl FieldInfo {
+    /// The field's value within a whole register value
+    pub const fn get(&self, value: u32) -> u32 {
+        (value & self.bits) >> self.lsb
+    }
+
+    pub const fn width(&self) -> u32 {
+        self.msb - self.lsb + 1
+    }
+}
+
+/// A register of a static table such as `PROC_PIO_REGISTERS`, for dumpers and checkers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegisterInfo {
+    /// Name without the `PROC_PIO_` prefix, e.g. `"SM0_EXECCTRL"`
+    pub name: &'static str,
+    pub offset: u32,
+    /// Mask of all the implemented bits
+    pub bits: u32,
+    /// `None` where the reset value is undefined
+    pub reset: Option<u32>,
+    pub description: &'static str,
+    /// Fields from the most significant down; registers without fields list themselves
+    pub fields: &'static [FieldInfo],
+}
+
+impl RegisterInfo {
+    pub fn field(&self, name: &str) -> Option<&'static FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Mask of the bits a write can change
+    pub fn writable_bits(&self) -> u32 {
+        self.fields
+            .iter()
+            .filter(|field| field.access.is_writable())
+            .fold(0, |bits, field| bits | field.bits)
+    }
+
+    /// Fields that `value` sets bits in but that cannot be written
+    pub fn unwritable_fields(&self, value: u32) -> impl Iterator<Item = &'static FieldInfo> {
+        self.fields
+            .iter()
+            .filter(move |field| !field.access.is_writable() && field.get(value) != 0)
+    }
+
+    /// Fields with a defined reset value that `value` does not match
+    pub fn reset_mismatches(&self, value: u32) -> impl Iterator<Item = &'static FieldInfo> {
+        self.fields
+            .iter()
+            .filter(move |field| field.reset.is_some_and(|reset| field.get(value) != reset))
+    }
+}
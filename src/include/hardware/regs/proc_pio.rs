@@ -3,6 +3,9 @@
 // Version        : 1
 // Bus type       : ahbl
 // Description    : Programmable IO block
+
+use super::info::{Access, FieldInfo, RegisterInfo};
+
 // =============================================================================
 // Register    : PROC_PIO_CTRL
 // Description : PIO control register
@@ -19,7 +22,7 @@ pub const PROC_PIO_CTRL_CLKDIV_RESTART_RESET: u32 = 0x0;
 pub const PROC_PIO_CTRL_CLKDIV_RESTART_BITS: u32 = 0x00000f00;
 pub const PROC_PIO_CTRL_CLKDIV_RESTART_MSB: u32 = 11;
 pub const PROC_PIO_CTRL_CLKDIV_RESTART_LSB: u32 = 8;
-pub const PROC_PIO_CTRL_CLKDIV_RESTART_ACCESS: &str = "SC";
+pub const PROC_PIO_CTRL_CLKDIV_RESTART_ACCESS: Access = Access::SC;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_CTRL_SM_RESTART
 // Description : Clear internal SM state which is otherwise difficult to access
@@ -28,7 +31,7 @@ pub const PROC_PIO_CTRL_SM_RESTART_RESET: u32 = 0x0;
 pub const PROC_PIO_CTRL_SM_RESTART_BITS: u32 = 0x000000f0;
 pub const PROC_PIO_CTRL_SM_RESTART_MSB: u32 = 7;
 pub const PROC_PIO_CTRL_SM_RESTART_LSB: u32 = 4;
-pub const PROC_PIO_CTRL_SM_RESTART_ACCESS: &str = "SC";
+pub const PROC_PIO_CTRL_SM_RESTART_ACCESS: Access = Access::SC;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_CTRL_SM_ENABLE
 // Description : Enable state machine
@@ -36,7 +39,7 @@ pub const PROC_PIO_CTRL_SM_ENABLE_RESET: u32 = 0x0;
 pub const PROC_PIO_CTRL_SM_ENABLE_BITS: u32 = 0x0000000f;
 pub const PROC_PIO_CTRL_SM_ENABLE_MSB: u32 = 3;
 pub const PROC_PIO_CTRL_SM_ENABLE_LSB: u32 = 0;
-pub const PROC_PIO_CTRL_SM_ENABLE_ACCESS: &str = "RW";
+pub const PROC_PIO_CTRL_SM_ENABLE_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_FSTAT
 // Description : FIFO status register
@@ -51,7 +54,7 @@ pub const PROC_PIO_FSTAT_TXEMPTY_RESET: u32 = 0xf;
 pub const PROC_PIO_FSTAT_TXEMPTY_BITS: u32 = 0x0f000000;
 pub const PROC_PIO_FSTAT_TXEMPTY_MSB: u32 = 27;
 pub const PROC_PIO_FSTAT_TXEMPTY_LSB: u32 = 24;
-pub const PROC_PIO_FSTAT_TXEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_FSTAT_TXEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FSTAT_TXFULL
 // Description : State machine TX FIFO is full
@@ -59,7 +62,7 @@ pub const PROC_PIO_FSTAT_TXFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_FSTAT_TXFULL_BITS: u32 = 0x000f0000;
 pub const PROC_PIO_FSTAT_TXFULL_MSB: u32 = 19;
 pub const PROC_PIO_FSTAT_TXFULL_LSB: u32 = 16;
-pub const PROC_PIO_FSTAT_TXFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_FSTAT_TXFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FSTAT_RXEMPTY
 // Description : State machine RX FIFO is empty
@@ -67,7 +70,7 @@ pub const PROC_PIO_FSTAT_RXEMPTY_RESET: u32 = 0xf;
 pub const PROC_PIO_FSTAT_RXEMPTY_BITS: u32 = 0x00000f00;
 pub const PROC_PIO_FSTAT_RXEMPTY_MSB: u32 = 11;
 pub const PROC_PIO_FSTAT_RXEMPTY_LSB: u32 = 8;
-pub const PROC_PIO_FSTAT_RXEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_FSTAT_RXEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FSTAT_RXFULL
 // Description : State machine RX FIFO is full
@@ -75,7 +78,7 @@ pub const PROC_PIO_FSTAT_RXFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_FSTAT_RXFULL_BITS: u32 = 0x0000000f;
 pub const PROC_PIO_FSTAT_RXFULL_MSB: u32 = 3;
 pub const PROC_PIO_FSTAT_RXFULL_LSB: u32 = 0;
-pub const PROC_PIO_FSTAT_RXFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_FSTAT_RXFULL_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_FDEBUG
 // Description : FIFO debug register
@@ -90,7 +93,7 @@ pub const PROC_PIO_FDEBUG_TXSTALL_RESET: u32 = 0x0;
 pub const PROC_PIO_FDEBUG_TXSTALL_BITS: u32 = 0x0f000000;
 pub const PROC_PIO_FDEBUG_TXSTALL_MSB: u32 = 27;
 pub const PROC_PIO_FDEBUG_TXSTALL_LSB: u32 = 24;
-pub const PROC_PIO_FDEBUG_TXSTALL_ACCESS: &str = "WC";
+pub const PROC_PIO_FDEBUG_TXSTALL_ACCESS: Access = Access::WC;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FDEBUG_TXOVER
 // Description : TX FIFO overflow has occurred. Write 1 to clear.
@@ -98,7 +101,7 @@ pub const PROC_PIO_FDEBUG_TXOVER_RESET: u32 = 0x0;
 pub const PROC_PIO_FDEBUG_TXOVER_BITS: u32 = 0x000f0000;
 pub const PROC_PIO_FDEBUG_TXOVER_MSB: u32 = 19;
 pub const PROC_PIO_FDEBUG_TXOVER_LSB: u32 = 16;
-pub const PROC_PIO_FDEBUG_TXOVER_ACCESS: &str = "WC";
+pub const PROC_PIO_FDEBUG_TXOVER_ACCESS: Access = Access::WC;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FDEBUG_RXUNDER
 // Description : RX FIFO underflow has occurred. Write 1 to clear.
@@ -106,7 +109,7 @@ pub const PROC_PIO_FDEBUG_RXUNDER_RESET: u32 = 0x0;
 pub const PROC_PIO_FDEBUG_RXUNDER_BITS: u32 = 0x00000f00;
 pub const PROC_PIO_FDEBUG_RXUNDER_MSB: u32 = 11;
 pub const PROC_PIO_FDEBUG_RXUNDER_LSB: u32 = 8;
-pub const PROC_PIO_FDEBUG_RXUNDER_ACCESS: &str = "WC";
+pub const PROC_PIO_FDEBUG_RXUNDER_ACCESS: Access = Access::WC;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FDEBUG_RXSTALL
 // Description : State machine has stalled on full RX FIFO. Write 1 to clear.
@@ -114,7 +117,7 @@ pub const PROC_PIO_FDEBUG_RXSTALL_RESET: u32 = 0x0;
 pub const PROC_PIO_FDEBUG_RXSTALL_BITS: u32 = 0x0000000f;
 pub const PROC_PIO_FDEBUG_RXSTALL_MSB: u32 = 3;
 pub const PROC_PIO_FDEBUG_RXSTALL_LSB: u32 = 0;
-pub const PROC_PIO_FDEBUG_RXSTALL_ACCESS: &str = "WC";
+pub const PROC_PIO_FDEBUG_RXSTALL_ACCESS: Access = Access::WC;
 // =============================================================================
 // Register    : PROC_PIO_FLEVEL
 // Description : FIFO levels
@@ -131,7 +134,7 @@ pub const PROC_PIO_FLEVEL_RX3_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_RX3_BITS: u32 = 0xf0000000;
 pub const PROC_PIO_FLEVEL_RX3_MSB: u32 = 31;
 pub const PROC_PIO_FLEVEL_RX3_LSB: u32 = 28;
-pub const PROC_PIO_FLEVEL_RX3_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_RX3_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL_TX3
 // Description : None
@@ -139,7 +142,7 @@ pub const PROC_PIO_FLEVEL_TX3_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_TX3_BITS: u32 = 0x0f000000;
 pub const PROC_PIO_FLEVEL_TX3_MSB: u32 = 27;
 pub const PROC_PIO_FLEVEL_TX3_LSB: u32 = 24;
-pub const PROC_PIO_FLEVEL_TX3_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_TX3_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL_RX2
 // Description : None
@@ -147,7 +150,7 @@ pub const PROC_PIO_FLEVEL_RX2_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_RX2_BITS: u32 = 0x00f00000;
 pub const PROC_PIO_FLEVEL_RX2_MSB: u32 = 23;
 pub const PROC_PIO_FLEVEL_RX2_LSB: u32 = 20;
-pub const PROC_PIO_FLEVEL_RX2_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_RX2_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL_TX2
 // Description : None
@@ -155,7 +158,7 @@ pub const PROC_PIO_FLEVEL_TX2_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_TX2_BITS: u32 = 0x000f0000;
 pub const PROC_PIO_FLEVEL_TX2_MSB: u32 = 19;
 pub const PROC_PIO_FLEVEL_TX2_LSB: u32 = 16;
-pub const PROC_PIO_FLEVEL_TX2_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_TX2_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL_RX1
 // Description : None
@@ -163,7 +166,7 @@ pub const PROC_PIO_FLEVEL_RX1_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_RX1_BITS: u32 = 0x0000f000;
 pub const PROC_PIO_FLEVEL_RX1_MSB: u32 = 15;
 pub const PROC_PIO_FLEVEL_RX1_LSB: u32 = 12;
-pub const PROC_PIO_FLEVEL_RX1_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_RX1_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL_TX1
 // Description : None
@@ -171,7 +174,7 @@ pub const PROC_PIO_FLEVEL_TX1_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_TX1_BITS: u32 = 0x00000f00;
 pub const PROC_PIO_FLEVEL_TX1_MSB: u32 = 11;
 pub const PROC_PIO_FLEVEL_TX1_LSB: u32 = 8;
-pub const PROC_PIO_FLEVEL_TX1_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_TX1_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL_RX0
 // Description : None
@@ -179,7 +182,7 @@ pub const PROC_PIO_FLEVEL_RX0_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_RX0_BITS: u32 = 0x000000f0;
 pub const PROC_PIO_FLEVEL_RX0_MSB: u32 = 7;
 pub const PROC_PIO_FLEVEL_RX0_LSB: u32 = 4;
-pub const PROC_PIO_FLEVEL_RX0_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_RX0_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL_TX0
 // Description : None
@@ -187,7 +190,7 @@ pub const PROC_PIO_FLEVEL_TX0_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL_TX0_BITS: u32 = 0x0000000f;
 pub const PROC_PIO_FLEVEL_TX0_MSB: u32 = 3;
 pub const PROC_PIO_FLEVEL_TX0_LSB: u32 = 0;
-pub const PROC_PIO_FLEVEL_TX0_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL_TX0_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_FLEVEL2
 // Description : FIFO level extra bits
@@ -204,7 +207,7 @@ pub const PROC_PIO_FLEVEL2_RX3_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_RX3_BITS: u32 = 0x10000000;
 pub const PROC_PIO_FLEVEL2_RX3_MSB: u32 = 28;
 pub const PROC_PIO_FLEVEL2_RX3_LSB: u32 = 28;
-pub const PROC_PIO_FLEVEL2_RX3_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_RX3_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL2_TX3
 // Description : None
@@ -212,7 +215,7 @@ pub const PROC_PIO_FLEVEL2_TX3_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_TX3_BITS: u32 = 0x01000000;
 pub const PROC_PIO_FLEVEL2_TX3_MSB: u32 = 24;
 pub const PROC_PIO_FLEVEL2_TX3_LSB: u32 = 24;
-pub const PROC_PIO_FLEVEL2_TX3_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_TX3_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL2_RX2
 // Description : None
@@ -220,7 +223,7 @@ pub const PROC_PIO_FLEVEL2_RX2_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_RX2_BITS: u32 = 0x00100000;
 pub const PROC_PIO_FLEVEL2_RX2_MSB: u32 = 20;
 pub const PROC_PIO_FLEVEL2_RX2_LSB: u32 = 20;
-pub const PROC_PIO_FLEVEL2_RX2_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_RX2_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL2_TX2
 // Description : None
@@ -228,7 +231,7 @@ pub const PROC_PIO_FLEVEL2_TX2_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_TX2_BITS: u32 = 0x00010000;
 pub const PROC_PIO_FLEVEL2_TX2_MSB: u32 = 16;
 pub const PROC_PIO_FLEVEL2_TX2_LSB: u32 = 16;
-pub const PROC_PIO_FLEVEL2_TX2_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_TX2_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL2_RX1
 // Description : None
@@ -236,7 +239,7 @@ pub const PROC_PIO_FLEVEL2_RX1_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_RX1_BITS: u32 = 0x00001000;
 pub const PROC_PIO_FLEVEL2_RX1_MSB: u32 = 12;
 pub const PROC_PIO_FLEVEL2_RX1_LSB: u32 = 12;
-pub const PROC_PIO_FLEVEL2_RX1_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_RX1_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL2_TX1
 // Description : None
@@ -244,7 +247,7 @@ pub const PROC_PIO_FLEVEL2_TX1_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_TX1_BITS: u32 = 0x00000100;
 pub const PROC_PIO_FLEVEL2_TX1_MSB: u32 = 8;
 pub const PROC_PIO_FLEVEL2_TX1_LSB: u32 = 8;
-pub const PROC_PIO_FLEVEL2_TX1_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_TX1_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL2_RX0
 // Description : None
@@ -252,7 +255,7 @@ pub const PROC_PIO_FLEVEL2_RX0_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_RX0_BITS: u32 = 0x00000010;
 pub const PROC_PIO_FLEVEL2_RX0_MSB: u32 = 4;
 pub const PROC_PIO_FLEVEL2_RX0_LSB: u32 = 4;
-pub const PROC_PIO_FLEVEL2_RX0_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_RX0_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_FLEVEL2_TX0
 // Description : None
@@ -260,7 +263,7 @@ pub const PROC_PIO_FLEVEL2_TX0_RESET: u32 = 0x0;
 pub const PROC_PIO_FLEVEL2_TX0_BITS: u32 = 0x00000001;
 pub const PROC_PIO_FLEVEL2_TX0_MSB: u32 = 0;
 pub const PROC_PIO_FLEVEL2_TX0_LSB: u32 = 0;
-pub const PROC_PIO_FLEVEL2_TX0_ACCESS: &str = "RO";
+pub const PROC_PIO_FLEVEL2_TX0_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_TXF0
 // Description : Direct write access to the TX FIFO for this state machine. Each
@@ -271,7 +274,7 @@ pub const PROC_PIO_TXF0_RESET: u32 = 0x00000000;
 pub const PROC_PIO_TXF0_WIDTH: u32 = 32;
 pub const PROC_PIO_TXF0_MSB: u32 = 31;
 pub const PROC_PIO_TXF0_LSB: u32 = 0;
-pub const PROC_PIO_TXF0_ACCESS: &str = "WF";
+pub const PROC_PIO_TXF0_ACCESS: Access = Access::WF;
 // =============================================================================
 // Register    : PROC_PIO_TXF1
 // Description : Direct write access to the TX FIFO for this state machine. Each
@@ -282,7 +285,7 @@ pub const PROC_PIO_TXF1_RESET: u32 = 0x00000000;
 pub const PROC_PIO_TXF1_WIDTH: u32 = 32;
 pub const PROC_PIO_TXF1_MSB: u32 = 31;
 pub const PROC_PIO_TXF1_LSB: u32 = 0;
-pub const PROC_PIO_TXF1_ACCESS: &str = "WF";
+pub const PROC_PIO_TXF1_ACCESS: Access = Access::WF;
 // =============================================================================
 // Register    : PROC_PIO_TXF2
 // Description : Direct write access to the TX FIFO for this state machine. Each
@@ -293,7 +296,7 @@ pub const PROC_PIO_TXF2_RESET: u32 = 0x00000000;
 pub const PROC_PIO_TXF2_WIDTH: u32 = 32;
 pub const PROC_PIO_TXF2_MSB: u32 = 31;
 pub const PROC_PIO_TXF2_LSB: u32 = 0;
-pub const PROC_PIO_TXF2_ACCESS: &str = "WF";
+pub const PROC_PIO_TXF2_ACCESS: Access = Access::WF;
 // =============================================================================
 // Register    : PROC_PIO_TXF3
 // Description : Direct write access to the TX FIFO for this state machine. Each
@@ -304,7 +307,7 @@ pub const PROC_PIO_TXF3_RESET: u32 = 0x00000000;
 pub const PROC_PIO_TXF3_WIDTH: u32 = 32;
 pub const PROC_PIO_TXF3_MSB: u32 = 31;
 pub const PROC_PIO_TXF3_LSB: u32 = 0;
-pub const PROC_PIO_TXF3_ACCESS: &str = "WF";
+pub const PROC_PIO_TXF3_ACCESS: Access = Access::WF;
 // =============================================================================
 // Register    : PROC_PIO_RXF0
 // Description : Direct read access to the RX FIFO for this state machine. Each
@@ -315,7 +318,7 @@ pub const PROC_PIO_RXF0_RESET: &str = "-";
 pub const PROC_PIO_RXF0_WIDTH: u32 = 32;
 pub const PROC_PIO_RXF0_MSB: u32 = 31;
 pub const PROC_PIO_RXF0_LSB: u32 = 0;
-pub const PROC_PIO_RXF0_ACCESS: &str = "RF";
+pub const PROC_PIO_RXF0_ACCESS: Access = Access::RF;
 // =============================================================================
 // Register    : PROC_PIO_RXF1
 // Description : Direct read access to the RX FIFO for this state machine. Each
@@ -326,7 +329,7 @@ pub const PROC_PIO_RXF1_RESET: &str = "-";
 pub const PROC_PIO_RXF1_WIDTH: u32 = 32;
 pub const PROC_PIO_RXF1_MSB: u32 = 31;
 pub const PROC_PIO_RXF1_LSB: u32 = 0;
-pub const PROC_PIO_RXF1_ACCESS: &str = "RF";
+pub const PROC_PIO_RXF1_ACCESS: Access = Access::RF;
 // =============================================================================
 // Register    : PROC_PIO_RXF2
 // Description : Direct read access to the RX FIFO for this state machine. Each
@@ -337,7 +340,7 @@ pub const PROC_PIO_RXF2_RESET: &str = "-";
 pub const PROC_PIO_RXF2_WIDTH: u32 = 32;
 pub const PROC_PIO_RXF2_MSB: u32 = 31;
 pub const PROC_PIO_RXF2_LSB: u32 = 0;
-pub const PROC_PIO_RXF2_ACCESS: &str = "RF";
+pub const PROC_PIO_RXF2_ACCESS: Access = Access::RF;
 // =============================================================================
 // Register    : PROC_PIO_RXF3
 // Description : Direct read access to the RX FIFO for this state machine. Each
@@ -348,7 +351,7 @@ pub const PROC_PIO_RXF3_RESET: &str = "-";
 pub const PROC_PIO_RXF3_WIDTH: u32 = 32;
 pub const PROC_PIO_RXF3_MSB: u32 = 31;
 pub const PROC_PIO_RXF3_LSB: u32 = 0;
-pub const PROC_PIO_RXF3_ACCESS: &str = "RF";
+pub const PROC_PIO_RXF3_ACCESS: Access = Access::RF;
 // =============================================================================
 // Register    : PROC_PIO_IRQ
 // Description : Interrupt request register. Write 1 to clear
@@ -358,7 +361,7 @@ pub const PROC_PIO_IRQ_RESET: u32 = 0x00000000;
 pub const PROC_PIO_IRQ_WIDTH: u32 = 32;
 pub const PROC_PIO_IRQ_MSB: u32 = 7;
 pub const PROC_PIO_IRQ_LSB: u32 = 0;
-pub const PROC_PIO_IRQ_ACCESS: &str = "WC";
+pub const PROC_PIO_IRQ_ACCESS: Access = Access::WC;
 // =============================================================================
 // Register    : PROC_PIO_IRQ_FORCE
 // Description : Writing a 1 to each of these bits will forcibly assert the
@@ -374,7 +377,7 @@ pub const PROC_PIO_IRQ_FORCE_RESET: u32 = 0x00000000;
 pub const PROC_PIO_IRQ_FORCE_WIDTH: u32 = 32;
 pub const PROC_PIO_IRQ_FORCE_MSB: u32 = 7;
 pub const PROC_PIO_IRQ_FORCE_LSB: u32 = 0;
-pub const PROC_PIO_IRQ_FORCE_ACCESS: &str = "WF";
+pub const PROC_PIO_IRQ_FORCE_ACCESS: Access = Access::WF;
 // =============================================================================
 // Register    : PROC_PIO_INPUT_SYNC_BYPASS
 // Description : There is a 2-flipflop synchronizer on each GPIO input, which
@@ -393,7 +396,7 @@ pub const PROC_PIO_INPUT_SYNC_BYPASS_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INPUT_SYNC_BYPASS_WIDTH: u32 = 32;
 pub const PROC_PIO_INPUT_SYNC_BYPASS_MSB: u32 = 31;
 pub const PROC_PIO_INPUT_SYNC_BYPASS_LSB: u32 = 0;
-pub const PROC_PIO_INPUT_SYNC_BYPASS_ACCESS: &str = "RW";
+pub const PROC_PIO_INPUT_SYNC_BYPASS_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_DBG_PADOUT
 // Description : Read to sample the pad output values PIO is currently driving
@@ -404,7 +407,7 @@ pub const PROC_PIO_DBG_PADOUT_RESET: u32 = 0x00000000;
 pub const PROC_PIO_DBG_PADOUT_WIDTH: u32 = 32;
 pub const PROC_PIO_DBG_PADOUT_MSB: u32 = 31;
 pub const PROC_PIO_DBG_PADOUT_LSB: u32 = 0;
-pub const PROC_PIO_DBG_PADOUT_ACCESS: &str = "RO";
+pub const PROC_PIO_DBG_PADOUT_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_DBG_PADOE
 // Description : Read to sample the pad output enables (direction) PIO is
@@ -415,7 +418,7 @@ pub const PROC_PIO_DBG_PADOE_RESET: u32 = 0x00000000;
 pub const PROC_PIO_DBG_PADOE_WIDTH: u32 = 32;
 pub const PROC_PIO_DBG_PADOE_MSB: u32 = 31;
 pub const PROC_PIO_DBG_PADOE_LSB: u32 = 0;
-pub const PROC_PIO_DBG_PADOE_ACCESS: &str = "RO";
+pub const PROC_PIO_DBG_PADOE_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_DBG_CFGINFO
 // Description : The PIO hardware has some free parameters that may vary between
@@ -434,7 +437,7 @@ pub const PROC_PIO_DBG_CFGINFO_IMEM_SIZE_RESET: &str = "-";
 pub const PROC_PIO_DBG_CFGINFO_IMEM_SIZE_BITS: u32 = 0x003f0000;
 pub const PROC_PIO_DBG_CFGINFO_IMEM_SIZE_MSB: u32 = 21;
 pub const PROC_PIO_DBG_CFGINFO_IMEM_SIZE_LSB: u32 = 16;
-pub const PROC_PIO_DBG_CFGINFO_IMEM_SIZE_ACCESS: &str = "RO";
+pub const PROC_PIO_DBG_CFGINFO_IMEM_SIZE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_DBG_CFGINFO_SM_COUNT
 // Description : The number of state machines this PIO instance is equipped
@@ -443,7 +446,7 @@ pub const PROC_PIO_DBG_CFGINFO_SM_COUNT_RESET: &str = "-";
 pub const PROC_PIO_DBG_CFGINFO_SM_COUNT_BITS: u32 = 0x00000f00;
 pub const PROC_PIO_DBG_CFGINFO_SM_COUNT_MSB: u32 = 11;
 pub const PROC_PIO_DBG_CFGINFO_SM_COUNT_LSB: u32 = 8;
-pub const PROC_PIO_DBG_CFGINFO_SM_COUNT_ACCESS: &str = "RO";
+pub const PROC_PIO_DBG_CFGINFO_SM_COUNT_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_DBG_CFGINFO_FIFO_DEPTH
 // Description : The depth of the state machine TX/RX FIFOs, measured in words.
@@ -453,7 +456,7 @@ pub const PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_RESET: &str = "-";
 pub const PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_BITS: u32 = 0x0000003f;
 pub const PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_MSB: u32 = 5;
 pub const PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_LSB: u32 = 0;
-pub const PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_ACCESS: &str = "RO";
+pub const PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM0
 // Description : Write-only access to instruction memory location 0
@@ -463,7 +466,7 @@ pub const PROC_PIO_INSTR_MEM0_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM0_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM0_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM0_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM0_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM0_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM1
 // Description : Write-only access to instruction memory location 1
@@ -473,7 +476,7 @@ pub const PROC_PIO_INSTR_MEM1_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM1_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM1_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM1_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM1_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM1_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM2
 // Description : Write-only access to instruction memory location 2
@@ -483,7 +486,7 @@ pub const PROC_PIO_INSTR_MEM2_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM2_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM2_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM2_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM2_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM2_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM3
 // Description : Write-only access to instruction memory location 3
@@ -493,7 +496,7 @@ pub const PROC_PIO_INSTR_MEM3_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM3_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM3_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM3_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM3_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM3_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM4
 // Description : Write-only access to instruction memory location 4
@@ -503,7 +506,7 @@ pub const PROC_PIO_INSTR_MEM4_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM4_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM4_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM4_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM4_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM4_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM5
 // Description : Write-only access to instruction memory location 5
@@ -513,7 +516,7 @@ pub const PROC_PIO_INSTR_MEM5_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM5_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM5_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM5_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM5_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM5_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM6
 // Description : Write-only access to instruction memory location 6
@@ -523,7 +526,7 @@ pub const PROC_PIO_INSTR_MEM6_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM6_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM6_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM6_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM6_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM6_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM7
 // Description : Write-only access to instruction memory location 7
@@ -533,7 +536,7 @@ pub const PROC_PIO_INSTR_MEM7_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM7_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM7_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM7_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM7_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM7_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM8
 // Description : Write-only access to instruction memory location 8
@@ -543,7 +546,7 @@ pub const PROC_PIO_INSTR_MEM8_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM8_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM8_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM8_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM8_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM8_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM9
 // Description : Write-only access to instruction memory location 9
@@ -553,7 +556,7 @@ pub const PROC_PIO_INSTR_MEM9_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM9_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM9_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM9_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM9_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM9_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM10
 // Description : Write-only access to instruction memory location 10
@@ -563,7 +566,7 @@ pub const PROC_PIO_INSTR_MEM10_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM10_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM10_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM10_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM10_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM10_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM11
 // Description : Write-only access to instruction memory location 11
@@ -573,7 +576,7 @@ pub const PROC_PIO_INSTR_MEM11_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM11_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM11_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM11_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM11_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM11_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM12
 // Description : Write-only access to instruction memory location 12
@@ -583,7 +586,7 @@ pub const PROC_PIO_INSTR_MEM12_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM12_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM12_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM12_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM12_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM12_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM13
 // Description : Write-only access to instruction memory location 13
@@ -593,7 +596,7 @@ pub const PROC_PIO_INSTR_MEM13_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM13_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM13_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM13_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM13_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM13_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM14
 // Description : Write-only access to instruction memory location 14
@@ -603,7 +606,7 @@ pub const PROC_PIO_INSTR_MEM14_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM14_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM14_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM14_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM14_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM14_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM15
 // Description : Write-only access to instruction memory location 15
@@ -613,7 +616,7 @@ pub const PROC_PIO_INSTR_MEM15_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM15_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM15_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM15_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM15_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM15_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM16
 // Description : Write-only access to instruction memory location 16
@@ -623,7 +626,7 @@ pub const PROC_PIO_INSTR_MEM16_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM16_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM16_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM16_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM16_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM16_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM17
 // Description : Write-only access to instruction memory location 17
@@ -633,7 +636,7 @@ pub const PROC_PIO_INSTR_MEM17_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM17_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM17_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM17_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM17_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM17_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM18
 // Description : Write-only access to instruction memory location 18
@@ -643,7 +646,7 @@ pub const PROC_PIO_INSTR_MEM18_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM18_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM18_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM18_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM18_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM18_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM19
 // Description : Write-only access to instruction memory location 19
@@ -653,7 +656,7 @@ pub const PROC_PIO_INSTR_MEM19_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM19_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM19_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM19_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM19_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM19_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM20
 // Description : Write-only access to instruction memory location 20
@@ -663,7 +666,7 @@ pub const PROC_PIO_INSTR_MEM20_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM20_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM20_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM20_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM20_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM20_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM21
 // Description : Write-only access to instruction memory location 21
@@ -673,7 +676,7 @@ pub const PROC_PIO_INSTR_MEM21_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM21_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM21_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM21_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM21_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM21_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM22
 // Description : Write-only access to instruction memory location 22
@@ -683,7 +686,7 @@ pub const PROC_PIO_INSTR_MEM22_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM22_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM22_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM22_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM22_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM22_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM23
 // Description : Write-only access to instruction memory location 23
@@ -693,7 +696,7 @@ pub const PROC_PIO_INSTR_MEM23_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM23_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM23_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM23_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM23_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM23_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM24
 // Description : Write-only access to instruction memory location 24
@@ -703,7 +706,7 @@ pub const PROC_PIO_INSTR_MEM24_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM24_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM24_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM24_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM24_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM24_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM25
 // Description : Write-only access to instruction memory location 25
@@ -713,7 +716,7 @@ pub const PROC_PIO_INSTR_MEM25_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM25_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM25_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM25_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM25_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM25_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM26
 // Description : Write-only access to instruction memory location 26
@@ -723,7 +726,7 @@ pub const PROC_PIO_INSTR_MEM26_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM26_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM26_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM26_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM26_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM26_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM27
 // Description : Write-only access to instruction memory location 27
@@ -733,7 +736,7 @@ pub const PROC_PIO_INSTR_MEM27_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM27_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM27_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM27_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM27_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM27_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM28
 // Description : Write-only access to instruction memory location 28
@@ -743,7 +746,7 @@ pub const PROC_PIO_INSTR_MEM28_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM28_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM28_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM28_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM28_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM28_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM29
 // Description : Write-only access to instruction memory location 29
@@ -753,7 +756,7 @@ pub const PROC_PIO_INSTR_MEM29_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM29_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM29_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM29_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM29_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM29_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM30
 // Description : Write-only access to instruction memory location 30
@@ -763,7 +766,7 @@ pub const PROC_PIO_INSTR_MEM30_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM30_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM30_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM30_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM30_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM30_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_INSTR_MEM31
 // Description : Write-only access to instruction memory location 31
@@ -773,7 +776,7 @@ pub const PROC_PIO_INSTR_MEM31_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTR_MEM31_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTR_MEM31_MSB: u32 = 15;
 pub const PROC_PIO_INSTR_MEM31_LSB: u32 = 0;
-pub const PROC_PIO_INSTR_MEM31_ACCESS: &str = "WO";
+pub const PROC_PIO_INSTR_MEM31_ACCESS: Access = Access::WO;
 // =============================================================================
 // Register    : PROC_PIO_SM0_CLKDIV
 // Description : Clock divider register for state machine 0
@@ -790,7 +793,7 @@ pub const PROC_PIO_SM0_CLKDIV_INT_RESET: u32 = 0x0001;
 pub const PROC_PIO_SM0_CLKDIV_INT_BITS: u32 = 0xffff0000;
 pub const PROC_PIO_SM0_CLKDIV_INT_MSB: u32 = 31;
 pub const PROC_PIO_SM0_CLKDIV_INT_LSB: u32 = 16;
-pub const PROC_PIO_SM0_CLKDIV_INT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_CLKDIV_INT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_CLKDIV_FRAC
 // Description : Fractional part of clock divider
@@ -798,7 +801,7 @@ pub const PROC_PIO_SM0_CLKDIV_FRAC_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_CLKDIV_FRAC_BITS: u32 = 0x0000ff00;
 pub const PROC_PIO_SM0_CLKDIV_FRAC_MSB: u32 = 15;
 pub const PROC_PIO_SM0_CLKDIV_FRAC_LSB: u32 = 8;
-pub const PROC_PIO_SM0_CLKDIV_FRAC_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_CLKDIV_FRAC_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM0_EXECCTRL
 // Description : Execution/behavioural settings for state machine 0
@@ -815,7 +818,7 @@ pub const PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_MSB: u32 = 31;
 pub const PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_LSB: u32 = 31;
-pub const PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_ACCESS: &str = "RO";
+pub const PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_SIDE_EN
 // Description : If 1, the delay MSB is used as side-set enable, rather than a
@@ -826,7 +829,7 @@ pub const PROC_PIO_SM0_EXECCTRL_SIDE_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_EXECCTRL_SIDE_EN_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM0_EXECCTRL_SIDE_EN_MSB: u32 = 30;
 pub const PROC_PIO_SM0_EXECCTRL_SIDE_EN_LSB: u32 = 30;
-pub const PROC_PIO_SM0_EXECCTRL_SIDE_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_SIDE_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR
 // Description : Side-set data is asserted to pin OEs instead of pin values
@@ -834,7 +837,7 @@ pub const PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_BITS: u32 = 0x20000000;
 pub const PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_MSB: u32 = 29;
 pub const PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_LSB: u32 = 29;
-pub const PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_JMP_PIN
 // Description : The GPIO number to use as condition for JMP PIN. Unaffected by
@@ -843,7 +846,7 @@ pub const PROC_PIO_SM0_EXECCTRL_JMP_PIN_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_EXECCTRL_JMP_PIN_BITS: u32 = 0x1f000000;
 pub const PROC_PIO_SM0_EXECCTRL_JMP_PIN_MSB: u32 = 28;
 pub const PROC_PIO_SM0_EXECCTRL_JMP_PIN_LSB: u32 = 24;
-pub const PROC_PIO_SM0_EXECCTRL_JMP_PIN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_JMP_PIN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL
 // Description : Which data bit to use for inline OUT enable
@@ -851,7 +854,7 @@ pub const PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_BITS: u32 = 0x00f80000;
 pub const PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_MSB: u32 = 23;
 pub const PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_LSB: u32 = 19;
-pub const PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN
 // Description : If 1, use a bit of OUT data as an auxiliary write enable
@@ -865,7 +868,7 @@ pub const PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_MSB: u32 = 18;
 pub const PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_LSB: u32 = 18;
-pub const PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_OUT_STICKY
 // Description : Continuously assert the most recent OUT/SET to the pins
@@ -873,7 +876,7 @@ pub const PROC_PIO_SM0_EXECCTRL_OUT_STICKY_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_EXECCTRL_OUT_STICKY_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM0_EXECCTRL_OUT_STICKY_MSB: u32 = 17;
 pub const PROC_PIO_SM0_EXECCTRL_OUT_STICKY_LSB: u32 = 17;
-pub const PROC_PIO_SM0_EXECCTRL_OUT_STICKY_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_OUT_STICKY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_WRAP_TOP
 // Description : After reaching this address, execution is wrapped to
@@ -884,7 +887,7 @@ pub const PROC_PIO_SM0_EXECCTRL_WRAP_TOP_RESET: u32 = 0x1f;
 pub const PROC_PIO_SM0_EXECCTRL_WRAP_TOP_BITS: u32 = 0x0001f000;
 pub const PROC_PIO_SM0_EXECCTRL_WRAP_TOP_MSB: u32 = 16;
 pub const PROC_PIO_SM0_EXECCTRL_WRAP_TOP_LSB: u32 = 12;
-pub const PROC_PIO_SM0_EXECCTRL_WRAP_TOP_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_WRAP_TOP_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM
 // Description : After reaching wrap_top, execution is wrapped to this address.
@@ -892,7 +895,7 @@ pub const PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_MSB: u32 = 11;
 pub const PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_LSB: u32 = 7;
-pub const PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_EXECCTRL_STATUS_SEL
 // Description : Comparison used for the MOV x, STATUS instruction.
@@ -902,7 +905,7 @@ pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_MSB: u32 = 5;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_LSB: u32 = 5;
-pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_ACCESS: Access = Access::RW;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_VALUE_TXLEVEL: u32 = 0x0;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_SEL_VALUE_RXLEVEL: u32 = 0x1;
 // -----------------------------------------------------------------------------
@@ -912,7 +915,7 @@ pub const PROC_PIO_SM0_EXECCTRL_STATUS_N_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_N_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_N_MSB: u32 = 4;
 pub const PROC_PIO_SM0_EXECCTRL_STATUS_N_LSB: u32 = 0;
-pub const PROC_PIO_SM0_EXECCTRL_STATUS_N_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_EXECCTRL_STATUS_N_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM0_SHIFTCTRL
 // Description : Control behaviour of the input/output shift registers for state
@@ -932,7 +935,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_MSB: u32 = 31;
 pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_LSB: u32 = 31;
-pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX
 // Description : When 1, TX FIFO steals the RX FIFO's storage, and becomes twice
@@ -944,7 +947,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_MSB: u32 = 30;
 pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_LSB: u32 = 30;
-pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH
 // Description : Number of bits shifted out of TXSR before autopull or
@@ -954,7 +957,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_BITS: u32 = 0x3e000000;
 pub const PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_MSB: u32 = 29;
 pub const PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_LSB: u32 = 25;
-pub const PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH
 // Description : Number of bits shifted into RXSR before autopush or conditional
@@ -964,7 +967,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_BITS: u32 = 0x01f00000;
 pub const PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_MSB: u32 = 24;
 pub const PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_LSB: u32 = 20;
-pub const PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR
 // Description : 1 = shift out of output shift register to right. 0 = to left.
@@ -972,7 +975,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_BITS: u32 = 0x00080000;
 pub const PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_MSB: u32 = 19;
 pub const PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_LSB: u32 = 19;
-pub const PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR
 // Description : 1 = shift input shift register to right (data enters from
@@ -981,7 +984,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_MSB: u32 = 18;
 pub const PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_LSB: u32 = 18;
-pub const PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_SHIFTCTRL_AUTOPULL
 // Description : Pull automatically when the output shift register is emptied
@@ -989,7 +992,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_MSB: u32 = 17;
 pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_LSB: u32 = 17;
-pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH
 // Description : Push automatically when the input shift register is filled
@@ -997,7 +1000,7 @@ pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_BITS: u32 = 0x00010000;
 pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_MSB: u32 = 16;
 pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_LSB: u32 = 16;
-pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM0_ADDR
 // Description : Current instruction address of state machine 0
@@ -1007,7 +1010,7 @@ pub const PROC_PIO_SM0_ADDR_RESET: u32 = 0x00000000;
 pub const PROC_PIO_SM0_ADDR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM0_ADDR_MSB: u32 = 4;
 pub const PROC_PIO_SM0_ADDR_LSB: u32 = 0;
-pub const PROC_PIO_SM0_ADDR_ACCESS: &str = "RO";
+pub const PROC_PIO_SM0_ADDR_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_SM0_INSTR
 // Description : Instruction currently being executed by state machine 0
@@ -1019,7 +1022,7 @@ pub const PROC_PIO_SM0_INSTR_RESET: &str = "-";
 pub const PROC_PIO_SM0_INSTR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM0_INSTR_MSB: u32 = 15;
 pub const PROC_PIO_SM0_INSTR_LSB: u32 = 0;
-pub const PROC_PIO_SM0_INSTR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_INSTR_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM0_PINCTRL
 // Description : State machine pin control
@@ -1035,7 +1038,7 @@ pub const PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_BITS: u32 = 0xe0000000;
 pub const PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_MSB: u32 = 31;
 pub const PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_LSB: u32 = 29;
-pub const PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_PINCTRL_SET_COUNT
 // Description : The number of pins asserted by a SET. Max of 5
@@ -1043,7 +1046,7 @@ pub const PROC_PIO_SM0_PINCTRL_SET_COUNT_RESET: u32 = 0x5;
 pub const PROC_PIO_SM0_PINCTRL_SET_COUNT_BITS: u32 = 0x1c000000;
 pub const PROC_PIO_SM0_PINCTRL_SET_COUNT_MSB: u32 = 28;
 pub const PROC_PIO_SM0_PINCTRL_SET_COUNT_LSB: u32 = 26;
-pub const PROC_PIO_SM0_PINCTRL_SET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_PINCTRL_SET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_PINCTRL_OUT_COUNT
 // Description : The number of pins asserted by an OUT. Value of 0 -> 32 pins
@@ -1051,7 +1054,7 @@ pub const PROC_PIO_SM0_PINCTRL_OUT_COUNT_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_PINCTRL_OUT_COUNT_BITS: u32 = 0x03f00000;
 pub const PROC_PIO_SM0_PINCTRL_OUT_COUNT_MSB: u32 = 25;
 pub const PROC_PIO_SM0_PINCTRL_OUT_COUNT_LSB: u32 = 20;
-pub const PROC_PIO_SM0_PINCTRL_OUT_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_PINCTRL_OUT_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_PINCTRL_IN_BASE
 // Description : The virtual pin corresponding to IN bit 0
@@ -1059,7 +1062,7 @@ pub const PROC_PIO_SM0_PINCTRL_IN_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_PINCTRL_IN_BASE_BITS: u32 = 0x000f8000;
 pub const PROC_PIO_SM0_PINCTRL_IN_BASE_MSB: u32 = 19;
 pub const PROC_PIO_SM0_PINCTRL_IN_BASE_LSB: u32 = 15;
-pub const PROC_PIO_SM0_PINCTRL_IN_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_PINCTRL_IN_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_PINCTRL_SIDESET_BASE
 // Description : The virtual pin corresponding to delay field bit 0
@@ -1067,7 +1070,7 @@ pub const PROC_PIO_SM0_PINCTRL_SIDESET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_PINCTRL_SIDESET_BASE_BITS: u32 = 0x00007c00;
 pub const PROC_PIO_SM0_PINCTRL_SIDESET_BASE_MSB: u32 = 14;
 pub const PROC_PIO_SM0_PINCTRL_SIDESET_BASE_LSB: u32 = 10;
-pub const PROC_PIO_SM0_PINCTRL_SIDESET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_PINCTRL_SIDESET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_PINCTRL_SET_BASE
 // Description : The virtual pin corresponding to SET bit 0
@@ -1075,7 +1078,7 @@ pub const PROC_PIO_SM0_PINCTRL_SET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_PINCTRL_SET_BASE_BITS: u32 = 0x000003e0;
 pub const PROC_PIO_SM0_PINCTRL_SET_BASE_MSB: u32 = 9;
 pub const PROC_PIO_SM0_PINCTRL_SET_BASE_LSB: u32 = 5;
-pub const PROC_PIO_SM0_PINCTRL_SET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_PINCTRL_SET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_PINCTRL_OUT_BASE
 // Description : The virtual pin corresponding to OUT bit 0
@@ -1083,7 +1086,7 @@ pub const PROC_PIO_SM0_PINCTRL_OUT_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM0_PINCTRL_OUT_BASE_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM0_PINCTRL_OUT_BASE_MSB: u32 = 4;
 pub const PROC_PIO_SM0_PINCTRL_OUT_BASE_LSB: u32 = 0;
-pub const PROC_PIO_SM0_PINCTRL_OUT_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_PINCTRL_OUT_BASE_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM0_DMACTRL_TX
 // Description : State machine DMA control
@@ -1100,7 +1103,7 @@ pub const PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_DMACTRL_TX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -1110,7 +1113,7 @@ pub const PROC_PIO_SM0_DMACTRL_TX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM0_DMACTRL_TX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM0_DMACTRL_TX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM0_DMACTRL_TX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM0_DMACTRL_TX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM0_DMACTRL_TX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -1121,7 +1124,7 @@ pub const PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD
 // Description : Threshold control. If there are no more than THRESHOLD items in
@@ -1130,7 +1133,7 @@ pub const PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM0_DMACTRL_RX
 // Description : State machine DMA control
@@ -1147,7 +1150,7 @@ pub const PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_DMACTRL_RX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -1157,7 +1160,7 @@ pub const PROC_PIO_SM0_DMACTRL_RX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM0_DMACTRL_RX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM0_DMACTRL_RX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM0_DMACTRL_RX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM0_DMACTRL_RX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM0_DMACTRL_RX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -1168,7 +1171,7 @@ pub const PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD
 // Description : Threshold control. If there are at least THRESHOLD items in the
@@ -1177,7 +1180,7 @@ pub const PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM1_CLKDIV
 // Description : Clock divider register for state machine 1
@@ -1194,7 +1197,7 @@ pub const PROC_PIO_SM1_CLKDIV_INT_RESET: u32 = 0x0001;
 pub const PROC_PIO_SM1_CLKDIV_INT_BITS: u32 = 0xffff0000;
 pub const PROC_PIO_SM1_CLKDIV_INT_MSB: u32 = 31;
 pub const PROC_PIO_SM1_CLKDIV_INT_LSB: u32 = 16;
-pub const PROC_PIO_SM1_CLKDIV_INT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_CLKDIV_INT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_CLKDIV_FRAC
 // Description : Fractional part of clock divider
@@ -1202,7 +1205,7 @@ pub const PROC_PIO_SM1_CLKDIV_FRAC_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_CLKDIV_FRAC_BITS: u32 = 0x0000ff00;
 pub const PROC_PIO_SM1_CLKDIV_FRAC_MSB: u32 = 15;
 pub const PROC_PIO_SM1_CLKDIV_FRAC_LSB: u32 = 8;
-pub const PROC_PIO_SM1_CLKDIV_FRAC_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_CLKDIV_FRAC_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM1_EXECCTRL
 // Description : Execution/behavioural settings for state machine 1
@@ -1219,7 +1222,7 @@ pub const PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_MSB: u32 = 31;
 pub const PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_LSB: u32 = 31;
-pub const PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_ACCESS: &str = "RO";
+pub const PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_SIDE_EN
 // Description : If 1, the delay MSB is used as side-set enable, rather than a
@@ -1230,7 +1233,7 @@ pub const PROC_PIO_SM1_EXECCTRL_SIDE_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_EXECCTRL_SIDE_EN_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM1_EXECCTRL_SIDE_EN_MSB: u32 = 30;
 pub const PROC_PIO_SM1_EXECCTRL_SIDE_EN_LSB: u32 = 30;
-pub const PROC_PIO_SM1_EXECCTRL_SIDE_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_SIDE_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR
 // Description : Side-set data is asserted to pin OEs instead of pin values
@@ -1238,7 +1241,7 @@ pub const PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_BITS: u32 = 0x20000000;
 pub const PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_MSB: u32 = 29;
 pub const PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_LSB: u32 = 29;
-pub const PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_JMP_PIN
 // Description : The GPIO number to use as condition for JMP PIN. Unaffected by
@@ -1247,7 +1250,7 @@ pub const PROC_PIO_SM1_EXECCTRL_JMP_PIN_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_EXECCTRL_JMP_PIN_BITS: u32 = 0x1f000000;
 pub const PROC_PIO_SM1_EXECCTRL_JMP_PIN_MSB: u32 = 28;
 pub const PROC_PIO_SM1_EXECCTRL_JMP_PIN_LSB: u32 = 24;
-pub const PROC_PIO_SM1_EXECCTRL_JMP_PIN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_JMP_PIN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL
 // Description : Which data bit to use for inline OUT enable
@@ -1255,7 +1258,7 @@ pub const PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_BITS: u32 = 0x00f80000;
 pub const PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_MSB: u32 = 23;
 pub const PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_LSB: u32 = 19;
-pub const PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN
 // Description : If 1, use a bit of OUT data as an auxiliary write enable
@@ -1269,7 +1272,7 @@ pub const PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_MSB: u32 = 18;
 pub const PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_LSB: u32 = 18;
-pub const PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_OUT_STICKY
 // Description : Continuously assert the most recent OUT/SET to the pins
@@ -1277,7 +1280,7 @@ pub const PROC_PIO_SM1_EXECCTRL_OUT_STICKY_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_EXECCTRL_OUT_STICKY_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM1_EXECCTRL_OUT_STICKY_MSB: u32 = 17;
 pub const PROC_PIO_SM1_EXECCTRL_OUT_STICKY_LSB: u32 = 17;
-pub const PROC_PIO_SM1_EXECCTRL_OUT_STICKY_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_OUT_STICKY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_WRAP_TOP
 // Description : After reaching this address, execution is wrapped to
@@ -1288,7 +1291,7 @@ pub const PROC_PIO_SM1_EXECCTRL_WRAP_TOP_RESET: u32 = 0x1f;
 pub const PROC_PIO_SM1_EXECCTRL_WRAP_TOP_BITS: u32 = 0x0001f000;
 pub const PROC_PIO_SM1_EXECCTRL_WRAP_TOP_MSB: u32 = 16;
 pub const PROC_PIO_SM1_EXECCTRL_WRAP_TOP_LSB: u32 = 12;
-pub const PROC_PIO_SM1_EXECCTRL_WRAP_TOP_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_WRAP_TOP_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM
 // Description : After reaching wrap_top, execution is wrapped to this address.
@@ -1296,7 +1299,7 @@ pub const PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_MSB: u32 = 11;
 pub const PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_LSB: u32 = 7;
-pub const PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_EXECCTRL_STATUS_SEL
 // Description : Comparison used for the MOV x, STATUS instruction.
@@ -1306,7 +1309,7 @@ pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_MSB: u32 = 5;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_LSB: u32 = 5;
-pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_ACCESS: Access = Access::RW;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_VALUE_TXLEVEL: u32 = 0x0;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_SEL_VALUE_RXLEVEL: u32 = 0x1;
 // -----------------------------------------------------------------------------
@@ -1316,7 +1319,7 @@ pub const PROC_PIO_SM1_EXECCTRL_STATUS_N_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_N_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_N_MSB: u32 = 4;
 pub const PROC_PIO_SM1_EXECCTRL_STATUS_N_LSB: u32 = 0;
-pub const PROC_PIO_SM1_EXECCTRL_STATUS_N_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_EXECCTRL_STATUS_N_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM1_SHIFTCTRL
 // Description : Control behaviour of the input/output shift registers for state
@@ -1336,7 +1339,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_MSB: u32 = 31;
 pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_LSB: u32 = 31;
-pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX
 // Description : When 1, TX FIFO steals the RX FIFO's storage, and becomes twice
@@ -1348,7 +1351,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_MSB: u32 = 30;
 pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_LSB: u32 = 30;
-pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH
 // Description : Number of bits shifted out of TXSR before autopull or
@@ -1358,7 +1361,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_BITS: u32 = 0x3e000000;
 pub const PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_MSB: u32 = 29;
 pub const PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_LSB: u32 = 25;
-pub const PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH
 // Description : Number of bits shifted into RXSR before autopush or conditional
@@ -1368,7 +1371,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_BITS: u32 = 0x01f00000;
 pub const PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_MSB: u32 = 24;
 pub const PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_LSB: u32 = 20;
-pub const PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR
 // Description : 1 = shift out of output shift register to right. 0 = to left.
@@ -1376,7 +1379,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_BITS: u32 = 0x00080000;
 pub const PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_MSB: u32 = 19;
 pub const PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_LSB: u32 = 19;
-pub const PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR
 // Description : 1 = shift input shift register to right (data enters from
@@ -1385,7 +1388,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_MSB: u32 = 18;
 pub const PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_LSB: u32 = 18;
-pub const PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_SHIFTCTRL_AUTOPULL
 // Description : Pull automatically when the output shift register is emptied
@@ -1393,7 +1396,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_MSB: u32 = 17;
 pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_LSB: u32 = 17;
-pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH
 // Description : Push automatically when the input shift register is filled
@@ -1401,7 +1404,7 @@ pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_BITS: u32 = 0x00010000;
 pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_MSB: u32 = 16;
 pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_LSB: u32 = 16;
-pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM1_ADDR
 // Description : Current instruction address of state machine 1
@@ -1411,7 +1414,7 @@ pub const PROC_PIO_SM1_ADDR_RESET: u32 = 0x00000000;
 pub const PROC_PIO_SM1_ADDR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM1_ADDR_MSB: u32 = 4;
 pub const PROC_PIO_SM1_ADDR_LSB: u32 = 0;
-pub const PROC_PIO_SM1_ADDR_ACCESS: &str = "RO";
+pub const PROC_PIO_SM1_ADDR_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_SM1_INSTR
 // Description : Instruction currently being executed by state machine 1
@@ -1423,7 +1426,7 @@ pub const PROC_PIO_SM1_INSTR_RESET: &str = "-";
 pub const PROC_PIO_SM1_INSTR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM1_INSTR_MSB: u32 = 15;
 pub const PROC_PIO_SM1_INSTR_LSB: u32 = 0;
-pub const PROC_PIO_SM1_INSTR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_INSTR_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM1_PINCTRL
 // Description : State machine pin control
@@ -1439,7 +1442,7 @@ pub const PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_BITS: u32 = 0xe0000000;
 pub const PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_MSB: u32 = 31;
 pub const PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_LSB: u32 = 29;
-pub const PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_PINCTRL_SET_COUNT
 // Description : The number of pins asserted by a SET. Max of 5
@@ -1447,7 +1450,7 @@ pub const PROC_PIO_SM1_PINCTRL_SET_COUNT_RESET: u32 = 0x5;
 pub const PROC_PIO_SM1_PINCTRL_SET_COUNT_BITS: u32 = 0x1c000000;
 pub const PROC_PIO_SM1_PINCTRL_SET_COUNT_MSB: u32 = 28;
 pub const PROC_PIO_SM1_PINCTRL_SET_COUNT_LSB: u32 = 26;
-pub const PROC_PIO_SM1_PINCTRL_SET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_PINCTRL_SET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_PINCTRL_OUT_COUNT
 // Description : The number of pins asserted by an OUT. Value of 0 -> 32 pins
@@ -1455,7 +1458,7 @@ pub const PROC_PIO_SM1_PINCTRL_OUT_COUNT_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_PINCTRL_OUT_COUNT_BITS: u32 = 0x03f00000;
 pub const PROC_PIO_SM1_PINCTRL_OUT_COUNT_MSB: u32 = 25;
 pub const PROC_PIO_SM1_PINCTRL_OUT_COUNT_LSB: u32 = 20;
-pub const PROC_PIO_SM1_PINCTRL_OUT_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_PINCTRL_OUT_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_PINCTRL_IN_BASE
 // Description : The virtual pin corresponding to IN bit 0
@@ -1463,7 +1466,7 @@ pub const PROC_PIO_SM1_PINCTRL_IN_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_PINCTRL_IN_BASE_BITS: u32 = 0x000f8000;
 pub const PROC_PIO_SM1_PINCTRL_IN_BASE_MSB: u32 = 19;
 pub const PROC_PIO_SM1_PINCTRL_IN_BASE_LSB: u32 = 15;
-pub const PROC_PIO_SM1_PINCTRL_IN_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_PINCTRL_IN_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_PINCTRL_SIDESET_BASE
 // Description : The virtual pin corresponding to delay field bit 0
@@ -1471,7 +1474,7 @@ pub const PROC_PIO_SM1_PINCTRL_SIDESET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_PINCTRL_SIDESET_BASE_BITS: u32 = 0x00007c00;
 pub const PROC_PIO_SM1_PINCTRL_SIDESET_BASE_MSB: u32 = 14;
 pub const PROC_PIO_SM1_PINCTRL_SIDESET_BASE_LSB: u32 = 10;
-pub const PROC_PIO_SM1_PINCTRL_SIDESET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_PINCTRL_SIDESET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_PINCTRL_SET_BASE
 // Description : The virtual pin corresponding to SET bit 0
@@ -1479,7 +1482,7 @@ pub const PROC_PIO_SM1_PINCTRL_SET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_PINCTRL_SET_BASE_BITS: u32 = 0x000003e0;
 pub const PROC_PIO_SM1_PINCTRL_SET_BASE_MSB: u32 = 9;
 pub const PROC_PIO_SM1_PINCTRL_SET_BASE_LSB: u32 = 5;
-pub const PROC_PIO_SM1_PINCTRL_SET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_PINCTRL_SET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_PINCTRL_OUT_BASE
 // Description : The virtual pin corresponding to OUT bit 0
@@ -1487,7 +1490,7 @@ pub const PROC_PIO_SM1_PINCTRL_OUT_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM1_PINCTRL_OUT_BASE_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM1_PINCTRL_OUT_BASE_MSB: u32 = 4;
 pub const PROC_PIO_SM1_PINCTRL_OUT_BASE_LSB: u32 = 0;
-pub const PROC_PIO_SM1_PINCTRL_OUT_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_PINCTRL_OUT_BASE_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM1_DMACTRL_TX
 // Description : State machine DMA control
@@ -1504,7 +1507,7 @@ pub const PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_DMACTRL_TX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -1514,7 +1517,7 @@ pub const PROC_PIO_SM1_DMACTRL_TX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM1_DMACTRL_TX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM1_DMACTRL_TX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM1_DMACTRL_TX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM1_DMACTRL_TX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM1_DMACTRL_TX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -1525,7 +1528,7 @@ pub const PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD
 // Description : Threshold control. If there are no more than THRESHOLD items in
@@ -1534,7 +1537,7 @@ pub const PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM1_DMACTRL_RX
 // Description : State machine DMA control
@@ -1551,7 +1554,7 @@ pub const PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_DMACTRL_RX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -1561,7 +1564,7 @@ pub const PROC_PIO_SM1_DMACTRL_RX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM1_DMACTRL_RX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM1_DMACTRL_RX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM1_DMACTRL_RX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM1_DMACTRL_RX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM1_DMACTRL_RX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -1572,7 +1575,7 @@ pub const PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD
 // Description : Threshold control. If there are at least THRESHOLD items in the
@@ -1581,7 +1584,7 @@ pub const PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM2_CLKDIV
 // Description : Clock divider register for state machine 2
@@ -1598,7 +1601,7 @@ pub const PROC_PIO_SM2_CLKDIV_INT_RESET: u32 = 0x0001;
 pub const PROC_PIO_SM2_CLKDIV_INT_BITS: u32 = 0xffff0000;
 pub const PROC_PIO_SM2_CLKDIV_INT_MSB: u32 = 31;
 pub const PROC_PIO_SM2_CLKDIV_INT_LSB: u32 = 16;
-pub const PROC_PIO_SM2_CLKDIV_INT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_CLKDIV_INT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_CLKDIV_FRAC
 // Description : Fractional part of clock divider
@@ -1606,7 +1609,7 @@ pub const PROC_PIO_SM2_CLKDIV_FRAC_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_CLKDIV_FRAC_BITS: u32 = 0x0000ff00;
 pub const PROC_PIO_SM2_CLKDIV_FRAC_MSB: u32 = 15;
 pub const PROC_PIO_SM2_CLKDIV_FRAC_LSB: u32 = 8;
-pub const PROC_PIO_SM2_CLKDIV_FRAC_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_CLKDIV_FRAC_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM2_EXECCTRL
 // Description : Execution/behavioural settings for state machine 2
@@ -1623,7 +1626,7 @@ pub const PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_MSB: u32 = 31;
 pub const PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_LSB: u32 = 31;
-pub const PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_ACCESS: &str = "RO";
+pub const PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_SIDE_EN
 // Description : If 1, the delay MSB is used as side-set enable, rather than a
@@ -1634,7 +1637,7 @@ pub const PROC_PIO_SM2_EXECCTRL_SIDE_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_EXECCTRL_SIDE_EN_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM2_EXECCTRL_SIDE_EN_MSB: u32 = 30;
 pub const PROC_PIO_SM2_EXECCTRL_SIDE_EN_LSB: u32 = 30;
-pub const PROC_PIO_SM2_EXECCTRL_SIDE_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_SIDE_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR
 // Description : Side-set data is asserted to pin OEs instead of pin values
@@ -1642,7 +1645,7 @@ pub const PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_BITS: u32 = 0x20000000;
 pub const PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_MSB: u32 = 29;
 pub const PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_LSB: u32 = 29;
-pub const PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_JMP_PIN
 // Description : The GPIO number to use as condition for JMP PIN. Unaffected by
@@ -1651,7 +1654,7 @@ pub const PROC_PIO_SM2_EXECCTRL_JMP_PIN_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_EXECCTRL_JMP_PIN_BITS: u32 = 0x1f000000;
 pub const PROC_PIO_SM2_EXECCTRL_JMP_PIN_MSB: u32 = 28;
 pub const PROC_PIO_SM2_EXECCTRL_JMP_PIN_LSB: u32 = 24;
-pub const PROC_PIO_SM2_EXECCTRL_JMP_PIN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_JMP_PIN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL
 // Description : Which data bit to use for inline OUT enable
@@ -1659,7 +1662,7 @@ pub const PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_BITS: u32 = 0x00f80000;
 pub const PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_MSB: u32 = 23;
 pub const PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_LSB: u32 = 19;
-pub const PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN
 // Description : If 1, use a bit of OUT data as an auxiliary write enable
@@ -1673,7 +1676,7 @@ pub const PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_MSB: u32 = 18;
 pub const PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_LSB: u32 = 18;
-pub const PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_OUT_STICKY
 // Description : Continuously assert the most recent OUT/SET to the pins
@@ -1681,7 +1684,7 @@ pub const PROC_PIO_SM2_EXECCTRL_OUT_STICKY_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_EXECCTRL_OUT_STICKY_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM2_EXECCTRL_OUT_STICKY_MSB: u32 = 17;
 pub const PROC_PIO_SM2_EXECCTRL_OUT_STICKY_LSB: u32 = 17;
-pub const PROC_PIO_SM2_EXECCTRL_OUT_STICKY_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_OUT_STICKY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_WRAP_TOP
 // Description : After reaching this address, execution is wrapped to
@@ -1692,7 +1695,7 @@ pub const PROC_PIO_SM2_EXECCTRL_WRAP_TOP_RESET: u32 = 0x1f;
 pub const PROC_PIO_SM2_EXECCTRL_WRAP_TOP_BITS: u32 = 0x0001f000;
 pub const PROC_PIO_SM2_EXECCTRL_WRAP_TOP_MSB: u32 = 16;
 pub const PROC_PIO_SM2_EXECCTRL_WRAP_TOP_LSB: u32 = 12;
-pub const PROC_PIO_SM2_EXECCTRL_WRAP_TOP_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_WRAP_TOP_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM
 // Description : After reaching wrap_top, execution is wrapped to this address.
@@ -1700,7 +1703,7 @@ pub const PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_MSB: u32 = 11;
 pub const PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_LSB: u32 = 7;
-pub const PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_EXECCTRL_STATUS_SEL
 // Description : Comparison used for the MOV x, STATUS instruction.
@@ -1710,7 +1713,7 @@ pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_MSB: u32 = 5;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_LSB: u32 = 5;
-pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_ACCESS: Access = Access::RW;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_VALUE_TXLEVEL: u32 = 0x0;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_SEL_VALUE_RXLEVEL: u32 = 0x1;
 // -----------------------------------------------------------------------------
@@ -1720,7 +1723,7 @@ pub const PROC_PIO_SM2_EXECCTRL_STATUS_N_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_N_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_N_MSB: u32 = 4;
 pub const PROC_PIO_SM2_EXECCTRL_STATUS_N_LSB: u32 = 0;
-pub const PROC_PIO_SM2_EXECCTRL_STATUS_N_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_EXECCTRL_STATUS_N_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM2_SHIFTCTRL
 // Description : Control behaviour of the input/output shift registers for state
@@ -1740,7 +1743,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_MSB: u32 = 31;
 pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_LSB: u32 = 31;
-pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX
 // Description : When 1, TX FIFO steals the RX FIFO's storage, and becomes twice
@@ -1752,7 +1755,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_MSB: u32 = 30;
 pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_LSB: u32 = 30;
-pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH
 // Description : Number of bits shifted out of TXSR before autopull or
@@ -1762,7 +1765,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_BITS: u32 = 0x3e000000;
 pub const PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_MSB: u32 = 29;
 pub const PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_LSB: u32 = 25;
-pub const PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH
 // Description : Number of bits shifted into RXSR before autopush or conditional
@@ -1772,7 +1775,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_BITS: u32 = 0x01f00000;
 pub const PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_MSB: u32 = 24;
 pub const PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_LSB: u32 = 20;
-pub const PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR
 // Description : 1 = shift out of output shift register to right. 0 = to left.
@@ -1780,7 +1783,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_BITS: u32 = 0x00080000;
 pub const PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_MSB: u32 = 19;
 pub const PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_LSB: u32 = 19;
-pub const PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR
 // Description : 1 = shift input shift register to right (data enters from
@@ -1789,7 +1792,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_MSB: u32 = 18;
 pub const PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_LSB: u32 = 18;
-pub const PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_SHIFTCTRL_AUTOPULL
 // Description : Pull automatically when the output shift register is emptied
@@ -1797,7 +1800,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_MSB: u32 = 17;
 pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_LSB: u32 = 17;
-pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH
 // Description : Push automatically when the input shift register is filled
@@ -1805,7 +1808,7 @@ pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_BITS: u32 = 0x00010000;
 pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_MSB: u32 = 16;
 pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_LSB: u32 = 16;
-pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM2_ADDR
 // Description : Current instruction address of state machine 2
@@ -1815,7 +1818,7 @@ pub const PROC_PIO_SM2_ADDR_RESET: u32 = 0x00000000;
 pub const PROC_PIO_SM2_ADDR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM2_ADDR_MSB: u32 = 4;
 pub const PROC_PIO_SM2_ADDR_LSB: u32 = 0;
-pub const PROC_PIO_SM2_ADDR_ACCESS: &str = "RO";
+pub const PROC_PIO_SM2_ADDR_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_SM2_INSTR
 // Description : Instruction currently being executed by state machine 2
@@ -1827,7 +1830,7 @@ pub const PROC_PIO_SM2_INSTR_RESET: &str = "-";
 pub const PROC_PIO_SM2_INSTR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM2_INSTR_MSB: u32 = 15;
 pub const PROC_PIO_SM2_INSTR_LSB: u32 = 0;
-pub const PROC_PIO_SM2_INSTR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_INSTR_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM2_PINCTRL
 // Description : State machine pin control
@@ -1843,7 +1846,7 @@ pub const PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_BITS: u32 = 0xe0000000;
 pub const PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_MSB: u32 = 31;
 pub const PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_LSB: u32 = 29;
-pub const PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_PINCTRL_SET_COUNT
 // Description : The number of pins asserted by a SET. Max of 5
@@ -1851,7 +1854,7 @@ pub const PROC_PIO_SM2_PINCTRL_SET_COUNT_RESET: u32 = 0x5;
 pub const PROC_PIO_SM2_PINCTRL_SET_COUNT_BITS: u32 = 0x1c000000;
 pub const PROC_PIO_SM2_PINCTRL_SET_COUNT_MSB: u32 = 28;
 pub const PROC_PIO_SM2_PINCTRL_SET_COUNT_LSB: u32 = 26;
-pub const PROC_PIO_SM2_PINCTRL_SET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_PINCTRL_SET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_PINCTRL_OUT_COUNT
 // Description : The number of pins asserted by an OUT. Value of 0 -> 32 pins
@@ -1859,7 +1862,7 @@ pub const PROC_PIO_SM2_PINCTRL_OUT_COUNT_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_PINCTRL_OUT_COUNT_BITS: u32 = 0x03f00000;
 pub const PROC_PIO_SM2_PINCTRL_OUT_COUNT_MSB: u32 = 25;
 pub const PROC_PIO_SM2_PINCTRL_OUT_COUNT_LSB: u32 = 20;
-pub const PROC_PIO_SM2_PINCTRL_OUT_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_PINCTRL_OUT_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_PINCTRL_IN_BASE
 // Description : The virtual pin corresponding to IN bit 0
@@ -1867,7 +1870,7 @@ pub const PROC_PIO_SM2_PINCTRL_IN_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_PINCTRL_IN_BASE_BITS: u32 = 0x000f8000;
 pub const PROC_PIO_SM2_PINCTRL_IN_BASE_MSB: u32 = 19;
 pub const PROC_PIO_SM2_PINCTRL_IN_BASE_LSB: u32 = 15;
-pub const PROC_PIO_SM2_PINCTRL_IN_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_PINCTRL_IN_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_PINCTRL_SIDESET_BASE
 // Description : The virtual pin corresponding to delay field bit 0
@@ -1875,7 +1878,7 @@ pub const PROC_PIO_SM2_PINCTRL_SIDESET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_PINCTRL_SIDESET_BASE_BITS: u32 = 0x00007c00;
 pub const PROC_PIO_SM2_PINCTRL_SIDESET_BASE_MSB: u32 = 14;
 pub const PROC_PIO_SM2_PINCTRL_SIDESET_BASE_LSB: u32 = 10;
-pub const PROC_PIO_SM2_PINCTRL_SIDESET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_PINCTRL_SIDESET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_PINCTRL_SET_BASE
 // Description : The virtual pin corresponding to SET bit 0
@@ -1883,7 +1886,7 @@ pub const PROC_PIO_SM2_PINCTRL_SET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_PINCTRL_SET_BASE_BITS: u32 = 0x000003e0;
 pub const PROC_PIO_SM2_PINCTRL_SET_BASE_MSB: u32 = 9;
 pub const PROC_PIO_SM2_PINCTRL_SET_BASE_LSB: u32 = 5;
-pub const PROC_PIO_SM2_PINCTRL_SET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_PINCTRL_SET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_PINCTRL_OUT_BASE
 // Description : The virtual pin corresponding to OUT bit 0
@@ -1891,7 +1894,7 @@ pub const PROC_PIO_SM2_PINCTRL_OUT_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM2_PINCTRL_OUT_BASE_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM2_PINCTRL_OUT_BASE_MSB: u32 = 4;
 pub const PROC_PIO_SM2_PINCTRL_OUT_BASE_LSB: u32 = 0;
-pub const PROC_PIO_SM2_PINCTRL_OUT_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_PINCTRL_OUT_BASE_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM2_DMACTRL_TX
 // Description : State machine DMA control
@@ -1908,7 +1911,7 @@ pub const PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_DMACTRL_TX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -1918,7 +1921,7 @@ pub const PROC_PIO_SM2_DMACTRL_TX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM2_DMACTRL_TX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM2_DMACTRL_TX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM2_DMACTRL_TX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM2_DMACTRL_TX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM2_DMACTRL_TX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -1929,7 +1932,7 @@ pub const PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD
 // Description : Threshold control. If there are no more than THRESHOLD items in
@@ -1938,7 +1941,7 @@ pub const PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM2_DMACTRL_RX
 // Description : State machine DMA control
@@ -1955,7 +1958,7 @@ pub const PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_DMACTRL_RX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -1965,7 +1968,7 @@ pub const PROC_PIO_SM2_DMACTRL_RX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM2_DMACTRL_RX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM2_DMACTRL_RX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM2_DMACTRL_RX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM2_DMACTRL_RX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM2_DMACTRL_RX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -1976,7 +1979,7 @@ pub const PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD
 // Description : Threshold control. If there are at least THRESHOLD items in the
@@ -1985,7 +1988,7 @@ pub const PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM3_CLKDIV
 // Description : Clock divider register for state machine 3
@@ -2002,7 +2005,7 @@ pub const PROC_PIO_SM3_CLKDIV_INT_RESET: u32 = 0x0001;
 pub const PROC_PIO_SM3_CLKDIV_INT_BITS: u32 = 0xffff0000;
 pub const PROC_PIO_SM3_CLKDIV_INT_MSB: u32 = 31;
 pub const PROC_PIO_SM3_CLKDIV_INT_LSB: u32 = 16;
-pub const PROC_PIO_SM3_CLKDIV_INT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_CLKDIV_INT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_CLKDIV_FRAC
 // Description : Fractional part of clock divider
@@ -2010,7 +2013,7 @@ pub const PROC_PIO_SM3_CLKDIV_FRAC_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_CLKDIV_FRAC_BITS: u32 = 0x0000ff00;
 pub const PROC_PIO_SM3_CLKDIV_FRAC_MSB: u32 = 15;
 pub const PROC_PIO_SM3_CLKDIV_FRAC_LSB: u32 = 8;
-pub const PROC_PIO_SM3_CLKDIV_FRAC_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_CLKDIV_FRAC_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM3_EXECCTRL
 // Description : Execution/behavioural settings for state machine 3
@@ -2027,7 +2030,7 @@ pub const PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_MSB: u32 = 31;
 pub const PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_LSB: u32 = 31;
-pub const PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_ACCESS: &str = "RO";
+pub const PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_SIDE_EN
 // Description : If 1, the delay MSB is used as side-set enable, rather than a
@@ -2038,7 +2041,7 @@ pub const PROC_PIO_SM3_EXECCTRL_SIDE_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_EXECCTRL_SIDE_EN_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM3_EXECCTRL_SIDE_EN_MSB: u32 = 30;
 pub const PROC_PIO_SM3_EXECCTRL_SIDE_EN_LSB: u32 = 30;
-pub const PROC_PIO_SM3_EXECCTRL_SIDE_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_SIDE_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR
 // Description : Side-set data is asserted to pin OEs instead of pin values
@@ -2046,7 +2049,7 @@ pub const PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_BITS: u32 = 0x20000000;
 pub const PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_MSB: u32 = 29;
 pub const PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_LSB: u32 = 29;
-pub const PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_JMP_PIN
 // Description : The GPIO number to use as condition for JMP PIN. Unaffected by
@@ -2055,7 +2058,7 @@ pub const PROC_PIO_SM3_EXECCTRL_JMP_PIN_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_EXECCTRL_JMP_PIN_BITS: u32 = 0x1f000000;
 pub const PROC_PIO_SM3_EXECCTRL_JMP_PIN_MSB: u32 = 28;
 pub const PROC_PIO_SM3_EXECCTRL_JMP_PIN_LSB: u32 = 24;
-pub const PROC_PIO_SM3_EXECCTRL_JMP_PIN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_JMP_PIN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL
 // Description : Which data bit to use for inline OUT enable
@@ -2063,7 +2066,7 @@ pub const PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_BITS: u32 = 0x00f80000;
 pub const PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_MSB: u32 = 23;
 pub const PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_LSB: u32 = 19;
-pub const PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN
 // Description : If 1, use a bit of OUT data as an auxiliary write enable
@@ -2077,7 +2080,7 @@ pub const PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_MSB: u32 = 18;
 pub const PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_LSB: u32 = 18;
-pub const PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_OUT_STICKY
 // Description : Continuously assert the most recent OUT/SET to the pins
@@ -2085,7 +2088,7 @@ pub const PROC_PIO_SM3_EXECCTRL_OUT_STICKY_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_EXECCTRL_OUT_STICKY_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM3_EXECCTRL_OUT_STICKY_MSB: u32 = 17;
 pub const PROC_PIO_SM3_EXECCTRL_OUT_STICKY_LSB: u32 = 17;
-pub const PROC_PIO_SM3_EXECCTRL_OUT_STICKY_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_OUT_STICKY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_WRAP_TOP
 // Description : After reaching this address, execution is wrapped to
@@ -2096,7 +2099,7 @@ pub const PROC_PIO_SM3_EXECCTRL_WRAP_TOP_RESET: u32 = 0x1f;
 pub const PROC_PIO_SM3_EXECCTRL_WRAP_TOP_BITS: u32 = 0x0001f000;
 pub const PROC_PIO_SM3_EXECCTRL_WRAP_TOP_MSB: u32 = 16;
 pub const PROC_PIO_SM3_EXECCTRL_WRAP_TOP_LSB: u32 = 12;
-pub const PROC_PIO_SM3_EXECCTRL_WRAP_TOP_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_WRAP_TOP_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM
 // Description : After reaching wrap_top, execution is wrapped to this address.
@@ -2104,7 +2107,7 @@ pub const PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_MSB: u32 = 11;
 pub const PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_LSB: u32 = 7;
-pub const PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_EXECCTRL_STATUS_SEL
 // Description : Comparison used for the MOV x, STATUS instruction.
@@ -2114,7 +2117,7 @@ pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_MSB: u32 = 5;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_LSB: u32 = 5;
-pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_ACCESS: Access = Access::RW;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_VALUE_TXLEVEL: u32 = 0x0;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_SEL_VALUE_RXLEVEL: u32 = 0x1;
 // -----------------------------------------------------------------------------
@@ -2124,7 +2127,7 @@ pub const PROC_PIO_SM3_EXECCTRL_STATUS_N_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_N_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_N_MSB: u32 = 4;
 pub const PROC_PIO_SM3_EXECCTRL_STATUS_N_LSB: u32 = 0;
-pub const PROC_PIO_SM3_EXECCTRL_STATUS_N_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_EXECCTRL_STATUS_N_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM3_SHIFTCTRL
 // Description : Control behaviour of the input/output shift registers for state
@@ -2144,7 +2147,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_MSB: u32 = 31;
 pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_LSB: u32 = 31;
-pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX
 // Description : When 1, TX FIFO steals the RX FIFO's storage, and becomes twice
@@ -2156,7 +2159,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_MSB: u32 = 30;
 pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_LSB: u32 = 30;
-pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH
 // Description : Number of bits shifted out of TXSR before autopull or
@@ -2166,7 +2169,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_BITS: u32 = 0x3e000000;
 pub const PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_MSB: u32 = 29;
 pub const PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_LSB: u32 = 25;
-pub const PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH
 // Description : Number of bits shifted into RXSR before autopush or conditional
@@ -2176,7 +2179,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_BITS: u32 = 0x01f00000;
 pub const PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_MSB: u32 = 24;
 pub const PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_LSB: u32 = 20;
-pub const PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR
 // Description : 1 = shift out of output shift register to right. 0 = to left.
@@ -2184,7 +2187,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_BITS: u32 = 0x00080000;
 pub const PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_MSB: u32 = 19;
 pub const PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_LSB: u32 = 19;
-pub const PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR
 // Description : 1 = shift input shift register to right (data enters from
@@ -2193,7 +2196,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_RESET: u32 = 0x1;
 pub const PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_BITS: u32 = 0x00040000;
 pub const PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_MSB: u32 = 18;
 pub const PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_LSB: u32 = 18;
-pub const PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_SHIFTCTRL_AUTOPULL
 // Description : Pull automatically when the output shift register is emptied
@@ -2201,7 +2204,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_BITS: u32 = 0x00020000;
 pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_MSB: u32 = 17;
 pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_LSB: u32 = 17;
-pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH
 // Description : Push automatically when the input shift register is filled
@@ -2209,7 +2212,7 @@ pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_BITS: u32 = 0x00010000;
 pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_MSB: u32 = 16;
 pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_LSB: u32 = 16;
-pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM3_ADDR
 // Description : Current instruction address of state machine 3
@@ -2219,7 +2222,7 @@ pub const PROC_PIO_SM3_ADDR_RESET: u32 = 0x00000000;
 pub const PROC_PIO_SM3_ADDR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM3_ADDR_MSB: u32 = 4;
 pub const PROC_PIO_SM3_ADDR_LSB: u32 = 0;
-pub const PROC_PIO_SM3_ADDR_ACCESS: &str = "RO";
+pub const PROC_PIO_SM3_ADDR_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_SM3_INSTR
 // Description : Instruction currently being executed by state machine 3
@@ -2231,7 +2234,7 @@ pub const PROC_PIO_SM3_INSTR_RESET: &str = "-";
 pub const PROC_PIO_SM3_INSTR_WIDTH: u32 = 32;
 pub const PROC_PIO_SM3_INSTR_MSB: u32 = 15;
 pub const PROC_PIO_SM3_INSTR_LSB: u32 = 0;
-pub const PROC_PIO_SM3_INSTR_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_INSTR_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM3_PINCTRL
 // Description : State machine pin control
@@ -2247,7 +2250,7 @@ pub const PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_BITS: u32 = 0xe0000000;
 pub const PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_MSB: u32 = 31;
 pub const PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_LSB: u32 = 29;
-pub const PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_PINCTRL_SET_COUNT
 // Description : The number of pins asserted by a SET. Max of 5
@@ -2255,7 +2258,7 @@ pub const PROC_PIO_SM3_PINCTRL_SET_COUNT_RESET: u32 = 0x5;
 pub const PROC_PIO_SM3_PINCTRL_SET_COUNT_BITS: u32 = 0x1c000000;
 pub const PROC_PIO_SM3_PINCTRL_SET_COUNT_MSB: u32 = 28;
 pub const PROC_PIO_SM3_PINCTRL_SET_COUNT_LSB: u32 = 26;
-pub const PROC_PIO_SM3_PINCTRL_SET_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_PINCTRL_SET_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_PINCTRL_OUT_COUNT
 // Description : The number of pins asserted by an OUT. Value of 0 -> 32 pins
@@ -2263,7 +2266,7 @@ pub const PROC_PIO_SM3_PINCTRL_OUT_COUNT_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_PINCTRL_OUT_COUNT_BITS: u32 = 0x03f00000;
 pub const PROC_PIO_SM3_PINCTRL_OUT_COUNT_MSB: u32 = 25;
 pub const PROC_PIO_SM3_PINCTRL_OUT_COUNT_LSB: u32 = 20;
-pub const PROC_PIO_SM3_PINCTRL_OUT_COUNT_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_PINCTRL_OUT_COUNT_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_PINCTRL_IN_BASE
 // Description : The virtual pin corresponding to IN bit 0
@@ -2271,7 +2274,7 @@ pub const PROC_PIO_SM3_PINCTRL_IN_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_PINCTRL_IN_BASE_BITS: u32 = 0x000f8000;
 pub const PROC_PIO_SM3_PINCTRL_IN_BASE_MSB: u32 = 19;
 pub const PROC_PIO_SM3_PINCTRL_IN_BASE_LSB: u32 = 15;
-pub const PROC_PIO_SM3_PINCTRL_IN_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_PINCTRL_IN_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_PINCTRL_SIDESET_BASE
 // Description : The virtual pin corresponding to delay field bit 0
@@ -2279,7 +2282,7 @@ pub const PROC_PIO_SM3_PINCTRL_SIDESET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_PINCTRL_SIDESET_BASE_BITS: u32 = 0x00007c00;
 pub const PROC_PIO_SM3_PINCTRL_SIDESET_BASE_MSB: u32 = 14;
 pub const PROC_PIO_SM3_PINCTRL_SIDESET_BASE_LSB: u32 = 10;
-pub const PROC_PIO_SM3_PINCTRL_SIDESET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_PINCTRL_SIDESET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_PINCTRL_SET_BASE
 // Description : The virtual pin corresponding to SET bit 0
@@ -2287,7 +2290,7 @@ pub const PROC_PIO_SM3_PINCTRL_SET_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_PINCTRL_SET_BASE_BITS: u32 = 0x000003e0;
 pub const PROC_PIO_SM3_PINCTRL_SET_BASE_MSB: u32 = 9;
 pub const PROC_PIO_SM3_PINCTRL_SET_BASE_LSB: u32 = 5;
-pub const PROC_PIO_SM3_PINCTRL_SET_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_PINCTRL_SET_BASE_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_PINCTRL_OUT_BASE
 // Description : The virtual pin corresponding to OUT bit 0
@@ -2295,7 +2298,7 @@ pub const PROC_PIO_SM3_PINCTRL_OUT_BASE_RESET: u32 = 0x00;
 pub const PROC_PIO_SM3_PINCTRL_OUT_BASE_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM3_PINCTRL_OUT_BASE_MSB: u32 = 4;
 pub const PROC_PIO_SM3_PINCTRL_OUT_BASE_LSB: u32 = 0;
-pub const PROC_PIO_SM3_PINCTRL_OUT_BASE_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_PINCTRL_OUT_BASE_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM3_DMACTRL_TX
 // Description : State machine DMA control
@@ -2312,7 +2315,7 @@ pub const PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_DMACTRL_TX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -2322,7 +2325,7 @@ pub const PROC_PIO_SM3_DMACTRL_TX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM3_DMACTRL_TX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM3_DMACTRL_TX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM3_DMACTRL_TX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM3_DMACTRL_TX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM3_DMACTRL_TX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -2333,7 +2336,7 @@ pub const PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD
 // Description : Threshold control. If there are no more than THRESHOLD items in
@@ -2342,7 +2345,7 @@ pub const PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_SM3_DMACTRL_RX
 // Description : State machine DMA control
@@ -2359,7 +2362,7 @@ pub const PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_RESET: u32 = 0x0;
 pub const PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_BITS: u32 = 0x80000000;
 pub const PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_MSB: u32 = 31;
 pub const PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_LSB: u32 = 31;
-pub const PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_DMACTRL_RX_ACTIVE
 // Description : 1 - Assert DREQ to DMA when fewer than fifo_threshold spaces
@@ -2369,7 +2372,7 @@ pub const PROC_PIO_SM3_DMACTRL_RX_ACTIVE_RESET: &str = "-";
 pub const PROC_PIO_SM3_DMACTRL_RX_ACTIVE_BITS: u32 = 0x40000000;
 pub const PROC_PIO_SM3_DMACTRL_RX_ACTIVE_MSB: u32 = 30;
 pub const PROC_PIO_SM3_DMACTRL_RX_ACTIVE_LSB: u32 = 30;
-pub const PROC_PIO_SM3_DMACTRL_RX_ACTIVE_ACCESS: &str = "RO";
+pub const PROC_PIO_SM3_DMACTRL_RX_ACTIVE_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME
 // Description : Delay in number of bus cycles before successive DREQs are
@@ -2380,7 +2383,7 @@ pub const PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_RESET: u32 = 0x02;
 pub const PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_BITS: u32 = 0x00000f80;
 pub const PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_MSB: u32 = 11;
 pub const PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_LSB: u32 = 7;
-pub const PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD
 // Description : Threshold control. If there are at least THRESHOLD items in the
@@ -2389,7 +2392,7 @@ pub const PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_RESET: u32 = 0x04;
 pub const PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_BITS: u32 = 0x0000001f;
 pub const PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_MSB: u32 = 4;
 pub const PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_LSB: u32 = 0;
-pub const PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: &str = "RW";
+pub const PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_INTR
 // Description : Raw Interrupts
@@ -2404,7 +2407,7 @@ pub const PROC_PIO_INTR_SM3_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM3_BITS: u32 = 0x00000800;
 pub const PROC_PIO_INTR_SM3_MSB: u32 = 11;
 pub const PROC_PIO_INTR_SM3_LSB: u32 = 11;
-pub const PROC_PIO_INTR_SM3_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM3_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM2
 // Description : None
@@ -2412,7 +2415,7 @@ pub const PROC_PIO_INTR_SM2_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM2_BITS: u32 = 0x00000400;
 pub const PROC_PIO_INTR_SM2_MSB: u32 = 10;
 pub const PROC_PIO_INTR_SM2_LSB: u32 = 10;
-pub const PROC_PIO_INTR_SM2_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM2_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM1
 // Description : None
@@ -2420,7 +2423,7 @@ pub const PROC_PIO_INTR_SM1_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM1_BITS: u32 = 0x00000200;
 pub const PROC_PIO_INTR_SM1_MSB: u32 = 9;
 pub const PROC_PIO_INTR_SM1_LSB: u32 = 9;
-pub const PROC_PIO_INTR_SM1_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM1_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM0
 // Description : None
@@ -2428,7 +2431,7 @@ pub const PROC_PIO_INTR_SM0_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM0_BITS: u32 = 0x00000100;
 pub const PROC_PIO_INTR_SM0_MSB: u32 = 8;
 pub const PROC_PIO_INTR_SM0_LSB: u32 = 8;
-pub const PROC_PIO_INTR_SM0_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM0_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM3_TXNFULL
 // Description : None
@@ -2436,7 +2439,7 @@ pub const PROC_PIO_INTR_SM3_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM3_TXNFULL_BITS: u32 = 0x00000080;
 pub const PROC_PIO_INTR_SM3_TXNFULL_MSB: u32 = 7;
 pub const PROC_PIO_INTR_SM3_TXNFULL_LSB: u32 = 7;
-pub const PROC_PIO_INTR_SM3_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM3_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM2_TXNFULL
 // Description : None
@@ -2444,7 +2447,7 @@ pub const PROC_PIO_INTR_SM2_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM2_TXNFULL_BITS: u32 = 0x00000040;
 pub const PROC_PIO_INTR_SM2_TXNFULL_MSB: u32 = 6;
 pub const PROC_PIO_INTR_SM2_TXNFULL_LSB: u32 = 6;
-pub const PROC_PIO_INTR_SM2_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM2_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM1_TXNFULL
 // Description : None
@@ -2452,7 +2455,7 @@ pub const PROC_PIO_INTR_SM1_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM1_TXNFULL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_INTR_SM1_TXNFULL_MSB: u32 = 5;
 pub const PROC_PIO_INTR_SM1_TXNFULL_LSB: u32 = 5;
-pub const PROC_PIO_INTR_SM1_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM1_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM0_TXNFULL
 // Description : None
@@ -2460,7 +2463,7 @@ pub const PROC_PIO_INTR_SM0_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM0_TXNFULL_BITS: u32 = 0x00000010;
 pub const PROC_PIO_INTR_SM0_TXNFULL_MSB: u32 = 4;
 pub const PROC_PIO_INTR_SM0_TXNFULL_LSB: u32 = 4;
-pub const PROC_PIO_INTR_SM0_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM0_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM3_RXNEMPTY
 // Description : None
@@ -2468,7 +2471,7 @@ pub const PROC_PIO_INTR_SM3_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM3_RXNEMPTY_BITS: u32 = 0x00000008;
 pub const PROC_PIO_INTR_SM3_RXNEMPTY_MSB: u32 = 3;
 pub const PROC_PIO_INTR_SM3_RXNEMPTY_LSB: u32 = 3;
-pub const PROC_PIO_INTR_SM3_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM3_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM2_RXNEMPTY
 // Description : None
@@ -2476,7 +2479,7 @@ pub const PROC_PIO_INTR_SM2_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM2_RXNEMPTY_BITS: u32 = 0x00000004;
 pub const PROC_PIO_INTR_SM2_RXNEMPTY_MSB: u32 = 2;
 pub const PROC_PIO_INTR_SM2_RXNEMPTY_LSB: u32 = 2;
-pub const PROC_PIO_INTR_SM2_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM2_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM1_RXNEMPTY
 // Description : None
@@ -2484,7 +2487,7 @@ pub const PROC_PIO_INTR_SM1_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM1_RXNEMPTY_BITS: u32 = 0x00000002;
 pub const PROC_PIO_INTR_SM1_RXNEMPTY_MSB: u32 = 1;
 pub const PROC_PIO_INTR_SM1_RXNEMPTY_LSB: u32 = 1;
-pub const PROC_PIO_INTR_SM1_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM1_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_INTR_SM0_RXNEMPTY
 // Description : None
@@ -2492,7 +2495,7 @@ pub const PROC_PIO_INTR_SM0_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_INTR_SM0_RXNEMPTY_BITS: u32 = 0x00000001;
 pub const PROC_PIO_INTR_SM0_RXNEMPTY_MSB: u32 = 0;
 pub const PROC_PIO_INTR_SM0_RXNEMPTY_LSB: u32 = 0;
-pub const PROC_PIO_INTR_SM0_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_INTR_SM0_RXNEMPTY_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_IRQ0_INTE
 // Description : Interrupt Enable for irq0
@@ -2507,7 +2510,7 @@ pub const PROC_PIO_IRQ0_INTE_SM3_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM3_BITS: u32 = 0x00000800;
 pub const PROC_PIO_IRQ0_INTE_SM3_MSB: u32 = 11;
 pub const PROC_PIO_IRQ0_INTE_SM3_LSB: u32 = 11;
-pub const PROC_PIO_IRQ0_INTE_SM3_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM3_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM2
 // Description : None
@@ -2515,7 +2518,7 @@ pub const PROC_PIO_IRQ0_INTE_SM2_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM2_BITS: u32 = 0x00000400;
 pub const PROC_PIO_IRQ0_INTE_SM2_MSB: u32 = 10;
 pub const PROC_PIO_IRQ0_INTE_SM2_LSB: u32 = 10;
-pub const PROC_PIO_IRQ0_INTE_SM2_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM2_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM1
 // Description : None
@@ -2523,7 +2526,7 @@ pub const PROC_PIO_IRQ0_INTE_SM1_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM1_BITS: u32 = 0x00000200;
 pub const PROC_PIO_IRQ0_INTE_SM1_MSB: u32 = 9;
 pub const PROC_PIO_IRQ0_INTE_SM1_LSB: u32 = 9;
-pub const PROC_PIO_IRQ0_INTE_SM1_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM1_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM0
 // Description : None
@@ -2531,7 +2534,7 @@ pub const PROC_PIO_IRQ0_INTE_SM0_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM0_BITS: u32 = 0x00000100;
 pub const PROC_PIO_IRQ0_INTE_SM0_MSB: u32 = 8;
 pub const PROC_PIO_IRQ0_INTE_SM0_LSB: u32 = 8;
-pub const PROC_PIO_IRQ0_INTE_SM0_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM0_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM3_TXNFULL
 // Description : None
@@ -2539,7 +2542,7 @@ pub const PROC_PIO_IRQ0_INTE_SM3_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM3_TXNFULL_BITS: u32 = 0x00000080;
 pub const PROC_PIO_IRQ0_INTE_SM3_TXNFULL_MSB: u32 = 7;
 pub const PROC_PIO_IRQ0_INTE_SM3_TXNFULL_LSB: u32 = 7;
-pub const PROC_PIO_IRQ0_INTE_SM3_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM3_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM2_TXNFULL
 // Description : None
@@ -2547,7 +2550,7 @@ pub const PROC_PIO_IRQ0_INTE_SM2_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM2_TXNFULL_BITS: u32 = 0x00000040;
 pub const PROC_PIO_IRQ0_INTE_SM2_TXNFULL_MSB: u32 = 6;
 pub const PROC_PIO_IRQ0_INTE_SM2_TXNFULL_LSB: u32 = 6;
-pub const PROC_PIO_IRQ0_INTE_SM2_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM2_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM1_TXNFULL
 // Description : None
@@ -2555,7 +2558,7 @@ pub const PROC_PIO_IRQ0_INTE_SM1_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM1_TXNFULL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_IRQ0_INTE_SM1_TXNFULL_MSB: u32 = 5;
 pub const PROC_PIO_IRQ0_INTE_SM1_TXNFULL_LSB: u32 = 5;
-pub const PROC_PIO_IRQ0_INTE_SM1_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM1_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM0_TXNFULL
 // Description : None
@@ -2563,7 +2566,7 @@ pub const PROC_PIO_IRQ0_INTE_SM0_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM0_TXNFULL_BITS: u32 = 0x00000010;
 pub const PROC_PIO_IRQ0_INTE_SM0_TXNFULL_MSB: u32 = 4;
 pub const PROC_PIO_IRQ0_INTE_SM0_TXNFULL_LSB: u32 = 4;
-pub const PROC_PIO_IRQ0_INTE_SM0_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM0_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY
 // Description : None
@@ -2571,7 +2574,7 @@ pub const PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_BITS: u32 = 0x00000008;
 pub const PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_MSB: u32 = 3;
 pub const PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_LSB: u32 = 3;
-pub const PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY
 // Description : None
@@ -2579,7 +2582,7 @@ pub const PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_BITS: u32 = 0x00000004;
 pub const PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_MSB: u32 = 2;
 pub const PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_LSB: u32 = 2;
-pub const PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY
 // Description : None
@@ -2587,7 +2590,7 @@ pub const PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_BITS: u32 = 0x00000002;
 pub const PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_MSB: u32 = 1;
 pub const PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_LSB: u32 = 1;
-pub const PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY
 // Description : None
@@ -2595,7 +2598,7 @@ pub const PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_BITS: u32 = 0x00000001;
 pub const PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_MSB: u32 = 0;
 pub const PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_LSB: u32 = 0;
-pub const PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_IRQ0_INTF
 // Description : Interrupt Force for irq0
@@ -2610,7 +2613,7 @@ pub const PROC_PIO_IRQ0_INTF_SM3_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM3_BITS: u32 = 0x00000800;
 pub const PROC_PIO_IRQ0_INTF_SM3_MSB: u32 = 11;
 pub const PROC_PIO_IRQ0_INTF_SM3_LSB: u32 = 11;
-pub const PROC_PIO_IRQ0_INTF_SM3_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM3_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM2
 // Description : None
@@ -2618,7 +2621,7 @@ pub const PROC_PIO_IRQ0_INTF_SM2_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM2_BITS: u32 = 0x00000400;
 pub const PROC_PIO_IRQ0_INTF_SM2_MSB: u32 = 10;
 pub const PROC_PIO_IRQ0_INTF_SM2_LSB: u32 = 10;
-pub const PROC_PIO_IRQ0_INTF_SM2_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM2_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM1
 // Description : None
@@ -2626,7 +2629,7 @@ pub const PROC_PIO_IRQ0_INTF_SM1_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM1_BITS: u32 = 0x00000200;
 pub const PROC_PIO_IRQ0_INTF_SM1_MSB: u32 = 9;
 pub const PROC_PIO_IRQ0_INTF_SM1_LSB: u32 = 9;
-pub const PROC_PIO_IRQ0_INTF_SM1_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM1_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM0
 // Description : None
@@ -2634,7 +2637,7 @@ pub const PROC_PIO_IRQ0_INTF_SM0_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM0_BITS: u32 = 0x00000100;
 pub const PROC_PIO_IRQ0_INTF_SM0_MSB: u32 = 8;
 pub const PROC_PIO_IRQ0_INTF_SM0_LSB: u32 = 8;
-pub const PROC_PIO_IRQ0_INTF_SM0_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM0_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM3_TXNFULL
 // Description : None
@@ -2642,7 +2645,7 @@ pub const PROC_PIO_IRQ0_INTF_SM3_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM3_TXNFULL_BITS: u32 = 0x00000080;
 pub const PROC_PIO_IRQ0_INTF_SM3_TXNFULL_MSB: u32 = 7;
 pub const PROC_PIO_IRQ0_INTF_SM3_TXNFULL_LSB: u32 = 7;
-pub const PROC_PIO_IRQ0_INTF_SM3_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM3_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM2_TXNFULL
 // Description : None
@@ -2650,7 +2653,7 @@ pub const PROC_PIO_IRQ0_INTF_SM2_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM2_TXNFULL_BITS: u32 = 0x00000040;
 pub const PROC_PIO_IRQ0_INTF_SM2_TXNFULL_MSB: u32 = 6;
 pub const PROC_PIO_IRQ0_INTF_SM2_TXNFULL_LSB: u32 = 6;
-pub const PROC_PIO_IRQ0_INTF_SM2_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM2_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM1_TXNFULL
 // Description : None
@@ -2658,7 +2661,7 @@ pub const PROC_PIO_IRQ0_INTF_SM1_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM1_TXNFULL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_IRQ0_INTF_SM1_TXNFULL_MSB: u32 = 5;
 pub const PROC_PIO_IRQ0_INTF_SM1_TXNFULL_LSB: u32 = 5;
-pub const PROC_PIO_IRQ0_INTF_SM1_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM1_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM0_TXNFULL
 // Description : None
@@ -2666,7 +2669,7 @@ pub const PROC_PIO_IRQ0_INTF_SM0_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM0_TXNFULL_BITS: u32 = 0x00000010;
 pub const PROC_PIO_IRQ0_INTF_SM0_TXNFULL_MSB: u32 = 4;
 pub const PROC_PIO_IRQ0_INTF_SM0_TXNFULL_LSB: u32 = 4;
-pub const PROC_PIO_IRQ0_INTF_SM0_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM0_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY
 // Description : None
@@ -2674,7 +2677,7 @@ pub const PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_BITS: u32 = 0x00000008;
 pub const PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_MSB: u32 = 3;
 pub const PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_LSB: u32 = 3;
-pub const PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY
 // Description : None
@@ -2682,7 +2685,7 @@ pub const PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_BITS: u32 = 0x00000004;
 pub const PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_MSB: u32 = 2;
 pub const PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_LSB: u32 = 2;
-pub const PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY
 // Description : None
@@ -2690,7 +2693,7 @@ pub const PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_BITS: u32 = 0x00000002;
 pub const PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_MSB: u32 = 1;
 pub const PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_LSB: u32 = 1;
-pub const PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY
 // Description : None
@@ -2698,7 +2701,7 @@ pub const PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_BITS: u32 = 0x00000001;
 pub const PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_MSB: u32 = 0;
 pub const PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_LSB: u32 = 0;
-pub const PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_IRQ0_INTS
 // Description : Interrupt status after masking & forcing for irq0
@@ -2713,7 +2716,7 @@ pub const PROC_PIO_IRQ0_INTS_SM3_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM3_BITS: u32 = 0x00000800;
 pub const PROC_PIO_IRQ0_INTS_SM3_MSB: u32 = 11;
 pub const PROC_PIO_IRQ0_INTS_SM3_LSB: u32 = 11;
-pub const PROC_PIO_IRQ0_INTS_SM3_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM3_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM2
 // Description : None
@@ -2721,7 +2724,7 @@ pub const PROC_PIO_IRQ0_INTS_SM2_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM2_BITS: u32 = 0x00000400;
 pub const PROC_PIO_IRQ0_INTS_SM2_MSB: u32 = 10;
 pub const PROC_PIO_IRQ0_INTS_SM2_LSB: u32 = 10;
-pub const PROC_PIO_IRQ0_INTS_SM2_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM2_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM1
 // Description : None
@@ -2729,7 +2732,7 @@ pub const PROC_PIO_IRQ0_INTS_SM1_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM1_BITS: u32 = 0x00000200;
 pub const PROC_PIO_IRQ0_INTS_SM1_MSB: u32 = 9;
 pub const PROC_PIO_IRQ0_INTS_SM1_LSB: u32 = 9;
-pub const PROC_PIO_IRQ0_INTS_SM1_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM1_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM0
 // Description : None
@@ -2737,7 +2740,7 @@ pub const PROC_PIO_IRQ0_INTS_SM0_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM0_BITS: u32 = 0x00000100;
 pub const PROC_PIO_IRQ0_INTS_SM0_MSB: u32 = 8;
 pub const PROC_PIO_IRQ0_INTS_SM0_LSB: u32 = 8;
-pub const PROC_PIO_IRQ0_INTS_SM0_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM0_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM3_TXNFULL
 // Description : None
@@ -2745,7 +2748,7 @@ pub const PROC_PIO_IRQ0_INTS_SM3_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM3_TXNFULL_BITS: u32 = 0x00000080;
 pub const PROC_PIO_IRQ0_INTS_SM3_TXNFULL_MSB: u32 = 7;
 pub const PROC_PIO_IRQ0_INTS_SM3_TXNFULL_LSB: u32 = 7;
-pub const PROC_PIO_IRQ0_INTS_SM3_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM3_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM2_TXNFULL
 // Description : None
@@ -2753,7 +2756,7 @@ pub const PROC_PIO_IRQ0_INTS_SM2_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM2_TXNFULL_BITS: u32 = 0x00000040;
 pub const PROC_PIO_IRQ0_INTS_SM2_TXNFULL_MSB: u32 = 6;
 pub const PROC_PIO_IRQ0_INTS_SM2_TXNFULL_LSB: u32 = 6;
-pub const PROC_PIO_IRQ0_INTS_SM2_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM2_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM1_TXNFULL
 // Description : None
@@ -2761,7 +2764,7 @@ pub const PROC_PIO_IRQ0_INTS_SM1_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM1_TXNFULL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_IRQ0_INTS_SM1_TXNFULL_MSB: u32 = 5;
 pub const PROC_PIO_IRQ0_INTS_SM1_TXNFULL_LSB: u32 = 5;
-pub const PROC_PIO_IRQ0_INTS_SM1_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM1_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM0_TXNFULL
 // Description : None
@@ -2769,7 +2772,7 @@ pub const PROC_PIO_IRQ0_INTS_SM0_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM0_TXNFULL_BITS: u32 = 0x00000010;
 pub const PROC_PIO_IRQ0_INTS_SM0_TXNFULL_MSB: u32 = 4;
 pub const PROC_PIO_IRQ0_INTS_SM0_TXNFULL_LSB: u32 = 4;
-pub const PROC_PIO_IRQ0_INTS_SM0_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM0_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY
 // Description : None
@@ -2777,7 +2780,7 @@ pub const PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_BITS: u32 = 0x00000008;
 pub const PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_MSB: u32 = 3;
 pub const PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_LSB: u32 = 3;
-pub const PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY
 // Description : None
@@ -2785,7 +2788,7 @@ pub const PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_BITS: u32 = 0x00000004;
 pub const PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_MSB: u32 = 2;
 pub const PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_LSB: u32 = 2;
-pub const PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY
 // Description : None
@@ -2793,7 +2796,7 @@ pub const PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_BITS: u32 = 0x00000002;
 pub const PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_MSB: u32 = 1;
 pub const PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_LSB: u32 = 1;
-pub const PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY
 // Description : None
@@ -2801,7 +2804,7 @@ pub const PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_BITS: u32 = 0x00000001;
 pub const PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_MSB: u32 = 0;
 pub const PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_LSB: u32 = 0;
-pub const PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_IRQ1_INTE
 // Description : Interrupt Enable for irq1
@@ -2816,7 +2819,7 @@ pub const PROC_PIO_IRQ1_INTE_SM3_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM3_BITS: u32 = 0x00000800;
 pub const PROC_PIO_IRQ1_INTE_SM3_MSB: u32 = 11;
 pub const PROC_PIO_IRQ1_INTE_SM3_LSB: u32 = 11;
-pub const PROC_PIO_IRQ1_INTE_SM3_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM3_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM2
 // Description : None
@@ -2824,7 +2827,7 @@ pub const PROC_PIO_IRQ1_INTE_SM2_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM2_BITS: u32 = 0x00000400;
 pub const PROC_PIO_IRQ1_INTE_SM2_MSB: u32 = 10;
 pub const PROC_PIO_IRQ1_INTE_SM2_LSB: u32 = 10;
-pub const PROC_PIO_IRQ1_INTE_SM2_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM2_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM1
 // Description : None
@@ -2832,7 +2835,7 @@ pub const PROC_PIO_IRQ1_INTE_SM1_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM1_BITS: u32 = 0x00000200;
 pub const PROC_PIO_IRQ1_INTE_SM1_MSB: u32 = 9;
 pub const PROC_PIO_IRQ1_INTE_SM1_LSB: u32 = 9;
-pub const PROC_PIO_IRQ1_INTE_SM1_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM1_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM0
 // Description : None
@@ -2840,7 +2843,7 @@ pub const PROC_PIO_IRQ1_INTE_SM0_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM0_BITS: u32 = 0x00000100;
 pub const PROC_PIO_IRQ1_INTE_SM0_MSB: u32 = 8;
 pub const PROC_PIO_IRQ1_INTE_SM0_LSB: u32 = 8;
-pub const PROC_PIO_IRQ1_INTE_SM0_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM0_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM3_TXNFULL
 // Description : None
@@ -2848,7 +2851,7 @@ pub const PROC_PIO_IRQ1_INTE_SM3_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM3_TXNFULL_BITS: u32 = 0x00000080;
 pub const PROC_PIO_IRQ1_INTE_SM3_TXNFULL_MSB: u32 = 7;
 pub const PROC_PIO_IRQ1_INTE_SM3_TXNFULL_LSB: u32 = 7;
-pub const PROC_PIO_IRQ1_INTE_SM3_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM3_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM2_TXNFULL
 // Description : None
@@ -2856,7 +2859,7 @@ pub const PROC_PIO_IRQ1_INTE_SM2_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM2_TXNFULL_BITS: u32 = 0x00000040;
 pub const PROC_PIO_IRQ1_INTE_SM2_TXNFULL_MSB: u32 = 6;
 pub const PROC_PIO_IRQ1_INTE_SM2_TXNFULL_LSB: u32 = 6;
-pub const PROC_PIO_IRQ1_INTE_SM2_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM2_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM1_TXNFULL
 // Description : None
@@ -2864,7 +2867,7 @@ pub const PROC_PIO_IRQ1_INTE_SM1_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM1_TXNFULL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_IRQ1_INTE_SM1_TXNFULL_MSB: u32 = 5;
 pub const PROC_PIO_IRQ1_INTE_SM1_TXNFULL_LSB: u32 = 5;
-pub const PROC_PIO_IRQ1_INTE_SM1_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM1_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM0_TXNFULL
 // Description : None
@@ -2872,7 +2875,7 @@ pub const PROC_PIO_IRQ1_INTE_SM0_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM0_TXNFULL_BITS: u32 = 0x00000010;
 pub const PROC_PIO_IRQ1_INTE_SM0_TXNFULL_MSB: u32 = 4;
 pub const PROC_PIO_IRQ1_INTE_SM0_TXNFULL_LSB: u32 = 4;
-pub const PROC_PIO_IRQ1_INTE_SM0_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM0_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY
 // Description : None
@@ -2880,7 +2883,7 @@ pub const PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_BITS: u32 = 0x00000008;
 pub const PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_MSB: u32 = 3;
 pub const PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_LSB: u32 = 3;
-pub const PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY
 // Description : None
@@ -2888,7 +2891,7 @@ pub const PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_BITS: u32 = 0x00000004;
 pub const PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_MSB: u32 = 2;
 pub const PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_LSB: u32 = 2;
-pub const PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY
 // Description : None
@@ -2896,7 +2899,7 @@ pub const PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_BITS: u32 = 0x00000002;
 pub const PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_MSB: u32 = 1;
 pub const PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_LSB: u32 = 1;
-pub const PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY
 // Description : None
@@ -2904,7 +2907,7 @@ pub const PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_BITS: u32 = 0x00000001;
 pub const PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_MSB: u32 = 0;
 pub const PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_LSB: u32 = 0;
-pub const PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_IRQ1_INTF
 // Description : Interrupt Force for irq1
@@ -2919,7 +2922,7 @@ pub const PROC_PIO_IRQ1_INTF_SM3_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM3_BITS: u32 = 0x00000800;
 pub const PROC_PIO_IRQ1_INTF_SM3_MSB: u32 = 11;
 pub const PROC_PIO_IRQ1_INTF_SM3_LSB: u32 = 11;
-pub const PROC_PIO_IRQ1_INTF_SM3_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM3_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM2
 // Description : None
@@ -2927,7 +2930,7 @@ pub const PROC_PIO_IRQ1_INTF_SM2_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM2_BITS: u32 = 0x00000400;
 pub const PROC_PIO_IRQ1_INTF_SM2_MSB: u32 = 10;
 pub const PROC_PIO_IRQ1_INTF_SM2_LSB: u32 = 10;
-pub const PROC_PIO_IRQ1_INTF_SM2_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM2_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM1
 // Description : None
@@ -2935,7 +2938,7 @@ pub const PROC_PIO_IRQ1_INTF_SM1_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM1_BITS: u32 = 0x00000200;
 pub const PROC_PIO_IRQ1_INTF_SM1_MSB: u32 = 9;
 pub const PROC_PIO_IRQ1_INTF_SM1_LSB: u32 = 9;
-pub const PROC_PIO_IRQ1_INTF_SM1_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM1_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM0
 // Description : None
@@ -2943,7 +2946,7 @@ pub const PROC_PIO_IRQ1_INTF_SM0_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM0_BITS: u32 = 0x00000100;
 pub const PROC_PIO_IRQ1_INTF_SM0_MSB: u32 = 8;
 pub const PROC_PIO_IRQ1_INTF_SM0_LSB: u32 = 8;
-pub const PROC_PIO_IRQ1_INTF_SM0_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM0_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM3_TXNFULL
 // Description : None
@@ -2951,7 +2954,7 @@ pub const PROC_PIO_IRQ1_INTF_SM3_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM3_TXNFULL_BITS: u32 = 0x00000080;
 pub const PROC_PIO_IRQ1_INTF_SM3_TXNFULL_MSB: u32 = 7;
 pub const PROC_PIO_IRQ1_INTF_SM3_TXNFULL_LSB: u32 = 7;
-pub const PROC_PIO_IRQ1_INTF_SM3_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM3_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM2_TXNFULL
 // Description : None
@@ -2959,7 +2962,7 @@ pub const PROC_PIO_IRQ1_INTF_SM2_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM2_TXNFULL_BITS: u32 = 0x00000040;
 pub const PROC_PIO_IRQ1_INTF_SM2_TXNFULL_MSB: u32 = 6;
 pub const PROC_PIO_IRQ1_INTF_SM2_TXNFULL_LSB: u32 = 6;
-pub const PROC_PIO_IRQ1_INTF_SM2_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM2_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM1_TXNFULL
 // Description : None
@@ -2967,7 +2970,7 @@ pub const PROC_PIO_IRQ1_INTF_SM1_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM1_TXNFULL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_IRQ1_INTF_SM1_TXNFULL_MSB: u32 = 5;
 pub const PROC_PIO_IRQ1_INTF_SM1_TXNFULL_LSB: u32 = 5;
-pub const PROC_PIO_IRQ1_INTF_SM1_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM1_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM0_TXNFULL
 // Description : None
@@ -2975,7 +2978,7 @@ pub const PROC_PIO_IRQ1_INTF_SM0_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM0_TXNFULL_BITS: u32 = 0x00000010;
 pub const PROC_PIO_IRQ1_INTF_SM0_TXNFULL_MSB: u32 = 4;
 pub const PROC_PIO_IRQ1_INTF_SM0_TXNFULL_LSB: u32 = 4;
-pub const PROC_PIO_IRQ1_INTF_SM0_TXNFULL_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM0_TXNFULL_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY
 // Description : None
@@ -2983,7 +2986,7 @@ pub const PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_BITS: u32 = 0x00000008;
 pub const PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_MSB: u32 = 3;
 pub const PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_LSB: u32 = 3;
-pub const PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY
 // Description : None
@@ -2991,7 +2994,7 @@ pub const PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_BITS: u32 = 0x00000004;
 pub const PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_MSB: u32 = 2;
 pub const PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_LSB: u32 = 2;
-pub const PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY
 // Description : None
@@ -2999,7 +3002,7 @@ pub const PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_BITS: u32 = 0x00000002;
 pub const PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_MSB: u32 = 1;
 pub const PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_LSB: u32 = 1;
-pub const PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_ACCESS: Access = Access::RW;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY
 // Description : None
@@ -3007,7 +3010,7 @@ pub const PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_BITS: u32 = 0x00000001;
 pub const PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_MSB: u32 = 0;
 pub const PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_LSB: u32 = 0;
-pub const PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_ACCESS: &str = "RW";
+pub const PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_IRQ1_INTS
 // Description : Interrupt status after masking & forcing for irq1
@@ -3022,7 +3025,7 @@ pub const PROC_PIO_IRQ1_INTS_SM3_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM3_BITS: u32 = 0x00000800;
 pub const PROC_PIO_IRQ1_INTS_SM3_MSB: u32 = 11;
 pub const PROC_PIO_IRQ1_INTS_SM3_LSB: u32 = 11;
-pub const PROC_PIO_IRQ1_INTS_SM3_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM3_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM2
 // Description : None
@@ -3030,7 +3033,7 @@ pub const PROC_PIO_IRQ1_INTS_SM2_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM2_BITS: u32 = 0x00000400;
 pub const PROC_PIO_IRQ1_INTS_SM2_MSB: u32 = 10;
 pub const PROC_PIO_IRQ1_INTS_SM2_LSB: u32 = 10;
-pub const PROC_PIO_IRQ1_INTS_SM2_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM2_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM1
 // Description : None
@@ -3038,7 +3041,7 @@ pub const PROC_PIO_IRQ1_INTS_SM1_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM1_BITS: u32 = 0x00000200;
 pub const PROC_PIO_IRQ1_INTS_SM1_MSB: u32 = 9;
 pub const PROC_PIO_IRQ1_INTS_SM1_LSB: u32 = 9;
-pub const PROC_PIO_IRQ1_INTS_SM1_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM1_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM0
 // Description : None
@@ -3046,7 +3049,7 @@ pub const PROC_PIO_IRQ1_INTS_SM0_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM0_BITS: u32 = 0x00000100;
 pub const PROC_PIO_IRQ1_INTS_SM0_MSB: u32 = 8;
 pub const PROC_PIO_IRQ1_INTS_SM0_LSB: u32 = 8;
-pub const PROC_PIO_IRQ1_INTS_SM0_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM0_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM3_TXNFULL
 // Description : None
@@ -3054,7 +3057,7 @@ pub const PROC_PIO_IRQ1_INTS_SM3_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM3_TXNFULL_BITS: u32 = 0x00000080;
 pub const PROC_PIO_IRQ1_INTS_SM3_TXNFULL_MSB: u32 = 7;
 pub const PROC_PIO_IRQ1_INTS_SM3_TXNFULL_LSB: u32 = 7;
-pub const PROC_PIO_IRQ1_INTS_SM3_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM3_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM2_TXNFULL
 // Description : None
@@ -3062,7 +3065,7 @@ pub const PROC_PIO_IRQ1_INTS_SM2_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM2_TXNFULL_BITS: u32 = 0x00000040;
 pub const PROC_PIO_IRQ1_INTS_SM2_TXNFULL_MSB: u32 = 6;
 pub const PROC_PIO_IRQ1_INTS_SM2_TXNFULL_LSB: u32 = 6;
-pub const PROC_PIO_IRQ1_INTS_SM2_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM2_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM1_TXNFULL
 // Description : None
@@ -3070,7 +3073,7 @@ pub const PROC_PIO_IRQ1_INTS_SM1_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM1_TXNFULL_BITS: u32 = 0x00000020;
 pub const PROC_PIO_IRQ1_INTS_SM1_TXNFULL_MSB: u32 = 5;
 pub const PROC_PIO_IRQ1_INTS_SM1_TXNFULL_LSB: u32 = 5;
-pub const PROC_PIO_IRQ1_INTS_SM1_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM1_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM0_TXNFULL
 // Description : None
@@ -3078,7 +3081,7 @@ pub const PROC_PIO_IRQ1_INTS_SM0_TXNFULL_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM0_TXNFULL_BITS: u32 = 0x00000010;
 pub const PROC_PIO_IRQ1_INTS_SM0_TXNFULL_MSB: u32 = 4;
 pub const PROC_PIO_IRQ1_INTS_SM0_TXNFULL_LSB: u32 = 4;
-pub const PROC_PIO_IRQ1_INTS_SM0_TXNFULL_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM0_TXNFULL_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY
 // Description : None
@@ -3086,7 +3089,7 @@ pub const PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_BITS: u32 = 0x00000008;
 pub const PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_MSB: u32 = 3;
 pub const PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_LSB: u32 = 3;
-pub const PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY
 // Description : None
@@ -3094,7 +3097,7 @@ pub const PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_BITS: u32 = 0x00000004;
 pub const PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_MSB: u32 = 2;
 pub const PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_LSB: u32 = 2;
-pub const PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY
 // Description : None
@@ -3102,7 +3105,7 @@ pub const PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_BITS: u32 = 0x00000002;
 pub const PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_MSB: u32 = 1;
 pub const PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_LSB: u32 = 1;
-pub const PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_ACCESS: Access = Access::RO;
 // -----------------------------------------------------------------------------
 // Field       : PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY
 // Description : None
@@ -3110,7 +3113,7 @@ pub const PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_RESET: u32 = 0x0;
 pub const PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_BITS: u32 = 0x00000001;
 pub const PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_MSB: u32 = 0;
 pub const PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_LSB: u32 = 0;
-pub const PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_ACCESS: &str = "RO";
+pub const PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_BLOCK_ID
 // Description : Block Identifier
@@ -3121,7 +3124,7 @@ pub const PROC_PIO_BLOCK_ID_RESET: u32 = 0x70696f32;
 pub const PROC_PIO_BLOCK_ID_WIDTH: u32 = 32;
 pub const PROC_PIO_BLOCK_ID_MSB: u32 = 31;
 pub const PROC_PIO_BLOCK_ID_LSB: u32 = 0;
-pub const PROC_PIO_BLOCK_ID_ACCESS: &str = "RO";
+pub const PROC_PIO_BLOCK_ID_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_INSTANCE_ID
 // Description : Block Instance Identifier
@@ -3131,7 +3134,7 @@ pub const PROC_PIO_INSTANCE_ID_RESET: u32 = 0x00000000;
 pub const PROC_PIO_INSTANCE_ID_WIDTH: u32 = 32;
 pub const PROC_PIO_INSTANCE_ID_MSB: u32 = 3;
 pub const PROC_PIO_INSTANCE_ID_LSB: u32 = 0;
-pub const PROC_PIO_INSTANCE_ID_ACCESS: &str = "RO";
+pub const PROC_PIO_INSTANCE_ID_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_RSTSEQ_AUTO
 // Description : None
@@ -3147,7 +3150,7 @@ pub const PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_RESET: u32 = 0x1;
 pub const PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_BITS: u32 = 0x00000001;
 pub const PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_MSB: u32 = 0;
 pub const PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_LSB: u32 = 0;
-pub const PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_ACCESS: &str = "RW";
+pub const PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_RSTSEQ_PARALLEL
 // Description : None
@@ -3162,7 +3165,7 @@ pub const PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_RESET: u32 = 0x0;
 pub const PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_BITS: u32 = 0x00000001;
 pub const PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_MSB: u32 = 0;
 pub const PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_LSB: u32 = 0;
-pub const PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_ACCESS: &str = "RO";
+pub const PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_ACCESS: Access = Access::RO;
 // =============================================================================
 // Register    : PROC_PIO_RSTSEQ_CTRL
 // Description : None
@@ -3179,7 +3182,7 @@ pub const PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_RESET: u32 = 0x0;
 pub const PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_BITS: u32 = 0x00000001;
 pub const PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_MSB: u32 = 0;
 pub const PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_LSB: u32 = 0;
-pub const PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_ACCESS: &str = "RW";
+pub const PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_ACCESS: Access = Access::RW;
 // =============================================================================
 // Register    : PROC_PIO_RSTSEQ_TRIG
 // Description : None
@@ -3194,7 +3197,7 @@ pub const PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_RESET: u32 = 0x0;
 pub const PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_BITS: u32 = 0x00000001;
 pub const PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_MSB: u32 = 0;
 pub const PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_LSB: u32 = 0;
-pub const PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_ACCESS: &str = "SC";
+pub const PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_ACCESS: Access = Access::SC;
 // =============================================================================
 // Register    : PROC_PIO_RSTSEQ_DONE
 // Description : None
@@ -3209,5 +3212,3743 @@ pub const PROC_PIO_RSTSEQ_DONE_BUSADAPTER_RESET: u32 = 0x0;
 pub const PROC_PIO_RSTSEQ_DONE_BUSADAPTER_BITS: u32 = 0x00000001;
 pub const PROC_PIO_RSTSEQ_DONE_BUSADAPTER_MSB: u32 = 0;
 pub const PROC_PIO_RSTSEQ_DONE_BUSADAPTER_LSB: u32 = 0;
-pub const PROC_PIO_RSTSEQ_DONE_BUSADAPTER_ACCESS: &str = "RO";
+pub const PROC_PIO_RSTSEQ_DONE_BUSADAPTER_ACCESS: Access = Access::RO;
 // =============================================================================
+// Register table : every register above with its fields, in offset order
+pub static PROC_PIO_REGISTERS: &[RegisterInfo] = &[
+    RegisterInfo {
+        name: "CTRL",
+        offset: PROC_PIO_CTRL_OFFSET,
+        bits: PROC_PIO_CTRL_BITS,
+        reset: Some(PROC_PIO_CTRL_RESET),
+        description: "PIO control register",
+        fields: &[
+            FieldInfo {
+                name: "CLKDIV_RESTART",
+                bits: PROC_PIO_CTRL_CLKDIV_RESTART_BITS,
+                msb: PROC_PIO_CTRL_CLKDIV_RESTART_MSB,
+                lsb: PROC_PIO_CTRL_CLKDIV_RESTART_LSB,
+                reset: Some(PROC_PIO_CTRL_CLKDIV_RESTART_RESET),
+                access: PROC_PIO_CTRL_CLKDIV_RESTART_ACCESS,
+                description: "Force clock dividers to restart their count and clear fractional accumulators. Restart multiple dividers to synchronise them.",
+            },
+            FieldInfo {
+                name: "SM_RESTART",
+                bits: PROC_PIO_CTRL_SM_RESTART_BITS,
+                msb: PROC_PIO_CTRL_SM_RESTART_MSB,
+                lsb: PROC_PIO_CTRL_SM_RESTART_LSB,
+                reset: Some(PROC_PIO_CTRL_SM_RESTART_RESET),
+                access: PROC_PIO_CTRL_SM_RESTART_ACCESS,
+                description: "Clear internal SM state which is otherwise difficult to access (e.g. shift counters). Self-clearing.",
+            },
+            FieldInfo {
+                name: "SM_ENABLE",
+                bits: PROC_PIO_CTRL_SM_ENABLE_BITS,
+                msb: PROC_PIO_CTRL_SM_ENABLE_MSB,
+                lsb: PROC_PIO_CTRL_SM_ENABLE_LSB,
+                reset: Some(PROC_PIO_CTRL_SM_ENABLE_RESET),
+                access: PROC_PIO_CTRL_SM_ENABLE_ACCESS,
+                description: "Enable state machine",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "FSTAT",
+        offset: PROC_PIO_FSTAT_OFFSET,
+        bits: PROC_PIO_FSTAT_BITS,
+        reset: Some(PROC_PIO_FSTAT_RESET),
+        description: "FIFO status register",
+        fields: &[
+            FieldInfo {
+                name: "TXEMPTY",
+                bits: PROC_PIO_FSTAT_TXEMPTY_BITS,
+                msb: PROC_PIO_FSTAT_TXEMPTY_MSB,
+                lsb: PROC_PIO_FSTAT_TXEMPTY_LSB,
+                reset: Some(PROC_PIO_FSTAT_TXEMPTY_RESET),
+                access: PROC_PIO_FSTAT_TXEMPTY_ACCESS,
+                description: "State machine TX FIFO is empty",
+            },
+            FieldInfo {
+                name: "TXFULL",
+                bits: PROC_PIO_FSTAT_TXFULL_BITS,
+                msb: PROC_PIO_FSTAT_TXFULL_MSB,
+                lsb: PROC_PIO_FSTAT_TXFULL_LSB,
+                reset: Some(PROC_PIO_FSTAT_TXFULL_RESET),
+                access: PROC_PIO_FSTAT_TXFULL_ACCESS,
+                description: "State machine TX FIFO is full",
+            },
+            FieldInfo {
+                name: "RXEMPTY",
+                bits: PROC_PIO_FSTAT_RXEMPTY_BITS,
+                msb: PROC_PIO_FSTAT_RXEMPTY_MSB,
+                lsb: PROC_PIO_FSTAT_RXEMPTY_LSB,
+                reset: Some(PROC_PIO_FSTAT_RXEMPTY_RESET),
+                access: PROC_PIO_FSTAT_RXEMPTY_ACCESS,
+                description: "State machine RX FIFO is empty",
+            },
+            FieldInfo {
+                name: "RXFULL",
+                bits: PROC_PIO_FSTAT_RXFULL_BITS,
+                msb: PROC_PIO_FSTAT_RXFULL_MSB,
+                lsb: PROC_PIO_FSTAT_RXFULL_LSB,
+                reset: Some(PROC_PIO_FSTAT_RXFULL_RESET),
+                access: PROC_PIO_FSTAT_RXFULL_ACCESS,
+                description: "State machine RX FIFO is full",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "FDEBUG",
+        offset: PROC_PIO_FDEBUG_OFFSET,
+        bits: PROC_PIO_FDEBUG_BITS,
+        reset: Some(PROC_PIO_FDEBUG_RESET),
+        description: "FIFO debug register",
+        fields: &[
+            FieldInfo {
+                name: "TXSTALL",
+                bits: PROC_PIO_FDEBUG_TXSTALL_BITS,
+                msb: PROC_PIO_FDEBUG_TXSTALL_MSB,
+                lsb: PROC_PIO_FDEBUG_TXSTALL_LSB,
+                reset: Some(PROC_PIO_FDEBUG_TXSTALL_RESET),
+                access: PROC_PIO_FDEBUG_TXSTALL_ACCESS,
+                description: "State machine has stalled on empty TX FIFO. Write 1 to clear.",
+            },
+            FieldInfo {
+                name: "TXOVER",
+                bits: PROC_PIO_FDEBUG_TXOVER_BITS,
+                msb: PROC_PIO_FDEBUG_TXOVER_MSB,
+                lsb: PROC_PIO_FDEBUG_TXOVER_LSB,
+                reset: Some(PROC_PIO_FDEBUG_TXOVER_RESET),
+                access: PROC_PIO_FDEBUG_TXOVER_ACCESS,
+                description: "TX FIFO overflow has occurred. Write 1 to clear.",
+            },
+            FieldInfo {
+                name: "RXUNDER",
+                bits: PROC_PIO_FDEBUG_RXUNDER_BITS,
+                msb: PROC_PIO_FDEBUG_RXUNDER_MSB,
+                lsb: PROC_PIO_FDEBUG_RXUNDER_LSB,
+                reset: Some(PROC_PIO_FDEBUG_RXUNDER_RESET),
+                access: PROC_PIO_FDEBUG_RXUNDER_ACCESS,
+                description: "RX FIFO underflow has occurred. Write 1 to clear.",
+            },
+            FieldInfo {
+                name: "RXSTALL",
+                bits: PROC_PIO_FDEBUG_RXSTALL_BITS,
+                msb: PROC_PIO_FDEBUG_RXSTALL_MSB,
+                lsb: PROC_PIO_FDEBUG_RXSTALL_LSB,
+                reset: Some(PROC_PIO_FDEBUG_RXSTALL_RESET),
+                access: PROC_PIO_FDEBUG_RXSTALL_ACCESS,
+                description: "State machine has stalled on full RX FIFO. Write 1 to clear.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "FLEVEL",
+        offset: PROC_PIO_FLEVEL_OFFSET,
+        bits: PROC_PIO_FLEVEL_BITS,
+        reset: Some(PROC_PIO_FLEVEL_RESET),
+        description: "FIFO levels These count up to 15 only. When counting higher the extra bit is set in flevel2 register and this value saturates",
+        fields: &[
+            FieldInfo {
+                name: "RX3",
+                bits: PROC_PIO_FLEVEL_RX3_BITS,
+                msb: PROC_PIO_FLEVEL_RX3_MSB,
+                lsb: PROC_PIO_FLEVEL_RX3_LSB,
+                reset: Some(PROC_PIO_FLEVEL_RX3_RESET),
+                access: PROC_PIO_FLEVEL_RX3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX3",
+                bits: PROC_PIO_FLEVEL_TX3_BITS,
+                msb: PROC_PIO_FLEVEL_TX3_MSB,
+                lsb: PROC_PIO_FLEVEL_TX3_LSB,
+                reset: Some(PROC_PIO_FLEVEL_TX3_RESET),
+                access: PROC_PIO_FLEVEL_TX3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "RX2",
+                bits: PROC_PIO_FLEVEL_RX2_BITS,
+                msb: PROC_PIO_FLEVEL_RX2_MSB,
+                lsb: PROC_PIO_FLEVEL_RX2_LSB,
+                reset: Some(PROC_PIO_FLEVEL_RX2_RESET),
+                access: PROC_PIO_FLEVEL_RX2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX2",
+                bits: PROC_PIO_FLEVEL_TX2_BITS,
+                msb: PROC_PIO_FLEVEL_TX2_MSB,
+                lsb: PROC_PIO_FLEVEL_TX2_LSB,
+                reset: Some(PROC_PIO_FLEVEL_TX2_RESET),
+                access: PROC_PIO_FLEVEL_TX2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "RX1",
+                bits: PROC_PIO_FLEVEL_RX1_BITS,
+                msb: PROC_PIO_FLEVEL_RX1_MSB,
+                lsb: PROC_PIO_FLEVEL_RX1_LSB,
+                reset: Some(PROC_PIO_FLEVEL_RX1_RESET),
+                access: PROC_PIO_FLEVEL_RX1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX1",
+                bits: PROC_PIO_FLEVEL_TX1_BITS,
+                msb: PROC_PIO_FLEVEL_TX1_MSB,
+                lsb: PROC_PIO_FLEVEL_TX1_LSB,
+                reset: Some(PROC_PIO_FLEVEL_TX1_RESET),
+                access: PROC_PIO_FLEVEL_TX1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "RX0",
+                bits: PROC_PIO_FLEVEL_RX0_BITS,
+                msb: PROC_PIO_FLEVEL_RX0_MSB,
+                lsb: PROC_PIO_FLEVEL_RX0_LSB,
+                reset: Some(PROC_PIO_FLEVEL_RX0_RESET),
+                access: PROC_PIO_FLEVEL_RX0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX0",
+                bits: PROC_PIO_FLEVEL_TX0_BITS,
+                msb: PROC_PIO_FLEVEL_TX0_MSB,
+                lsb: PROC_PIO_FLEVEL_TX0_LSB,
+                reset: Some(PROC_PIO_FLEVEL_TX0_RESET),
+                access: PROC_PIO_FLEVEL_TX0_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "FLEVEL2",
+        offset: PROC_PIO_FLEVEL2_OFFSET,
+        bits: PROC_PIO_FLEVEL2_BITS,
+        reset: Some(PROC_PIO_FLEVEL2_RESET),
+        description: "FIFO level extra bits These are only used in double fifo mode, and the fifo has more than 15 elements",
+        fields: &[
+            FieldInfo {
+                name: "RX3",
+                bits: PROC_PIO_FLEVEL2_RX3_BITS,
+                msb: PROC_PIO_FLEVEL2_RX3_MSB,
+                lsb: PROC_PIO_FLEVEL2_RX3_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_RX3_RESET),
+                access: PROC_PIO_FLEVEL2_RX3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX3",
+                bits: PROC_PIO_FLEVEL2_TX3_BITS,
+                msb: PROC_PIO_FLEVEL2_TX3_MSB,
+                lsb: PROC_PIO_FLEVEL2_TX3_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_TX3_RESET),
+                access: PROC_PIO_FLEVEL2_TX3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "RX2",
+                bits: PROC_PIO_FLEVEL2_RX2_BITS,
+                msb: PROC_PIO_FLEVEL2_RX2_MSB,
+                lsb: PROC_PIO_FLEVEL2_RX2_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_RX2_RESET),
+                access: PROC_PIO_FLEVEL2_RX2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX2",
+                bits: PROC_PIO_FLEVEL2_TX2_BITS,
+                msb: PROC_PIO_FLEVEL2_TX2_MSB,
+                lsb: PROC_PIO_FLEVEL2_TX2_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_TX2_RESET),
+                access: PROC_PIO_FLEVEL2_TX2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "RX1",
+                bits: PROC_PIO_FLEVEL2_RX1_BITS,
+                msb: PROC_PIO_FLEVEL2_RX1_MSB,
+                lsb: PROC_PIO_FLEVEL2_RX1_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_RX1_RESET),
+                access: PROC_PIO_FLEVEL2_RX1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX1",
+                bits: PROC_PIO_FLEVEL2_TX1_BITS,
+                msb: PROC_PIO_FLEVEL2_TX1_MSB,
+                lsb: PROC_PIO_FLEVEL2_TX1_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_TX1_RESET),
+                access: PROC_PIO_FLEVEL2_TX1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "RX0",
+                bits: PROC_PIO_FLEVEL2_RX0_BITS,
+                msb: PROC_PIO_FLEVEL2_RX0_MSB,
+                lsb: PROC_PIO_FLEVEL2_RX0_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_RX0_RESET),
+                access: PROC_PIO_FLEVEL2_RX0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "TX0",
+                bits: PROC_PIO_FLEVEL2_TX0_BITS,
+                msb: PROC_PIO_FLEVEL2_TX0_MSB,
+                lsb: PROC_PIO_FLEVEL2_TX0_LSB,
+                reset: Some(PROC_PIO_FLEVEL2_TX0_RESET),
+                access: PROC_PIO_FLEVEL2_TX0_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "TXF0",
+        offset: PROC_PIO_TXF0_OFFSET,
+        bits: PROC_PIO_TXF0_BITS,
+        reset: Some(PROC_PIO_TXF0_RESET),
+        description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "TXF0",
+                bits: PROC_PIO_TXF0_BITS,
+                msb: PROC_PIO_TXF0_MSB,
+                lsb: PROC_PIO_TXF0_LSB,
+                reset: Some(PROC_PIO_TXF0_RESET),
+                access: PROC_PIO_TXF0_ACCESS,
+                description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "TXF1",
+        offset: PROC_PIO_TXF1_OFFSET,
+        bits: PROC_PIO_TXF1_BITS,
+        reset: Some(PROC_PIO_TXF1_RESET),
+        description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "TXF1",
+                bits: PROC_PIO_TXF1_BITS,
+                msb: PROC_PIO_TXF1_MSB,
+                lsb: PROC_PIO_TXF1_LSB,
+                reset: Some(PROC_PIO_TXF1_RESET),
+                access: PROC_PIO_TXF1_ACCESS,
+                description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "TXF2",
+        offset: PROC_PIO_TXF2_OFFSET,
+        bits: PROC_PIO_TXF2_BITS,
+        reset: Some(PROC_PIO_TXF2_RESET),
+        description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "TXF2",
+                bits: PROC_PIO_TXF2_BITS,
+                msb: PROC_PIO_TXF2_MSB,
+                lsb: PROC_PIO_TXF2_LSB,
+                reset: Some(PROC_PIO_TXF2_RESET),
+                access: PROC_PIO_TXF2_ACCESS,
+                description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "TXF3",
+        offset: PROC_PIO_TXF3_OFFSET,
+        bits: PROC_PIO_TXF3_BITS,
+        reset: Some(PROC_PIO_TXF3_RESET),
+        description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "TXF3",
+                bits: PROC_PIO_TXF3_BITS,
+                msb: PROC_PIO_TXF3_MSB,
+                lsb: PROC_PIO_TXF3_LSB,
+                reset: Some(PROC_PIO_TXF3_RESET),
+                access: PROC_PIO_TXF3_ACCESS,
+                description: "Direct write access to the TX FIFO for this state machine. Each write pushes one word to the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RXF0",
+        offset: PROC_PIO_RXF0_OFFSET,
+        bits: PROC_PIO_RXF0_BITS,
+        reset: None,
+        description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "RXF0",
+                bits: PROC_PIO_RXF0_BITS,
+                msb: PROC_PIO_RXF0_MSB,
+                lsb: PROC_PIO_RXF0_LSB,
+                reset: None,
+                access: PROC_PIO_RXF0_ACCESS,
+                description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RXF1",
+        offset: PROC_PIO_RXF1_OFFSET,
+        bits: PROC_PIO_RXF1_BITS,
+        reset: None,
+        description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "RXF1",
+                bits: PROC_PIO_RXF1_BITS,
+                msb: PROC_PIO_RXF1_MSB,
+                lsb: PROC_PIO_RXF1_LSB,
+                reset: None,
+                access: PROC_PIO_RXF1_ACCESS,
+                description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RXF2",
+        offset: PROC_PIO_RXF2_OFFSET,
+        bits: PROC_PIO_RXF2_BITS,
+        reset: None,
+        description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "RXF2",
+                bits: PROC_PIO_RXF2_BITS,
+                msb: PROC_PIO_RXF2_MSB,
+                lsb: PROC_PIO_RXF2_LSB,
+                reset: None,
+                access: PROC_PIO_RXF2_ACCESS,
+                description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RXF3",
+        offset: PROC_PIO_RXF3_OFFSET,
+        bits: PROC_PIO_RXF3_BITS,
+        reset: None,
+        description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+        fields: &[
+            FieldInfo {
+                name: "RXF3",
+                bits: PROC_PIO_RXF3_BITS,
+                msb: PROC_PIO_RXF3_MSB,
+                lsb: PROC_PIO_RXF3_LSB,
+                reset: None,
+                access: PROC_PIO_RXF3_ACCESS,
+                description: "Direct read access to the RX FIFO for this state machine. Each read pops one word from the FIFO.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ",
+        offset: PROC_PIO_IRQ_OFFSET,
+        bits: PROC_PIO_IRQ_BITS,
+        reset: Some(PROC_PIO_IRQ_RESET),
+        description: "Interrupt request register. Write 1 to clear",
+        fields: &[
+            FieldInfo {
+                name: "IRQ",
+                bits: PROC_PIO_IRQ_BITS,
+                msb: PROC_PIO_IRQ_MSB,
+                lsb: PROC_PIO_IRQ_LSB,
+                reset: Some(PROC_PIO_IRQ_RESET),
+                access: PROC_PIO_IRQ_ACCESS,
+                description: "Interrupt request register. Write 1 to clear",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ_FORCE",
+        offset: PROC_PIO_IRQ_FORCE_OFFSET,
+        bits: PROC_PIO_IRQ_FORCE_BITS,
+        reset: Some(PROC_PIO_IRQ_FORCE_RESET),
+        description: "Writing a 1 to each of these bits will forcibly assert the corresponding IRQ. Note this is different to the INTF register: writing here affects PIO internal state. INTF just asserts the processor-facing IRQ signal for testing ISRs, and is not visible to the state machines.",
+        fields: &[
+            FieldInfo {
+                name: "IRQ_FORCE",
+                bits: PROC_PIO_IRQ_FORCE_BITS,
+                msb: PROC_PIO_IRQ_FORCE_MSB,
+                lsb: PROC_PIO_IRQ_FORCE_LSB,
+                reset: Some(PROC_PIO_IRQ_FORCE_RESET),
+                access: PROC_PIO_IRQ_FORCE_ACCESS,
+                description: "Writing a 1 to each of these bits will forcibly assert the corresponding IRQ. Note this is different to the INTF register: writing here affects PIO internal state. INTF just asserts the processor-facing IRQ signal for testing ISRs, and is not visible to the state machines.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INPUT_SYNC_BYPASS",
+        offset: PROC_PIO_INPUT_SYNC_BYPASS_OFFSET,
+        bits: PROC_PIO_INPUT_SYNC_BYPASS_BITS,
+        reset: Some(PROC_PIO_INPUT_SYNC_BYPASS_RESET),
+        description: "There is a 2-flipflop synchronizer on each GPIO input, which protects PIO logic from metastabilities. This increases input delay, and for fast synchronous IO (e.g. SPI) these synchronizers may need to be bypassed. Each bit in this register corresponds to one GPIO. 0 -> input is synchronized (default) 1 -> synchronizer is bypassed If in doubt, leave this register as all zeroes.",
+        fields: &[
+            FieldInfo {
+                name: "INPUT_SYNC_BYPASS",
+                bits: PROC_PIO_INPUT_SYNC_BYPASS_BITS,
+                msb: PROC_PIO_INPUT_SYNC_BYPASS_MSB,
+                lsb: PROC_PIO_INPUT_SYNC_BYPASS_LSB,
+                reset: Some(PROC_PIO_INPUT_SYNC_BYPASS_RESET),
+                access: PROC_PIO_INPUT_SYNC_BYPASS_ACCESS,
+                description: "There is a 2-flipflop synchronizer on each GPIO input, which protects PIO logic from metastabilities. This increases input delay, and for fast synchronous IO (e.g. SPI) these synchronizers may need to be bypassed. Each bit in this register corresponds to one GPIO. 0 -> input is synchronized (default) 1 -> synchronizer is bypassed If in doubt, leave this register as all zeroes.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "DBG_PADOUT",
+        offset: PROC_PIO_DBG_PADOUT_OFFSET,
+        bits: PROC_PIO_DBG_PADOUT_BITS,
+        reset: Some(PROC_PIO_DBG_PADOUT_RESET),
+        description: "Read to sample the pad output values PIO is currently driving to the GPIOs.",
+        fields: &[
+            FieldInfo {
+                name: "DBG_PADOUT",
+                bits: PROC_PIO_DBG_PADOUT_BITS,
+                msb: PROC_PIO_DBG_PADOUT_MSB,
+                lsb: PROC_PIO_DBG_PADOUT_LSB,
+                reset: Some(PROC_PIO_DBG_PADOUT_RESET),
+                access: PROC_PIO_DBG_PADOUT_ACCESS,
+                description: "Read to sample the pad output values PIO is currently driving to the GPIOs.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "DBG_PADOE",
+        offset: PROC_PIO_DBG_PADOE_OFFSET,
+        bits: PROC_PIO_DBG_PADOE_BITS,
+        reset: Some(PROC_PIO_DBG_PADOE_RESET),
+        description: "Read to sample the pad output enables (direction) PIO is currently driving to the GPIOs.",
+        fields: &[
+            FieldInfo {
+                name: "DBG_PADOE",
+                bits: PROC_PIO_DBG_PADOE_BITS,
+                msb: PROC_PIO_DBG_PADOE_MSB,
+                lsb: PROC_PIO_DBG_PADOE_LSB,
+                reset: Some(PROC_PIO_DBG_PADOE_RESET),
+                access: PROC_PIO_DBG_PADOE_ACCESS,
+                description: "Read to sample the pad output enables (direction) PIO is currently driving to the GPIOs.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "DBG_CFGINFO",
+        offset: PROC_PIO_DBG_CFGINFO_OFFSET,
+        bits: PROC_PIO_DBG_CFGINFO_BITS,
+        reset: Some(PROC_PIO_DBG_CFGINFO_RESET),
+        description: "The PIO hardware has some free parameters that may vary between chip products. These should be provided in the chip datasheet, but are also exposed here.",
+        fields: &[
+            FieldInfo {
+                name: "IMEM_SIZE",
+                bits: PROC_PIO_DBG_CFGINFO_IMEM_SIZE_BITS,
+                msb: PROC_PIO_DBG_CFGINFO_IMEM_SIZE_MSB,
+                lsb: PROC_PIO_DBG_CFGINFO_IMEM_SIZE_LSB,
+                reset: None,
+                access: PROC_PIO_DBG_CFGINFO_IMEM_SIZE_ACCESS,
+                description: "The size of the instruction memory, measured in units of one instruction",
+            },
+            FieldInfo {
+                name: "SM_COUNT",
+                bits: PROC_PIO_DBG_CFGINFO_SM_COUNT_BITS,
+                msb: PROC_PIO_DBG_CFGINFO_SM_COUNT_MSB,
+                lsb: PROC_PIO_DBG_CFGINFO_SM_COUNT_LSB,
+                reset: None,
+                access: PROC_PIO_DBG_CFGINFO_SM_COUNT_ACCESS,
+                description: "The number of state machines this PIO instance is equipped with.",
+            },
+            FieldInfo {
+                name: "FIFO_DEPTH",
+                bits: PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_BITS,
+                msb: PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_MSB,
+                lsb: PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_LSB,
+                reset: None,
+                access: PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_ACCESS,
+                description: "The depth of the state machine TX/RX FIFOs, measured in words. Joining fifos via SHIFTCTRL_FJOIN gives one FIFO with double this depth.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM0",
+        offset: PROC_PIO_INSTR_MEM0_OFFSET,
+        bits: PROC_PIO_INSTR_MEM0_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM0_RESET),
+        description: "Write-only access to instruction memory location 0",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM0",
+                bits: PROC_PIO_INSTR_MEM0_BITS,
+                msb: PROC_PIO_INSTR_MEM0_MSB,
+                lsb: PROC_PIO_INSTR_MEM0_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM0_RESET),
+                access: PROC_PIO_INSTR_MEM0_ACCESS,
+                description: "Write-only access to instruction memory location 0",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM1",
+        offset: PROC_PIO_INSTR_MEM1_OFFSET,
+        bits: PROC_PIO_INSTR_MEM1_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM1_RESET),
+        description: "Write-only access to instruction memory location 1",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM1",
+                bits: PROC_PIO_INSTR_MEM1_BITS,
+                msb: PROC_PIO_INSTR_MEM1_MSB,
+                lsb: PROC_PIO_INSTR_MEM1_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM1_RESET),
+                access: PROC_PIO_INSTR_MEM1_ACCESS,
+                description: "Write-only access to instruction memory location 1",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM2",
+        offset: PROC_PIO_INSTR_MEM2_OFFSET,
+        bits: PROC_PIO_INSTR_MEM2_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM2_RESET),
+        description: "Write-only access to instruction memory location 2",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM2",
+                bits: PROC_PIO_INSTR_MEM2_BITS,
+                msb: PROC_PIO_INSTR_MEM2_MSB,
+                lsb: PROC_PIO_INSTR_MEM2_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM2_RESET),
+                access: PROC_PIO_INSTR_MEM2_ACCESS,
+                description: "Write-only access to instruction memory location 2",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM3",
+        offset: PROC_PIO_INSTR_MEM3_OFFSET,
+        bits: PROC_PIO_INSTR_MEM3_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM3_RESET),
+        description: "Write-only access to instruction memory location 3",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM3",
+                bits: PROC_PIO_INSTR_MEM3_BITS,
+                msb: PROC_PIO_INSTR_MEM3_MSB,
+                lsb: PROC_PIO_INSTR_MEM3_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM3_RESET),
+                access: PROC_PIO_INSTR_MEM3_ACCESS,
+                description: "Write-only access to instruction memory location 3",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM4",
+        offset: PROC_PIO_INSTR_MEM4_OFFSET,
+        bits: PROC_PIO_INSTR_MEM4_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM4_RESET),
+        description: "Write-only access to instruction memory location 4",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM4",
+                bits: PROC_PIO_INSTR_MEM4_BITS,
+                msb: PROC_PIO_INSTR_MEM4_MSB,
+                lsb: PROC_PIO_INSTR_MEM4_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM4_RESET),
+                access: PROC_PIO_INSTR_MEM4_ACCESS,
+                description: "Write-only access to instruction memory location 4",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM5",
+        offset: PROC_PIO_INSTR_MEM5_OFFSET,
+        bits: PROC_PIO_INSTR_MEM5_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM5_RESET),
+        description: "Write-only access to instruction memory location 5",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM5",
+                bits: PROC_PIO_INSTR_MEM5_BITS,
+                msb: PROC_PIO_INSTR_MEM5_MSB,
+                lsb: PROC_PIO_INSTR_MEM5_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM5_RESET),
+                access: PROC_PIO_INSTR_MEM5_ACCESS,
+                description: "Write-only access to instruction memory location 5",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM6",
+        offset: PROC_PIO_INSTR_MEM6_OFFSET,
+        bits: PROC_PIO_INSTR_MEM6_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM6_RESET),
+        description: "Write-only access to instruction memory location 6",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM6",
+                bits: PROC_PIO_INSTR_MEM6_BITS,
+                msb: PROC_PIO_INSTR_MEM6_MSB,
+                lsb: PROC_PIO_INSTR_MEM6_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM6_RESET),
+                access: PROC_PIO_INSTR_MEM6_ACCESS,
+                description: "Write-only access to instruction memory location 6",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM7",
+        offset: PROC_PIO_INSTR_MEM7_OFFSET,
+        bits: PROC_PIO_INSTR_MEM7_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM7_RESET),
+        description: "Write-only access to instruction memory location 7",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM7",
+                bits: PROC_PIO_INSTR_MEM7_BITS,
+                msb: PROC_PIO_INSTR_MEM7_MSB,
+                lsb: PROC_PIO_INSTR_MEM7_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM7_RESET),
+                access: PROC_PIO_INSTR_MEM7_ACCESS,
+                description: "Write-only access to instruction memory location 7",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM8",
+        offset: PROC_PIO_INSTR_MEM8_OFFSET,
+        bits: PROC_PIO_INSTR_MEM8_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM8_RESET),
+        description: "Write-only access to instruction memory location 8",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM8",
+                bits: PROC_PIO_INSTR_MEM8_BITS,
+                msb: PROC_PIO_INSTR_MEM8_MSB,
+                lsb: PROC_PIO_INSTR_MEM8_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM8_RESET),
+                access: PROC_PIO_INSTR_MEM8_ACCESS,
+                description: "Write-only access to instruction memory location 8",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM9",
+        offset: PROC_PIO_INSTR_MEM9_OFFSET,
+        bits: PROC_PIO_INSTR_MEM9_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM9_RESET),
+        description: "Write-only access to instruction memory location 9",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM9",
+                bits: PROC_PIO_INSTR_MEM9_BITS,
+                msb: PROC_PIO_INSTR_MEM9_MSB,
+                lsb: PROC_PIO_INSTR_MEM9_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM9_RESET),
+                access: PROC_PIO_INSTR_MEM9_ACCESS,
+                description: "Write-only access to instruction memory location 9",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM10",
+        offset: PROC_PIO_INSTR_MEM10_OFFSET,
+        bits: PROC_PIO_INSTR_MEM10_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM10_RESET),
+        description: "Write-only access to instruction memory location 10",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM10",
+                bits: PROC_PIO_INSTR_MEM10_BITS,
+                msb: PROC_PIO_INSTR_MEM10_MSB,
+                lsb: PROC_PIO_INSTR_MEM10_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM10_RESET),
+                access: PROC_PIO_INSTR_MEM10_ACCESS,
+                description: "Write-only access to instruction memory location 10",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM11",
+        offset: PROC_PIO_INSTR_MEM11_OFFSET,
+        bits: PROC_PIO_INSTR_MEM11_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM11_RESET),
+        description: "Write-only access to instruction memory location 11",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM11",
+                bits: PROC_PIO_INSTR_MEM11_BITS,
+                msb: PROC_PIO_INSTR_MEM11_MSB,
+                lsb: PROC_PIO_INSTR_MEM11_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM11_RESET),
+                access: PROC_PIO_INSTR_MEM11_ACCESS,
+                description: "Write-only access to instruction memory location 11",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM12",
+        offset: PROC_PIO_INSTR_MEM12_OFFSET,
+        bits: PROC_PIO_INSTR_MEM12_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM12_RESET),
+        description: "Write-only access to instruction memory location 12",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM12",
+                bits: PROC_PIO_INSTR_MEM12_BITS,
+                msb: PROC_PIO_INSTR_MEM12_MSB,
+                lsb: PROC_PIO_INSTR_MEM12_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM12_RESET),
+                access: PROC_PIO_INSTR_MEM12_ACCESS,
+                description: "Write-only access to instruction memory location 12",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM13",
+        offset: PROC_PIO_INSTR_MEM13_OFFSET,
+        bits: PROC_PIO_INSTR_MEM13_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM13_RESET),
+        description: "Write-only access to instruction memory location 13",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM13",
+                bits: PROC_PIO_INSTR_MEM13_BITS,
+                msb: PROC_PIO_INSTR_MEM13_MSB,
+                lsb: PROC_PIO_INSTR_MEM13_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM13_RESET),
+                access: PROC_PIO_INSTR_MEM13_ACCESS,
+                description: "Write-only access to instruction memory location 13",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM14",
+        offset: PROC_PIO_INSTR_MEM14_OFFSET,
+        bits: PROC_PIO_INSTR_MEM14_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM14_RESET),
+        description: "Write-only access to instruction memory location 14",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM14",
+                bits: PROC_PIO_INSTR_MEM14_BITS,
+                msb: PROC_PIO_INSTR_MEM14_MSB,
+                lsb: PROC_PIO_INSTR_MEM14_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM14_RESET),
+                access: PROC_PIO_INSTR_MEM14_ACCESS,
+                description: "Write-only access to instruction memory location 14",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM15",
+        offset: PROC_PIO_INSTR_MEM15_OFFSET,
+        bits: PROC_PIO_INSTR_MEM15_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM15_RESET),
+        description: "Write-only access to instruction memory location 15",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM15",
+                bits: PROC_PIO_INSTR_MEM15_BITS,
+                msb: PROC_PIO_INSTR_MEM15_MSB,
+                lsb: PROC_PIO_INSTR_MEM15_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM15_RESET),
+                access: PROC_PIO_INSTR_MEM15_ACCESS,
+                description: "Write-only access to instruction memory location 15",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM16",
+        offset: PROC_PIO_INSTR_MEM16_OFFSET,
+        bits: PROC_PIO_INSTR_MEM16_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM16_RESET),
+        description: "Write-only access to instruction memory location 16",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM16",
+                bits: PROC_PIO_INSTR_MEM16_BITS,
+                msb: PROC_PIO_INSTR_MEM16_MSB,
+                lsb: PROC_PIO_INSTR_MEM16_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM16_RESET),
+                access: PROC_PIO_INSTR_MEM16_ACCESS,
+                description: "Write-only access to instruction memory location 16",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM17",
+        offset: PROC_PIO_INSTR_MEM17_OFFSET,
+        bits: PROC_PIO_INSTR_MEM17_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM17_RESET),
+        description: "Write-only access to instruction memory location 17",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM17",
+                bits: PROC_PIO_INSTR_MEM17_BITS,
+                msb: PROC_PIO_INSTR_MEM17_MSB,
+                lsb: PROC_PIO_INSTR_MEM17_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM17_RESET),
+                access: PROC_PIO_INSTR_MEM17_ACCESS,
+                description: "Write-only access to instruction memory location 17",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM18",
+        offset: PROC_PIO_INSTR_MEM18_OFFSET,
+        bits: PROC_PIO_INSTR_MEM18_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM18_RESET),
+        description: "Write-only access to instruction memory location 18",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM18",
+                bits: PROC_PIO_INSTR_MEM18_BITS,
+                msb: PROC_PIO_INSTR_MEM18_MSB,
+                lsb: PROC_PIO_INSTR_MEM18_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM18_RESET),
+                access: PROC_PIO_INSTR_MEM18_ACCESS,
+                description: "Write-only access to instruction memory location 18",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM19",
+        offset: PROC_PIO_INSTR_MEM19_OFFSET,
+        bits: PROC_PIO_INSTR_MEM19_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM19_RESET),
+        description: "Write-only access to instruction memory location 19",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM19",
+                bits: PROC_PIO_INSTR_MEM19_BITS,
+                msb: PROC_PIO_INSTR_MEM19_MSB,
+                lsb: PROC_PIO_INSTR_MEM19_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM19_RESET),
+                access: PROC_PIO_INSTR_MEM19_ACCESS,
+                description: "Write-only access to instruction memory location 19",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM20",
+        offset: PROC_PIO_INSTR_MEM20_OFFSET,
+        bits: PROC_PIO_INSTR_MEM20_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM20_RESET),
+        description: "Write-only access to instruction memory location 20",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM20",
+                bits: PROC_PIO_INSTR_MEM20_BITS,
+                msb: PROC_PIO_INSTR_MEM20_MSB,
+                lsb: PROC_PIO_INSTR_MEM20_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM20_RESET),
+                access: PROC_PIO_INSTR_MEM20_ACCESS,
+                description: "Write-only access to instruction memory location 20",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM21",
+        offset: PROC_PIO_INSTR_MEM21_OFFSET,
+        bits: PROC_PIO_INSTR_MEM21_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM21_RESET),
+        description: "Write-only access to instruction memory location 21",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM21",
+                bits: PROC_PIO_INSTR_MEM21_BITS,
+                msb: PROC_PIO_INSTR_MEM21_MSB,
+                lsb: PROC_PIO_INSTR_MEM21_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM21_RESET),
+                access: PROC_PIO_INSTR_MEM21_ACCESS,
+                description: "Write-only access to instruction memory location 21",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM22",
+        offset: PROC_PIO_INSTR_MEM22_OFFSET,
+        bits: PROC_PIO_INSTR_MEM22_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM22_RESET),
+        description: "Write-only access to instruction memory location 22",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM22",
+                bits: PROC_PIO_INSTR_MEM22_BITS,
+                msb: PROC_PIO_INSTR_MEM22_MSB,
+                lsb: PROC_PIO_INSTR_MEM22_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM22_RESET),
+                access: PROC_PIO_INSTR_MEM22_ACCESS,
+                description: "Write-only access to instruction memory location 22",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM23",
+        offset: PROC_PIO_INSTR_MEM23_OFFSET,
+        bits: PROC_PIO_INSTR_MEM23_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM23_RESET),
+        description: "Write-only access to instruction memory location 23",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM23",
+                bits: PROC_PIO_INSTR_MEM23_BITS,
+                msb: PROC_PIO_INSTR_MEM23_MSB,
+                lsb: PROC_PIO_INSTR_MEM23_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM23_RESET),
+                access: PROC_PIO_INSTR_MEM23_ACCESS,
+                description: "Write-only access to instruction memory location 23",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM24",
+        offset: PROC_PIO_INSTR_MEM24_OFFSET,
+        bits: PROC_PIO_INSTR_MEM24_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM24_RESET),
+        description: "Write-only access to instruction memory location 24",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM24",
+                bits: PROC_PIO_INSTR_MEM24_BITS,
+                msb: PROC_PIO_INSTR_MEM24_MSB,
+                lsb: PROC_PIO_INSTR_MEM24_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM24_RESET),
+                access: PROC_PIO_INSTR_MEM24_ACCESS,
+                description: "Write-only access to instruction memory location 24",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM25",
+        offset: PROC_PIO_INSTR_MEM25_OFFSET,
+        bits: PROC_PIO_INSTR_MEM25_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM25_RESET),
+        description: "Write-only access to instruction memory location 25",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM25",
+                bits: PROC_PIO_INSTR_MEM25_BITS,
+                msb: PROC_PIO_INSTR_MEM25_MSB,
+                lsb: PROC_PIO_INSTR_MEM25_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM25_RESET),
+                access: PROC_PIO_INSTR_MEM25_ACCESS,
+                description: "Write-only access to instruction memory location 25",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM26",
+        offset: PROC_PIO_INSTR_MEM26_OFFSET,
+        bits: PROC_PIO_INSTR_MEM26_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM26_RESET),
+        description: "Write-only access to instruction memory location 26",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM26",
+                bits: PROC_PIO_INSTR_MEM26_BITS,
+                msb: PROC_PIO_INSTR_MEM26_MSB,
+                lsb: PROC_PIO_INSTR_MEM26_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM26_RESET),
+                access: PROC_PIO_INSTR_MEM26_ACCESS,
+                description: "Write-only access to instruction memory location 26",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM27",
+        offset: PROC_PIO_INSTR_MEM27_OFFSET,
+        bits: PROC_PIO_INSTR_MEM27_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM27_RESET),
+        description: "Write-only access to instruction memory location 27",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM27",
+                bits: PROC_PIO_INSTR_MEM27_BITS,
+                msb: PROC_PIO_INSTR_MEM27_MSB,
+                lsb: PROC_PIO_INSTR_MEM27_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM27_RESET),
+                access: PROC_PIO_INSTR_MEM27_ACCESS,
+                description: "Write-only access to instruction memory location 27",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM28",
+        offset: PROC_PIO_INSTR_MEM28_OFFSET,
+        bits: PROC_PIO_INSTR_MEM28_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM28_RESET),
+        description: "Write-only access to instruction memory location 28",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM28",
+                bits: PROC_PIO_INSTR_MEM28_BITS,
+                msb: PROC_PIO_INSTR_MEM28_MSB,
+                lsb: PROC_PIO_INSTR_MEM28_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM28_RESET),
+                access: PROC_PIO_INSTR_MEM28_ACCESS,
+                description: "Write-only access to instruction memory location 28",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM29",
+        offset: PROC_PIO_INSTR_MEM29_OFFSET,
+        bits: PROC_PIO_INSTR_MEM29_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM29_RESET),
+        description: "Write-only access to instruction memory location 29",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM29",
+                bits: PROC_PIO_INSTR_MEM29_BITS,
+                msb: PROC_PIO_INSTR_MEM29_MSB,
+                lsb: PROC_PIO_INSTR_MEM29_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM29_RESET),
+                access: PROC_PIO_INSTR_MEM29_ACCESS,
+                description: "Write-only access to instruction memory location 29",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM30",
+        offset: PROC_PIO_INSTR_MEM30_OFFSET,
+        bits: PROC_PIO_INSTR_MEM30_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM30_RESET),
+        description: "Write-only access to instruction memory location 30",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM30",
+                bits: PROC_PIO_INSTR_MEM30_BITS,
+                msb: PROC_PIO_INSTR_MEM30_MSB,
+                lsb: PROC_PIO_INSTR_MEM30_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM30_RESET),
+                access: PROC_PIO_INSTR_MEM30_ACCESS,
+                description: "Write-only access to instruction memory location 30",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTR_MEM31",
+        offset: PROC_PIO_INSTR_MEM31_OFFSET,
+        bits: PROC_PIO_INSTR_MEM31_BITS,
+        reset: Some(PROC_PIO_INSTR_MEM31_RESET),
+        description: "Write-only access to instruction memory location 31",
+        fields: &[
+            FieldInfo {
+                name: "INSTR_MEM31",
+                bits: PROC_PIO_INSTR_MEM31_BITS,
+                msb: PROC_PIO_INSTR_MEM31_MSB,
+                lsb: PROC_PIO_INSTR_MEM31_LSB,
+                reset: Some(PROC_PIO_INSTR_MEM31_RESET),
+                access: PROC_PIO_INSTR_MEM31_ACCESS,
+                description: "Write-only access to instruction memory location 31",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_CLKDIV",
+        offset: PROC_PIO_SM0_CLKDIV_OFFSET,
+        bits: PROC_PIO_SM0_CLKDIV_BITS,
+        reset: Some(PROC_PIO_SM0_CLKDIV_RESET),
+        description: "Clock divider register for state machine 0 Frequency = clock freq / (CLKDIV_INT + CLKDIV_FRAC / 256)",
+        fields: &[
+            FieldInfo {
+                name: "INT",
+                bits: PROC_PIO_SM0_CLKDIV_INT_BITS,
+                msb: PROC_PIO_SM0_CLKDIV_INT_MSB,
+                lsb: PROC_PIO_SM0_CLKDIV_INT_LSB,
+                reset: Some(PROC_PIO_SM0_CLKDIV_INT_RESET),
+                access: PROC_PIO_SM0_CLKDIV_INT_ACCESS,
+                description: "Effective frequency is sysclk/int. Value of 0 is interpreted as max possible value",
+            },
+            FieldInfo {
+                name: "FRAC",
+                bits: PROC_PIO_SM0_CLKDIV_FRAC_BITS,
+                msb: PROC_PIO_SM0_CLKDIV_FRAC_MSB,
+                lsb: PROC_PIO_SM0_CLKDIV_FRAC_LSB,
+                reset: Some(PROC_PIO_SM0_CLKDIV_FRAC_RESET),
+                access: PROC_PIO_SM0_CLKDIV_FRAC_ACCESS,
+                description: "Fractional part of clock divider",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_EXECCTRL",
+        offset: PROC_PIO_SM0_EXECCTRL_OFFSET,
+        bits: PROC_PIO_SM0_EXECCTRL_BITS,
+        reset: Some(PROC_PIO_SM0_EXECCTRL_RESET),
+        description: "Execution/behavioural settings for state machine 0",
+        fields: &[
+            FieldInfo {
+                name: "EXEC_STALLED",
+                bits: PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_ACCESS,
+                description: "An instruction written to SMx_INSTR is stalled, and latched by the state machine. Will clear once the instruction completes.",
+            },
+            FieldInfo {
+                name: "SIDE_EN",
+                bits: PROC_PIO_SM0_EXECCTRL_SIDE_EN_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_SIDE_EN_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_SIDE_EN_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_SIDE_EN_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_SIDE_EN_ACCESS,
+                description: "If 1, the delay MSB is used as side-set enable, rather than a side-set data bit. This allows instructions to perform side-set optionally, rather than on every instruction.",
+            },
+            FieldInfo {
+                name: "SIDE_PINDIR",
+                bits: PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_ACCESS,
+                description: "Side-set data is asserted to pin OEs instead of pin values",
+            },
+            FieldInfo {
+                name: "JMP_PIN",
+                bits: PROC_PIO_SM0_EXECCTRL_JMP_PIN_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_JMP_PIN_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_JMP_PIN_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_JMP_PIN_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_JMP_PIN_ACCESS,
+                description: "The GPIO number to use as condition for JMP PIN. Unaffected by input mapping.",
+            },
+            FieldInfo {
+                name: "OUT_EN_SEL",
+                bits: PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_ACCESS,
+                description: "Which data bit to use for inline OUT enable",
+            },
+            FieldInfo {
+                name: "INLINE_OUT_EN",
+                bits: PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_ACCESS,
+                description: "If 1, use a bit of OUT data as an auxiliary write enable When used in conjunction with OUT_STICKY, writes with an enable of 0 will deassert the latest pin write. This can create useful masking/override behaviour due to the priority ordering of state machine pin writes (SM0 < SM1 < ...)",
+            },
+            FieldInfo {
+                name: "OUT_STICKY",
+                bits: PROC_PIO_SM0_EXECCTRL_OUT_STICKY_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_OUT_STICKY_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_OUT_STICKY_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_OUT_STICKY_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_OUT_STICKY_ACCESS,
+                description: "Continuously assert the most recent OUT/SET to the pins",
+            },
+            FieldInfo {
+                name: "WRAP_TOP",
+                bits: PROC_PIO_SM0_EXECCTRL_WRAP_TOP_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_WRAP_TOP_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_WRAP_TOP_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_WRAP_TOP_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_WRAP_TOP_ACCESS,
+                description: "After reaching this address, execution is wrapped to wrap_bottom. If the instruction is a jump, and the jump condition is true, the jump takes priority.",
+            },
+            FieldInfo {
+                name: "WRAP_BOTTOM",
+                bits: PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_ACCESS,
+                description: "After reaching wrap_top, execution is wrapped to this address.",
+            },
+            FieldInfo {
+                name: "STATUS_SEL",
+                bits: PROC_PIO_SM0_EXECCTRL_STATUS_SEL_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_STATUS_SEL_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_STATUS_SEL_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_STATUS_SEL_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_STATUS_SEL_ACCESS,
+                description: "Comparison used for the MOV x, STATUS instruction. 0x0 -> All-ones if TX FIFO level < N, otherwise all-zeroes 0x1 -> All-ones if RX FIFO level < N, otherwise all-zeroes",
+            },
+            FieldInfo {
+                name: "STATUS_N",
+                bits: PROC_PIO_SM0_EXECCTRL_STATUS_N_BITS,
+                msb: PROC_PIO_SM0_EXECCTRL_STATUS_N_MSB,
+                lsb: PROC_PIO_SM0_EXECCTRL_STATUS_N_LSB,
+                reset: Some(PROC_PIO_SM0_EXECCTRL_STATUS_N_RESET),
+                access: PROC_PIO_SM0_EXECCTRL_STATUS_N_ACCESS,
+                description: "Comparison level for the MOV x, STATUS instruction",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_SHIFTCTRL",
+        offset: PROC_PIO_SM0_SHIFTCTRL_OFFSET,
+        bits: PROC_PIO_SM0_SHIFTCTRL_BITS,
+        reset: Some(PROC_PIO_SM0_SHIFTCTRL_RESET),
+        description: "Control behaviour of the input/output shift registers for state machine 0",
+        fields: &[
+            FieldInfo {
+                name: "FJOIN_RX",
+                bits: PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_ACCESS,
+                description: "When 1, RX FIFO steals the TX FIFO's storage, and becomes twice as deep. TX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "FJOIN_TX",
+                bits: PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_ACCESS,
+                description: "When 1, TX FIFO steals the RX FIFO's storage, and becomes twice as deep. RX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "PULL_THRESH",
+                bits: PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_ACCESS,
+                description: "Number of bits shifted out of TXSR before autopull or conditional pull. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "PUSH_THRESH",
+                bits: PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_ACCESS,
+                description: "Number of bits shifted into RXSR before autopush or conditional push. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "OUT_SHIFTDIR",
+                bits: PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_ACCESS,
+                description: "1 = shift out of output shift register to right. 0 = to left.",
+            },
+            FieldInfo {
+                name: "IN_SHIFTDIR",
+                bits: PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_ACCESS,
+                description: "1 = shift input shift register to right (data enters from left). 0 = to left.",
+            },
+            FieldInfo {
+                name: "AUTOPULL",
+                bits: PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_ACCESS,
+                description: "Pull automatically when the output shift register is emptied",
+            },
+            FieldInfo {
+                name: "AUTOPUSH",
+                bits: PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_BITS,
+                msb: PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_MSB,
+                lsb: PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_LSB,
+                reset: Some(PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_RESET),
+                access: PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_ACCESS,
+                description: "Push automatically when the input shift register is filled",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_ADDR",
+        offset: PROC_PIO_SM0_ADDR_OFFSET,
+        bits: PROC_PIO_SM0_ADDR_BITS,
+        reset: Some(PROC_PIO_SM0_ADDR_RESET),
+        description: "Current instruction address of state machine 0",
+        fields: &[
+            FieldInfo {
+                name: "SM0_ADDR",
+                bits: PROC_PIO_SM0_ADDR_BITS,
+                msb: PROC_PIO_SM0_ADDR_MSB,
+                lsb: PROC_PIO_SM0_ADDR_LSB,
+                reset: Some(PROC_PIO_SM0_ADDR_RESET),
+                access: PROC_PIO_SM0_ADDR_ACCESS,
+                description: "Current instruction address of state machine 0",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_INSTR",
+        offset: PROC_PIO_SM0_INSTR_OFFSET,
+        bits: PROC_PIO_SM0_INSTR_BITS,
+        reset: None,
+        description: "Instruction currently being executed by state machine 0 Write to execute an instruction immediately (including jumps) and then resume execution.",
+        fields: &[
+            FieldInfo {
+                name: "SM0_INSTR",
+                bits: PROC_PIO_SM0_INSTR_BITS,
+                msb: PROC_PIO_SM0_INSTR_MSB,
+                lsb: PROC_PIO_SM0_INSTR_LSB,
+                reset: None,
+                access: PROC_PIO_SM0_INSTR_ACCESS,
+                description: "Instruction currently being executed by state machine 0 Write to execute an instruction immediately (including jumps) and then resume execution.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_PINCTRL",
+        offset: PROC_PIO_SM0_PINCTRL_OFFSET,
+        bits: PROC_PIO_SM0_PINCTRL_BITS,
+        reset: Some(PROC_PIO_SM0_PINCTRL_RESET),
+        description: "State machine pin control",
+        fields: &[
+            FieldInfo {
+                name: "SIDESET_COUNT",
+                bits: PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_BITS,
+                msb: PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_MSB,
+                lsb: PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_RESET),
+                access: PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_ACCESS,
+                description: "The number of delay bits co-opted for side-set. Inclusive of the enable bit, if present.",
+            },
+            FieldInfo {
+                name: "SET_COUNT",
+                bits: PROC_PIO_SM0_PINCTRL_SET_COUNT_BITS,
+                msb: PROC_PIO_SM0_PINCTRL_SET_COUNT_MSB,
+                lsb: PROC_PIO_SM0_PINCTRL_SET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM0_PINCTRL_SET_COUNT_RESET),
+                access: PROC_PIO_SM0_PINCTRL_SET_COUNT_ACCESS,
+                description: "The number of pins asserted by a SET. Max of 5",
+            },
+            FieldInfo {
+                name: "OUT_COUNT",
+                bits: PROC_PIO_SM0_PINCTRL_OUT_COUNT_BITS,
+                msb: PROC_PIO_SM0_PINCTRL_OUT_COUNT_MSB,
+                lsb: PROC_PIO_SM0_PINCTRL_OUT_COUNT_LSB,
+                reset: Some(PROC_PIO_SM0_PINCTRL_OUT_COUNT_RESET),
+                access: PROC_PIO_SM0_PINCTRL_OUT_COUNT_ACCESS,
+                description: "The number of pins asserted by an OUT. Value of 0 -> 32 pins",
+            },
+            FieldInfo {
+                name: "IN_BASE",
+                bits: PROC_PIO_SM0_PINCTRL_IN_BASE_BITS,
+                msb: PROC_PIO_SM0_PINCTRL_IN_BASE_MSB,
+                lsb: PROC_PIO_SM0_PINCTRL_IN_BASE_LSB,
+                reset: Some(PROC_PIO_SM0_PINCTRL_IN_BASE_RESET),
+                access: PROC_PIO_SM0_PINCTRL_IN_BASE_ACCESS,
+                description: "The virtual pin corresponding to IN bit 0",
+            },
+            FieldInfo {
+                name: "SIDESET_BASE",
+                bits: PROC_PIO_SM0_PINCTRL_SIDESET_BASE_BITS,
+                msb: PROC_PIO_SM0_PINCTRL_SIDESET_BASE_MSB,
+                lsb: PROC_PIO_SM0_PINCTRL_SIDESET_BASE_LSB,
+                reset: Some(PROC_PIO_SM0_PINCTRL_SIDESET_BASE_RESET),
+                access: PROC_PIO_SM0_PINCTRL_SIDESET_BASE_ACCESS,
+                description: "The virtual pin corresponding to delay field bit 0",
+            },
+            FieldInfo {
+                name: "SET_BASE",
+                bits: PROC_PIO_SM0_PINCTRL_SET_BASE_BITS,
+                msb: PROC_PIO_SM0_PINCTRL_SET_BASE_MSB,
+                lsb: PROC_PIO_SM0_PINCTRL_SET_BASE_LSB,
+                reset: Some(PROC_PIO_SM0_PINCTRL_SET_BASE_RESET),
+                access: PROC_PIO_SM0_PINCTRL_SET_BASE_ACCESS,
+                description: "The virtual pin corresponding to SET bit 0",
+            },
+            FieldInfo {
+                name: "OUT_BASE",
+                bits: PROC_PIO_SM0_PINCTRL_OUT_BASE_BITS,
+                msb: PROC_PIO_SM0_PINCTRL_OUT_BASE_MSB,
+                lsb: PROC_PIO_SM0_PINCTRL_OUT_BASE_LSB,
+                reset: Some(PROC_PIO_SM0_PINCTRL_OUT_BASE_RESET),
+                access: PROC_PIO_SM0_PINCTRL_OUT_BASE_ACCESS,
+                description: "The virtual pin corresponding to OUT bit 0",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_DMACTRL_TX",
+        offset: PROC_PIO_SM0_DMACTRL_TX_OFFSET,
+        bits: PROC_PIO_SM0_DMACTRL_TX_BITS,
+        reset: Some(PROC_PIO_SM0_DMACTRL_TX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_RESET),
+                access: PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM0_DMACTRL_TX_ACTIVE_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_TX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_TX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM0_DMACTRL_TX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are no more than THRESHOLD items in the TX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM0_DMACTRL_RX",
+        offset: PROC_PIO_SM0_DMACTRL_RX_OFFSET,
+        bits: PROC_PIO_SM0_DMACTRL_RX_BITS,
+        reset: Some(PROC_PIO_SM0_DMACTRL_RX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_RESET),
+                access: PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM0_DMACTRL_RX_ACTIVE_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_RX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_RX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM0_DMACTRL_RX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are at least THRESHOLD items in the RX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_CLKDIV",
+        offset: PROC_PIO_SM1_CLKDIV_OFFSET,
+        bits: PROC_PIO_SM1_CLKDIV_BITS,
+        reset: Some(PROC_PIO_SM1_CLKDIV_RESET),
+        description: "Clock divider register for state machine 1 Frequency = clock freq / (CLKDIV_INT + CLKDIV_FRAC / 256)",
+        fields: &[
+            FieldInfo {
+                name: "INT",
+                bits: PROC_PIO_SM1_CLKDIV_INT_BITS,
+                msb: PROC_PIO_SM1_CLKDIV_INT_MSB,
+                lsb: PROC_PIO_SM1_CLKDIV_INT_LSB,
+                reset: Some(PROC_PIO_SM1_CLKDIV_INT_RESET),
+                access: PROC_PIO_SM1_CLKDIV_INT_ACCESS,
+                description: "Effective frequency is sysclk/int. Value of 0 is interpreted as max possible value",
+            },
+            FieldInfo {
+                name: "FRAC",
+                bits: PROC_PIO_SM1_CLKDIV_FRAC_BITS,
+                msb: PROC_PIO_SM1_CLKDIV_FRAC_MSB,
+                lsb: PROC_PIO_SM1_CLKDIV_FRAC_LSB,
+                reset: Some(PROC_PIO_SM1_CLKDIV_FRAC_RESET),
+                access: PROC_PIO_SM1_CLKDIV_FRAC_ACCESS,
+                description: "Fractional part of clock divider",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_EXECCTRL",
+        offset: PROC_PIO_SM1_EXECCTRL_OFFSET,
+        bits: PROC_PIO_SM1_EXECCTRL_BITS,
+        reset: Some(PROC_PIO_SM1_EXECCTRL_RESET),
+        description: "Execution/behavioural settings for state machine 1",
+        fields: &[
+            FieldInfo {
+                name: "EXEC_STALLED",
+                bits: PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_EXEC_STALLED_ACCESS,
+                description: "An instruction written to SMx_INSTR is stalled, and latched by the state machine. Will clear once the instruction completes.",
+            },
+            FieldInfo {
+                name: "SIDE_EN",
+                bits: PROC_PIO_SM1_EXECCTRL_SIDE_EN_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_SIDE_EN_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_SIDE_EN_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_SIDE_EN_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_SIDE_EN_ACCESS,
+                description: "If 1, the delay MSB is used as side-set enable, rather than a side-set data bit. This allows instructions to perform side-set optionally, rather than on every instruction.",
+            },
+            FieldInfo {
+                name: "SIDE_PINDIR",
+                bits: PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_SIDE_PINDIR_ACCESS,
+                description: "Side-set data is asserted to pin OEs instead of pin values",
+            },
+            FieldInfo {
+                name: "JMP_PIN",
+                bits: PROC_PIO_SM1_EXECCTRL_JMP_PIN_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_JMP_PIN_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_JMP_PIN_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_JMP_PIN_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_JMP_PIN_ACCESS,
+                description: "The GPIO number to use as condition for JMP PIN. Unaffected by input mapping.",
+            },
+            FieldInfo {
+                name: "OUT_EN_SEL",
+                bits: PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_OUT_EN_SEL_ACCESS,
+                description: "Which data bit to use for inline OUT enable",
+            },
+            FieldInfo {
+                name: "INLINE_OUT_EN",
+                bits: PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_INLINE_OUT_EN_ACCESS,
+                description: "If 1, use a bit of OUT data as an auxiliary write enable When used in conjunction with OUT_STICKY, writes with an enable of 0 will deassert the latest pin write. This can create useful masking/override behaviour due to the priority ordering of state machine pin writes (SM0 < SM1 < ...)",
+            },
+            FieldInfo {
+                name: "OUT_STICKY",
+                bits: PROC_PIO_SM1_EXECCTRL_OUT_STICKY_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_OUT_STICKY_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_OUT_STICKY_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_OUT_STICKY_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_OUT_STICKY_ACCESS,
+                description: "Continuously assert the most recent OUT/SET to the pins",
+            },
+            FieldInfo {
+                name: "WRAP_TOP",
+                bits: PROC_PIO_SM1_EXECCTRL_WRAP_TOP_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_WRAP_TOP_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_WRAP_TOP_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_WRAP_TOP_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_WRAP_TOP_ACCESS,
+                description: "After reaching this address, execution is wrapped to wrap_bottom. If the instruction is a jump, and the jump condition is true, the jump takes priority.",
+            },
+            FieldInfo {
+                name: "WRAP_BOTTOM",
+                bits: PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_WRAP_BOTTOM_ACCESS,
+                description: "After reaching wrap_top, execution is wrapped to this address.",
+            },
+            FieldInfo {
+                name: "STATUS_SEL",
+                bits: PROC_PIO_SM1_EXECCTRL_STATUS_SEL_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_STATUS_SEL_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_STATUS_SEL_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_STATUS_SEL_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_STATUS_SEL_ACCESS,
+                description: "Comparison used for the MOV x, STATUS instruction. 0x0 -> All-ones if TX FIFO level < N, otherwise all-zeroes 0x1 -> All-ones if RX FIFO level < N, otherwise all-zeroes",
+            },
+            FieldInfo {
+                name: "STATUS_N",
+                bits: PROC_PIO_SM1_EXECCTRL_STATUS_N_BITS,
+                msb: PROC_PIO_SM1_EXECCTRL_STATUS_N_MSB,
+                lsb: PROC_PIO_SM1_EXECCTRL_STATUS_N_LSB,
+                reset: Some(PROC_PIO_SM1_EXECCTRL_STATUS_N_RESET),
+                access: PROC_PIO_SM1_EXECCTRL_STATUS_N_ACCESS,
+                description: "Comparison level for the MOV x, STATUS instruction",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_SHIFTCTRL",
+        offset: PROC_PIO_SM1_SHIFTCTRL_OFFSET,
+        bits: PROC_PIO_SM1_SHIFTCTRL_BITS,
+        reset: Some(PROC_PIO_SM1_SHIFTCTRL_RESET),
+        description: "Control behaviour of the input/output shift registers for state machine 1",
+        fields: &[
+            FieldInfo {
+                name: "FJOIN_RX",
+                bits: PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_FJOIN_RX_ACCESS,
+                description: "When 1, RX FIFO steals the TX FIFO's storage, and becomes twice as deep. TX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "FJOIN_TX",
+                bits: PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_FJOIN_TX_ACCESS,
+                description: "When 1, TX FIFO steals the RX FIFO's storage, and becomes twice as deep. RX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "PULL_THRESH",
+                bits: PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_PULL_THRESH_ACCESS,
+                description: "Number of bits shifted out of TXSR before autopull or conditional pull. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "PUSH_THRESH",
+                bits: PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_PUSH_THRESH_ACCESS,
+                description: "Number of bits shifted into RXSR before autopush or conditional push. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "OUT_SHIFTDIR",
+                bits: PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_OUT_SHIFTDIR_ACCESS,
+                description: "1 = shift out of output shift register to right. 0 = to left.",
+            },
+            FieldInfo {
+                name: "IN_SHIFTDIR",
+                bits: PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_IN_SHIFTDIR_ACCESS,
+                description: "1 = shift input shift register to right (data enters from left). 0 = to left.",
+            },
+            FieldInfo {
+                name: "AUTOPULL",
+                bits: PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_AUTOPULL_ACCESS,
+                description: "Pull automatically when the output shift register is emptied",
+            },
+            FieldInfo {
+                name: "AUTOPUSH",
+                bits: PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_BITS,
+                msb: PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_MSB,
+                lsb: PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_LSB,
+                reset: Some(PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_RESET),
+                access: PROC_PIO_SM1_SHIFTCTRL_AUTOPUSH_ACCESS,
+                description: "Push automatically when the input shift register is filled",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_ADDR",
+        offset: PROC_PIO_SM1_ADDR_OFFSET,
+        bits: PROC_PIO_SM1_ADDR_BITS,
+        reset: Some(PROC_PIO_SM1_ADDR_RESET),
+        description: "Current instruction address of state machine 1",
+        fields: &[
+            FieldInfo {
+                name: "SM1_ADDR",
+                bits: PROC_PIO_SM1_ADDR_BITS,
+                msb: PROC_PIO_SM1_ADDR_MSB,
+                lsb: PROC_PIO_SM1_ADDR_LSB,
+                reset: Some(PROC_PIO_SM1_ADDR_RESET),
+                access: PROC_PIO_SM1_ADDR_ACCESS,
+                description: "Current instruction address of state machine 1",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_INSTR",
+        offset: PROC_PIO_SM1_INSTR_OFFSET,
+        bits: PROC_PIO_SM1_INSTR_BITS,
+        reset: None,
+        description: "Instruction currently being executed by state machine 1 Write to execute an instruction immediately (including jumps) and then resume execution.",
+        fields: &[
+            FieldInfo {
+                name: "SM1_INSTR",
+                bits: PROC_PIO_SM1_INSTR_BITS,
+                msb: PROC_PIO_SM1_INSTR_MSB,
+                lsb: PROC_PIO_SM1_INSTR_LSB,
+                reset: None,
+                access: PROC_PIO_SM1_INSTR_ACCESS,
+                description: "Instruction currently being executed by state machine 1 Write to execute an instruction immediately (including jumps) and then resume execution.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_PINCTRL",
+        offset: PROC_PIO_SM1_PINCTRL_OFFSET,
+        bits: PROC_PIO_SM1_PINCTRL_BITS,
+        reset: Some(PROC_PIO_SM1_PINCTRL_RESET),
+        description: "State machine pin control",
+        fields: &[
+            FieldInfo {
+                name: "SIDESET_COUNT",
+                bits: PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_BITS,
+                msb: PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_MSB,
+                lsb: PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_RESET),
+                access: PROC_PIO_SM1_PINCTRL_SIDESET_COUNT_ACCESS,
+                description: "The number of delay bits co-opted for side-set. Inclusive of the enable bit, if present.",
+            },
+            FieldInfo {
+                name: "SET_COUNT",
+                bits: PROC_PIO_SM1_PINCTRL_SET_COUNT_BITS,
+                msb: PROC_PIO_SM1_PINCTRL_SET_COUNT_MSB,
+                lsb: PROC_PIO_SM1_PINCTRL_SET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM1_PINCTRL_SET_COUNT_RESET),
+                access: PROC_PIO_SM1_PINCTRL_SET_COUNT_ACCESS,
+                description: "The number of pins asserted by a SET. Max of 5",
+            },
+            FieldInfo {
+                name: "OUT_COUNT",
+                bits: PROC_PIO_SM1_PINCTRL_OUT_COUNT_BITS,
+                msb: PROC_PIO_SM1_PINCTRL_OUT_COUNT_MSB,
+                lsb: PROC_PIO_SM1_PINCTRL_OUT_COUNT_LSB,
+                reset: Some(PROC_PIO_SM1_PINCTRL_OUT_COUNT_RESET),
+                access: PROC_PIO_SM1_PINCTRL_OUT_COUNT_ACCESS,
+                description: "The number of pins asserted by an OUT. Value of 0 -> 32 pins",
+            },
+            FieldInfo {
+                name: "IN_BASE",
+                bits: PROC_PIO_SM1_PINCTRL_IN_BASE_BITS,
+                msb: PROC_PIO_SM1_PINCTRL_IN_BASE_MSB,
+                lsb: PROC_PIO_SM1_PINCTRL_IN_BASE_LSB,
+                reset: Some(PROC_PIO_SM1_PINCTRL_IN_BASE_RESET),
+                access: PROC_PIO_SM1_PINCTRL_IN_BASE_ACCESS,
+                description: "The virtual pin corresponding to IN bit 0",
+            },
+            FieldInfo {
+                name: "SIDESET_BASE",
+                bits: PROC_PIO_SM1_PINCTRL_SIDESET_BASE_BITS,
+                msb: PROC_PIO_SM1_PINCTRL_SIDESET_BASE_MSB,
+                lsb: PROC_PIO_SM1_PINCTRL_SIDESET_BASE_LSB,
+                reset: Some(PROC_PIO_SM1_PINCTRL_SIDESET_BASE_RESET),
+                access: PROC_PIO_SM1_PINCTRL_SIDESET_BASE_ACCESS,
+                description: "The virtual pin corresponding to delay field bit 0",
+            },
+            FieldInfo {
+                name: "SET_BASE",
+                bits: PROC_PIO_SM1_PINCTRL_SET_BASE_BITS,
+                msb: PROC_PIO_SM1_PINCTRL_SET_BASE_MSB,
+                lsb: PROC_PIO_SM1_PINCTRL_SET_BASE_LSB,
+                reset: Some(PROC_PIO_SM1_PINCTRL_SET_BASE_RESET),
+                access: PROC_PIO_SM1_PINCTRL_SET_BASE_ACCESS,
+                description: "The virtual pin corresponding to SET bit 0",
+            },
+            FieldInfo {
+                name: "OUT_BASE",
+                bits: PROC_PIO_SM1_PINCTRL_OUT_BASE_BITS,
+                msb: PROC_PIO_SM1_PINCTRL_OUT_BASE_MSB,
+                lsb: PROC_PIO_SM1_PINCTRL_OUT_BASE_LSB,
+                reset: Some(PROC_PIO_SM1_PINCTRL_OUT_BASE_RESET),
+                access: PROC_PIO_SM1_PINCTRL_OUT_BASE_ACCESS,
+                description: "The virtual pin corresponding to OUT bit 0",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_DMACTRL_TX",
+        offset: PROC_PIO_SM1_DMACTRL_TX_OFFSET,
+        bits: PROC_PIO_SM1_DMACTRL_TX_BITS,
+        reset: Some(PROC_PIO_SM1_DMACTRL_TX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_RESET),
+                access: PROC_PIO_SM1_DMACTRL_TX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM1_DMACTRL_TX_ACTIVE_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_TX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_TX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM1_DMACTRL_TX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM1_DMACTRL_TX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM1_DMACTRL_TX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are no more than THRESHOLD items in the TX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM1_DMACTRL_RX",
+        offset: PROC_PIO_SM1_DMACTRL_RX_OFFSET,
+        bits: PROC_PIO_SM1_DMACTRL_RX_BITS,
+        reset: Some(PROC_PIO_SM1_DMACTRL_RX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_RESET),
+                access: PROC_PIO_SM1_DMACTRL_RX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM1_DMACTRL_RX_ACTIVE_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_RX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_RX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM1_DMACTRL_RX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM1_DMACTRL_RX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM1_DMACTRL_RX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are at least THRESHOLD items in the RX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_CLKDIV",
+        offset: PROC_PIO_SM2_CLKDIV_OFFSET,
+        bits: PROC_PIO_SM2_CLKDIV_BITS,
+        reset: Some(PROC_PIO_SM2_CLKDIV_RESET),
+        description: "Clock divider register for state machine 2 Frequency = clock freq / (CLKDIV_INT + CLKDIV_FRAC / 256)",
+        fields: &[
+            FieldInfo {
+                name: "INT",
+                bits: PROC_PIO_SM2_CLKDIV_INT_BITS,
+                msb: PROC_PIO_SM2_CLKDIV_INT_MSB,
+                lsb: PROC_PIO_SM2_CLKDIV_INT_LSB,
+                reset: Some(PROC_PIO_SM2_CLKDIV_INT_RESET),
+                access: PROC_PIO_SM2_CLKDIV_INT_ACCESS,
+                description: "Effective frequency is sysclk/int. Value of 0 is interpreted as max possible value",
+            },
+            FieldInfo {
+                name: "FRAC",
+                bits: PROC_PIO_SM2_CLKDIV_FRAC_BITS,
+                msb: PROC_PIO_SM2_CLKDIV_FRAC_MSB,
+                lsb: PROC_PIO_SM2_CLKDIV_FRAC_LSB,
+                reset: Some(PROC_PIO_SM2_CLKDIV_FRAC_RESET),
+                access: PROC_PIO_SM2_CLKDIV_FRAC_ACCESS,
+                description: "Fractional part of clock divider",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_EXECCTRL",
+        offset: PROC_PIO_SM2_EXECCTRL_OFFSET,
+        bits: PROC_PIO_SM2_EXECCTRL_BITS,
+        reset: Some(PROC_PIO_SM2_EXECCTRL_RESET),
+        description: "Execution/behavioural settings for state machine 2",
+        fields: &[
+            FieldInfo {
+                name: "EXEC_STALLED",
+                bits: PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_EXEC_STALLED_ACCESS,
+                description: "An instruction written to SMx_INSTR is stalled, and latched by the state machine. Will clear once the instruction completes.",
+            },
+            FieldInfo {
+                name: "SIDE_EN",
+                bits: PROC_PIO_SM2_EXECCTRL_SIDE_EN_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_SIDE_EN_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_SIDE_EN_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_SIDE_EN_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_SIDE_EN_ACCESS,
+                description: "If 1, the delay MSB is used as side-set enable, rather than a side-set data bit. This allows instructions to perform side-set optionally, rather than on every instruction.",
+            },
+            FieldInfo {
+                name: "SIDE_PINDIR",
+                bits: PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_SIDE_PINDIR_ACCESS,
+                description: "Side-set data is asserted to pin OEs instead of pin values",
+            },
+            FieldInfo {
+                name: "JMP_PIN",
+                bits: PROC_PIO_SM2_EXECCTRL_JMP_PIN_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_JMP_PIN_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_JMP_PIN_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_JMP_PIN_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_JMP_PIN_ACCESS,
+                description: "The GPIO number to use as condition for JMP PIN. Unaffected by input mapping.",
+            },
+            FieldInfo {
+                name: "OUT_EN_SEL",
+                bits: PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_OUT_EN_SEL_ACCESS,
+                description: "Which data bit to use for inline OUT enable",
+            },
+            FieldInfo {
+                name: "INLINE_OUT_EN",
+                bits: PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_INLINE_OUT_EN_ACCESS,
+                description: "If 1, use a bit of OUT data as an auxiliary write enable When used in conjunction with OUT_STICKY, writes with an enable of 0 will deassert the latest pin write. This can create useful masking/override behaviour due to the priority ordering of state machine pin writes (SM0 < SM1 < ...)",
+            },
+            FieldInfo {
+                name: "OUT_STICKY",
+                bits: PROC_PIO_SM2_EXECCTRL_OUT_STICKY_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_OUT_STICKY_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_OUT_STICKY_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_OUT_STICKY_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_OUT_STICKY_ACCESS,
+                description: "Continuously assert the most recent OUT/SET to the pins",
+            },
+            FieldInfo {
+                name: "WRAP_TOP",
+                bits: PROC_PIO_SM2_EXECCTRL_WRAP_TOP_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_WRAP_TOP_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_WRAP_TOP_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_WRAP_TOP_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_WRAP_TOP_ACCESS,
+                description: "After reaching this address, execution is wrapped to wrap_bottom. If the instruction is a jump, and the jump condition is true, the jump takes priority.",
+            },
+            FieldInfo {
+                name: "WRAP_BOTTOM",
+                bits: PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_WRAP_BOTTOM_ACCESS,
+                description: "After reaching wrap_top, execution is wrapped to this address.",
+            },
+            FieldInfo {
+                name: "STATUS_SEL",
+                bits: PROC_PIO_SM2_EXECCTRL_STATUS_SEL_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_STATUS_SEL_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_STATUS_SEL_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_STATUS_SEL_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_STATUS_SEL_ACCESS,
+                description: "Comparison used for the MOV x, STATUS instruction. 0x0 -> All-ones if TX FIFO level < N, otherwise all-zeroes 0x1 -> All-ones if RX FIFO level < N, otherwise all-zeroes",
+            },
+            FieldInfo {
+                name: "STATUS_N",
+                bits: PROC_PIO_SM2_EXECCTRL_STATUS_N_BITS,
+                msb: PROC_PIO_SM2_EXECCTRL_STATUS_N_MSB,
+                lsb: PROC_PIO_SM2_EXECCTRL_STATUS_N_LSB,
+                reset: Some(PROC_PIO_SM2_EXECCTRL_STATUS_N_RESET),
+                access: PROC_PIO_SM2_EXECCTRL_STATUS_N_ACCESS,
+                description: "Comparison level for the MOV x, STATUS instruction",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_SHIFTCTRL",
+        offset: PROC_PIO_SM2_SHIFTCTRL_OFFSET,
+        bits: PROC_PIO_SM2_SHIFTCTRL_BITS,
+        reset: Some(PROC_PIO_SM2_SHIFTCTRL_RESET),
+        description: "Control behaviour of the input/output shift registers for state machine 2",
+        fields: &[
+            FieldInfo {
+                name: "FJOIN_RX",
+                bits: PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_FJOIN_RX_ACCESS,
+                description: "When 1, RX FIFO steals the TX FIFO's storage, and becomes twice as deep. TX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "FJOIN_TX",
+                bits: PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_FJOIN_TX_ACCESS,
+                description: "When 1, TX FIFO steals the RX FIFO's storage, and becomes twice as deep. RX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "PULL_THRESH",
+                bits: PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_PULL_THRESH_ACCESS,
+                description: "Number of bits shifted out of TXSR before autopull or conditional pull. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "PUSH_THRESH",
+                bits: PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_PUSH_THRESH_ACCESS,
+                description: "Number of bits shifted into RXSR before autopush or conditional push. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "OUT_SHIFTDIR",
+                bits: PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_OUT_SHIFTDIR_ACCESS,
+                description: "1 = shift out of output shift register to right. 0 = to left.",
+            },
+            FieldInfo {
+                name: "IN_SHIFTDIR",
+                bits: PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_IN_SHIFTDIR_ACCESS,
+                description: "1 = shift input shift register to right (data enters from left). 0 = to left.",
+            },
+            FieldInfo {
+                name: "AUTOPULL",
+                bits: PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_AUTOPULL_ACCESS,
+                description: "Pull automatically when the output shift register is emptied",
+            },
+            FieldInfo {
+                name: "AUTOPUSH",
+                bits: PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_BITS,
+                msb: PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_MSB,
+                lsb: PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_LSB,
+                reset: Some(PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_RESET),
+                access: PROC_PIO_SM2_SHIFTCTRL_AUTOPUSH_ACCESS,
+                description: "Push automatically when the input shift register is filled",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_ADDR",
+        offset: PROC_PIO_SM2_ADDR_OFFSET,
+        bits: PROC_PIO_SM2_ADDR_BITS,
+        reset: Some(PROC_PIO_SM2_ADDR_RESET),
+        description: "Current instruction address of state machine 2",
+        fields: &[
+            FieldInfo {
+                name: "SM2_ADDR",
+                bits: PROC_PIO_SM2_ADDR_BITS,
+                msb: PROC_PIO_SM2_ADDR_MSB,
+                lsb: PROC_PIO_SM2_ADDR_LSB,
+                reset: Some(PROC_PIO_SM2_ADDR_RESET),
+                access: PROC_PIO_SM2_ADDR_ACCESS,
+                description: "Current instruction address of state machine 2",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_INSTR",
+        offset: PROC_PIO_SM2_INSTR_OFFSET,
+        bits: PROC_PIO_SM2_INSTR_BITS,
+        reset: None,
+        description: "Instruction currently being executed by state machine 2 Write to execute an instruction immediately (including jumps) and then resume execution.",
+        fields: &[
+            FieldInfo {
+                name: "SM2_INSTR",
+                bits: PROC_PIO_SM2_INSTR_BITS,
+                msb: PROC_PIO_SM2_INSTR_MSB,
+                lsb: PROC_PIO_SM2_INSTR_LSB,
+                reset: None,
+                access: PROC_PIO_SM2_INSTR_ACCESS,
+                description: "Instruction currently being executed by state machine 2 Write to execute an instruction immediately (including jumps) and then resume execution.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_PINCTRL",
+        offset: PROC_PIO_SM2_PINCTRL_OFFSET,
+        bits: PROC_PIO_SM2_PINCTRL_BITS,
+        reset: Some(PROC_PIO_SM2_PINCTRL_RESET),
+        description: "State machine pin control",
+        fields: &[
+            FieldInfo {
+                name: "SIDESET_COUNT",
+                bits: PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_BITS,
+                msb: PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_MSB,
+                lsb: PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_RESET),
+                access: PROC_PIO_SM2_PINCTRL_SIDESET_COUNT_ACCESS,
+                description: "The number of delay bits co-opted for side-set. Inclusive of the enable bit, if present.",
+            },
+            FieldInfo {
+                name: "SET_COUNT",
+                bits: PROC_PIO_SM2_PINCTRL_SET_COUNT_BITS,
+                msb: PROC_PIO_SM2_PINCTRL_SET_COUNT_MSB,
+                lsb: PROC_PIO_SM2_PINCTRL_SET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM2_PINCTRL_SET_COUNT_RESET),
+                access: PROC_PIO_SM2_PINCTRL_SET_COUNT_ACCESS,
+                description: "The number of pins asserted by a SET. Max of 5",
+            },
+            FieldInfo {
+                name: "OUT_COUNT",
+                bits: PROC_PIO_SM2_PINCTRL_OUT_COUNT_BITS,
+                msb: PROC_PIO_SM2_PINCTRL_OUT_COUNT_MSB,
+                lsb: PROC_PIO_SM2_PINCTRL_OUT_COUNT_LSB,
+                reset: Some(PROC_PIO_SM2_PINCTRL_OUT_COUNT_RESET),
+                access: PROC_PIO_SM2_PINCTRL_OUT_COUNT_ACCESS,
+                description: "The number of pins asserted by an OUT. Value of 0 -> 32 pins",
+            },
+            FieldInfo {
+                name: "IN_BASE",
+                bits: PROC_PIO_SM2_PINCTRL_IN_BASE_BITS,
+                msb: PROC_PIO_SM2_PINCTRL_IN_BASE_MSB,
+                lsb: PROC_PIO_SM2_PINCTRL_IN_BASE_LSB,
+                reset: Some(PROC_PIO_SM2_PINCTRL_IN_BASE_RESET),
+                access: PROC_PIO_SM2_PINCTRL_IN_BASE_ACCESS,
+                description: "The virtual pin corresponding to IN bit 0",
+            },
+            FieldInfo {
+                name: "SIDESET_BASE",
+                bits: PROC_PIO_SM2_PINCTRL_SIDESET_BASE_BITS,
+                msb: PROC_PIO_SM2_PINCTRL_SIDESET_BASE_MSB,
+                lsb: PROC_PIO_SM2_PINCTRL_SIDESET_BASE_LSB,
+                reset: Some(PROC_PIO_SM2_PINCTRL_SIDESET_BASE_RESET),
+                access: PROC_PIO_SM2_PINCTRL_SIDESET_BASE_ACCESS,
+                description: "The virtual pin corresponding to delay field bit 0",
+            },
+            FieldInfo {
+                name: "SET_BASE",
+                bits: PROC_PIO_SM2_PINCTRL_SET_BASE_BITS,
+                msb: PROC_PIO_SM2_PINCTRL_SET_BASE_MSB,
+                lsb: PROC_PIO_SM2_PINCTRL_SET_BASE_LSB,
+                reset: Some(PROC_PIO_SM2_PINCTRL_SET_BASE_RESET),
+                access: PROC_PIO_SM2_PINCTRL_SET_BASE_ACCESS,
+                description: "The virtual pin corresponding to SET bit 0",
+            },
+            FieldInfo {
+                name: "OUT_BASE",
+                bits: PROC_PIO_SM2_PINCTRL_OUT_BASE_BITS,
+                msb: PROC_PIO_SM2_PINCTRL_OUT_BASE_MSB,
+                lsb: PROC_PIO_SM2_PINCTRL_OUT_BASE_LSB,
+                reset: Some(PROC_PIO_SM2_PINCTRL_OUT_BASE_RESET),
+                access: PROC_PIO_SM2_PINCTRL_OUT_BASE_ACCESS,
+                description: "The virtual pin corresponding to OUT bit 0",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_DMACTRL_TX",
+        offset: PROC_PIO_SM2_DMACTRL_TX_OFFSET,
+        bits: PROC_PIO_SM2_DMACTRL_TX_BITS,
+        reset: Some(PROC_PIO_SM2_DMACTRL_TX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_RESET),
+                access: PROC_PIO_SM2_DMACTRL_TX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM2_DMACTRL_TX_ACTIVE_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_TX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_TX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM2_DMACTRL_TX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM2_DMACTRL_TX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM2_DMACTRL_TX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are no more than THRESHOLD items in the TX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM2_DMACTRL_RX",
+        offset: PROC_PIO_SM2_DMACTRL_RX_OFFSET,
+        bits: PROC_PIO_SM2_DMACTRL_RX_BITS,
+        reset: Some(PROC_PIO_SM2_DMACTRL_RX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_RESET),
+                access: PROC_PIO_SM2_DMACTRL_RX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM2_DMACTRL_RX_ACTIVE_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_RX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_RX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM2_DMACTRL_RX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM2_DMACTRL_RX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM2_DMACTRL_RX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are at least THRESHOLD items in the RX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_CLKDIV",
+        offset: PROC_PIO_SM3_CLKDIV_OFFSET,
+        bits: PROC_PIO_SM3_CLKDIV_BITS,
+        reset: Some(PROC_PIO_SM3_CLKDIV_RESET),
+        description: "Clock divider register for state machine 3 Frequency = clock freq / (CLKDIV_INT + CLKDIV_FRAC / 256)",
+        fields: &[
+            FieldInfo {
+                name: "INT",
+                bits: PROC_PIO_SM3_CLKDIV_INT_BITS,
+                msb: PROC_PIO_SM3_CLKDIV_INT_MSB,
+                lsb: PROC_PIO_SM3_CLKDIV_INT_LSB,
+                reset: Some(PROC_PIO_SM3_CLKDIV_INT_RESET),
+                access: PROC_PIO_SM3_CLKDIV_INT_ACCESS,
+                description: "Effective frequency is sysclk/int. Value of 0 is interpreted as max possible value",
+            },
+            FieldInfo {
+                name: "FRAC",
+                bits: PROC_PIO_SM3_CLKDIV_FRAC_BITS,
+                msb: PROC_PIO_SM3_CLKDIV_FRAC_MSB,
+                lsb: PROC_PIO_SM3_CLKDIV_FRAC_LSB,
+                reset: Some(PROC_PIO_SM3_CLKDIV_FRAC_RESET),
+                access: PROC_PIO_SM3_CLKDIV_FRAC_ACCESS,
+                description: "Fractional part of clock divider",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_EXECCTRL",
+        offset: PROC_PIO_SM3_EXECCTRL_OFFSET,
+        bits: PROC_PIO_SM3_EXECCTRL_BITS,
+        reset: Some(PROC_PIO_SM3_EXECCTRL_RESET),
+        description: "Execution/behavioural settings for state machine 3",
+        fields: &[
+            FieldInfo {
+                name: "EXEC_STALLED",
+                bits: PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_EXEC_STALLED_ACCESS,
+                description: "An instruction written to SMx_INSTR is stalled, and latched by the state machine. Will clear once the instruction completes.",
+            },
+            FieldInfo {
+                name: "SIDE_EN",
+                bits: PROC_PIO_SM3_EXECCTRL_SIDE_EN_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_SIDE_EN_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_SIDE_EN_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_SIDE_EN_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_SIDE_EN_ACCESS,
+                description: "If 1, the delay MSB is used as side-set enable, rather than a side-set data bit. This allows instructions to perform side-set optionally, rather than on every instruction.",
+            },
+            FieldInfo {
+                name: "SIDE_PINDIR",
+                bits: PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_SIDE_PINDIR_ACCESS,
+                description: "Side-set data is asserted to pin OEs instead of pin values",
+            },
+            FieldInfo {
+                name: "JMP_PIN",
+                bits: PROC_PIO_SM3_EXECCTRL_JMP_PIN_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_JMP_PIN_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_JMP_PIN_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_JMP_PIN_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_JMP_PIN_ACCESS,
+                description: "The GPIO number to use as condition for JMP PIN. Unaffected by input mapping.",
+            },
+            FieldInfo {
+                name: "OUT_EN_SEL",
+                bits: PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_OUT_EN_SEL_ACCESS,
+                description: "Which data bit to use for inline OUT enable",
+            },
+            FieldInfo {
+                name: "INLINE_OUT_EN",
+                bits: PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_INLINE_OUT_EN_ACCESS,
+                description: "If 1, use a bit of OUT data as an auxiliary write enable When used in conjunction with OUT_STICKY, writes with an enable of 0 will deassert the latest pin write. This can create useful masking/override behaviour due to the priority ordering of state machine pin writes (SM0 < SM1 < ...)",
+            },
+            FieldInfo {
+                name: "OUT_STICKY",
+                bits: PROC_PIO_SM3_EXECCTRL_OUT_STICKY_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_OUT_STICKY_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_OUT_STICKY_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_OUT_STICKY_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_OUT_STICKY_ACCESS,
+                description: "Continuously assert the most recent OUT/SET to the pins",
+            },
+            FieldInfo {
+                name: "WRAP_TOP",
+                bits: PROC_PIO_SM3_EXECCTRL_WRAP_TOP_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_WRAP_TOP_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_WRAP_TOP_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_WRAP_TOP_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_WRAP_TOP_ACCESS,
+                description: "After reaching this address, execution is wrapped to wrap_bottom. If the instruction is a jump, and the jump condition is true, the jump takes priority.",
+            },
+            FieldInfo {
+                name: "WRAP_BOTTOM",
+                bits: PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_WRAP_BOTTOM_ACCESS,
+                description: "After reaching wrap_top, execution is wrapped to this address.",
+            },
+            FieldInfo {
+                name: "STATUS_SEL",
+                bits: PROC_PIO_SM3_EXECCTRL_STATUS_SEL_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_STATUS_SEL_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_STATUS_SEL_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_STATUS_SEL_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_STATUS_SEL_ACCESS,
+                description: "Comparison used for the MOV x, STATUS instruction. 0x0 -> All-ones if TX FIFO level < N, otherwise all-zeroes 0x1 -> All-ones if RX FIFO level < N, otherwise all-zeroes",
+            },
+            FieldInfo {
+                name: "STATUS_N",
+                bits: PROC_PIO_SM3_EXECCTRL_STATUS_N_BITS,
+                msb: PROC_PIO_SM3_EXECCTRL_STATUS_N_MSB,
+                lsb: PROC_PIO_SM3_EXECCTRL_STATUS_N_LSB,
+                reset: Some(PROC_PIO_SM3_EXECCTRL_STATUS_N_RESET),
+                access: PROC_PIO_SM3_EXECCTRL_STATUS_N_ACCESS,
+                description: "Comparison level for the MOV x, STATUS instruction",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_SHIFTCTRL",
+        offset: PROC_PIO_SM3_SHIFTCTRL_OFFSET,
+        bits: PROC_PIO_SM3_SHIFTCTRL_BITS,
+        reset: Some(PROC_PIO_SM3_SHIFTCTRL_RESET),
+        description: "Control behaviour of the input/output shift registers for state machine 3",
+        fields: &[
+            FieldInfo {
+                name: "FJOIN_RX",
+                bits: PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_FJOIN_RX_ACCESS,
+                description: "When 1, RX FIFO steals the TX FIFO's storage, and becomes twice as deep. TX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "FJOIN_TX",
+                bits: PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_FJOIN_TX_ACCESS,
+                description: "When 1, TX FIFO steals the RX FIFO's storage, and becomes twice as deep. RX FIFO is disabled as a result (always reads as both full and empty). FIFOs are flushed when this bit is changed.",
+            },
+            FieldInfo {
+                name: "PULL_THRESH",
+                bits: PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_PULL_THRESH_ACCESS,
+                description: "Number of bits shifted out of TXSR before autopull or conditional pull. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "PUSH_THRESH",
+                bits: PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_PUSH_THRESH_ACCESS,
+                description: "Number of bits shifted into RXSR before autopush or conditional push. Write 0 for value of 32.",
+            },
+            FieldInfo {
+                name: "OUT_SHIFTDIR",
+                bits: PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_OUT_SHIFTDIR_ACCESS,
+                description: "1 = shift out of output shift register to right. 0 = to left.",
+            },
+            FieldInfo {
+                name: "IN_SHIFTDIR",
+                bits: PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_IN_SHIFTDIR_ACCESS,
+                description: "1 = shift input shift register to right (data enters from left). 0 = to left.",
+            },
+            FieldInfo {
+                name: "AUTOPULL",
+                bits: PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_AUTOPULL_ACCESS,
+                description: "Pull automatically when the output shift register is emptied",
+            },
+            FieldInfo {
+                name: "AUTOPUSH",
+                bits: PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_BITS,
+                msb: PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_MSB,
+                lsb: PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_LSB,
+                reset: Some(PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_RESET),
+                access: PROC_PIO_SM3_SHIFTCTRL_AUTOPUSH_ACCESS,
+                description: "Push automatically when the input shift register is filled",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_ADDR",
+        offset: PROC_PIO_SM3_ADDR_OFFSET,
+        bits: PROC_PIO_SM3_ADDR_BITS,
+        reset: Some(PROC_PIO_SM3_ADDR_RESET),
+        description: "Current instruction address of state machine 3",
+        fields: &[
+            FieldInfo {
+                name: "SM3_ADDR",
+                bits: PROC_PIO_SM3_ADDR_BITS,
+                msb: PROC_PIO_SM3_ADDR_MSB,
+                lsb: PROC_PIO_SM3_ADDR_LSB,
+                reset: Some(PROC_PIO_SM3_ADDR_RESET),
+                access: PROC_PIO_SM3_ADDR_ACCESS,
+                description: "Current instruction address of state machine 3",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_INSTR",
+        offset: PROC_PIO_SM3_INSTR_OFFSET,
+        bits: PROC_PIO_SM3_INSTR_BITS,
+        reset: None,
+        description: "Instruction currently being executed by state machine 3 Write to execute an instruction immediately (including jumps) and then resume execution.",
+        fields: &[
+            FieldInfo {
+                name: "SM3_INSTR",
+                bits: PROC_PIO_SM3_INSTR_BITS,
+                msb: PROC_PIO_SM3_INSTR_MSB,
+                lsb: PROC_PIO_SM3_INSTR_LSB,
+                reset: None,
+                access: PROC_PIO_SM3_INSTR_ACCESS,
+                description: "Instruction currently being executed by state machine 3 Write to execute an instruction immediately (including jumps) and then resume execution.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_PINCTRL",
+        offset: PROC_PIO_SM3_PINCTRL_OFFSET,
+        bits: PROC_PIO_SM3_PINCTRL_BITS,
+        reset: Some(PROC_PIO_SM3_PINCTRL_RESET),
+        description: "State machine pin control",
+        fields: &[
+            FieldInfo {
+                name: "SIDESET_COUNT",
+                bits: PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_BITS,
+                msb: PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_MSB,
+                lsb: PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_RESET),
+                access: PROC_PIO_SM3_PINCTRL_SIDESET_COUNT_ACCESS,
+                description: "The number of delay bits co-opted for side-set. Inclusive of the enable bit, if present.",
+            },
+            FieldInfo {
+                name: "SET_COUNT",
+                bits: PROC_PIO_SM3_PINCTRL_SET_COUNT_BITS,
+                msb: PROC_PIO_SM3_PINCTRL_SET_COUNT_MSB,
+                lsb: PROC_PIO_SM3_PINCTRL_SET_COUNT_LSB,
+                reset: Some(PROC_PIO_SM3_PINCTRL_SET_COUNT_RESET),
+                access: PROC_PIO_SM3_PINCTRL_SET_COUNT_ACCESS,
+                description: "The number of pins asserted by a SET. Max of 5",
+            },
+            FieldInfo {
+                name: "OUT_COUNT",
+                bits: PROC_PIO_SM3_PINCTRL_OUT_COUNT_BITS,
+                msb: PROC_PIO_SM3_PINCTRL_OUT_COUNT_MSB,
+                lsb: PROC_PIO_SM3_PINCTRL_OUT_COUNT_LSB,
+                reset: Some(PROC_PIO_SM3_PINCTRL_OUT_COUNT_RESET),
+                access: PROC_PIO_SM3_PINCTRL_OUT_COUNT_ACCESS,
+                description: "The number of pins asserted by an OUT. Value of 0 -> 32 pins",
+            },
+            FieldInfo {
+                name: "IN_BASE",
+                bits: PROC_PIO_SM3_PINCTRL_IN_BASE_BITS,
+                msb: PROC_PIO_SM3_PINCTRL_IN_BASE_MSB,
+                lsb: PROC_PIO_SM3_PINCTRL_IN_BASE_LSB,
+                reset: Some(PROC_PIO_SM3_PINCTRL_IN_BASE_RESET),
+                access: PROC_PIO_SM3_PINCTRL_IN_BASE_ACCESS,
+                description: "The virtual pin corresponding to IN bit 0",
+            },
+            FieldInfo {
+                name: "SIDESET_BASE",
+                bits: PROC_PIO_SM3_PINCTRL_SIDESET_BASE_BITS,
+                msb: PROC_PIO_SM3_PINCTRL_SIDESET_BASE_MSB,
+                lsb: PROC_PIO_SM3_PINCTRL_SIDESET_BASE_LSB,
+                reset: Some(PROC_PIO_SM3_PINCTRL_SIDESET_BASE_RESET),
+                access: PROC_PIO_SM3_PINCTRL_SIDESET_BASE_ACCESS,
+                description: "The virtual pin corresponding to delay field bit 0",
+            },
+            FieldInfo {
+                name: "SET_BASE",
+                bits: PROC_PIO_SM3_PINCTRL_SET_BASE_BITS,
+                msb: PROC_PIO_SM3_PINCTRL_SET_BASE_MSB,
+                lsb: PROC_PIO_SM3_PINCTRL_SET_BASE_LSB,
+                reset: Some(PROC_PIO_SM3_PINCTRL_SET_BASE_RESET),
+                access: PROC_PIO_SM3_PINCTRL_SET_BASE_ACCESS,
+                description: "The virtual pin corresponding to SET bit 0",
+            },
+            FieldInfo {
+                name: "OUT_BASE",
+                bits: PROC_PIO_SM3_PINCTRL_OUT_BASE_BITS,
+                msb: PROC_PIO_SM3_PINCTRL_OUT_BASE_MSB,
+                lsb: PROC_PIO_SM3_PINCTRL_OUT_BASE_LSB,
+                reset: Some(PROC_PIO_SM3_PINCTRL_OUT_BASE_RESET),
+                access: PROC_PIO_SM3_PINCTRL_OUT_BASE_ACCESS,
+                description: "The virtual pin corresponding to OUT bit 0",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_DMACTRL_TX",
+        offset: PROC_PIO_SM3_DMACTRL_TX_OFFSET,
+        bits: PROC_PIO_SM3_DMACTRL_TX_BITS,
+        reset: Some(PROC_PIO_SM3_DMACTRL_TX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_RESET),
+                access: PROC_PIO_SM3_DMACTRL_TX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM3_DMACTRL_TX_ACTIVE_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_TX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_TX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM3_DMACTRL_TX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM3_DMACTRL_TX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM3_DMACTRL_TX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are no more than THRESHOLD items in the TX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "SM3_DMACTRL_RX",
+        offset: PROC_PIO_SM3_DMACTRL_RX_OFFSET,
+        bits: PROC_PIO_SM3_DMACTRL_RX_BITS,
+        reset: Some(PROC_PIO_SM3_DMACTRL_RX_RESET),
+        description: "State machine DMA control",
+        fields: &[
+            FieldInfo {
+                name: "DREQ_EN",
+                bits: PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_LSB,
+                reset: Some(PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_RESET),
+                access: PROC_PIO_SM3_DMACTRL_RX_DREQ_EN_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "ACTIVE",
+                bits: PROC_PIO_SM3_DMACTRL_RX_ACTIVE_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_RX_ACTIVE_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_RX_ACTIVE_LSB,
+                reset: None,
+                access: PROC_PIO_SM3_DMACTRL_RX_ACTIVE_ACCESS,
+                description: "1 - Assert DREQ to DMA when fewer than fifo_threshold spaces are available 0 - Don't assert DREQ",
+            },
+            FieldInfo {
+                name: "DWELL_TIME",
+                bits: PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_LSB,
+                reset: Some(PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_RESET),
+                access: PROC_PIO_SM3_DMACTRL_RX_DWELL_TIME_ACCESS,
+                description: "Delay in number of bus cycles before successive DREQs are generated. Used to account for system bus latency in write data arriving at the FIFO.",
+            },
+            FieldInfo {
+                name: "FIFO_THRESHOLD",
+                bits: PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_BITS,
+                msb: PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_MSB,
+                lsb: PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_LSB,
+                reset: Some(PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_RESET),
+                access: PROC_PIO_SM3_DMACTRL_RX_FIFO_THRESHOLD_ACCESS,
+                description: "Threshold control. If there are at least THRESHOLD items in the RX FIFO, DMA dreq and/or the interrupt line is asserted.",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INTR",
+        offset: PROC_PIO_INTR_OFFSET,
+        bits: PROC_PIO_INTR_BITS,
+        reset: Some(PROC_PIO_INTR_RESET),
+        description: "Raw Interrupts",
+        fields: &[
+            FieldInfo {
+                name: "SM3",
+                bits: PROC_PIO_INTR_SM3_BITS,
+                msb: PROC_PIO_INTR_SM3_MSB,
+                lsb: PROC_PIO_INTR_SM3_LSB,
+                reset: Some(PROC_PIO_INTR_SM3_RESET),
+                access: PROC_PIO_INTR_SM3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2",
+                bits: PROC_PIO_INTR_SM2_BITS,
+                msb: PROC_PIO_INTR_SM2_MSB,
+                lsb: PROC_PIO_INTR_SM2_LSB,
+                reset: Some(PROC_PIO_INTR_SM2_RESET),
+                access: PROC_PIO_INTR_SM2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1",
+                bits: PROC_PIO_INTR_SM1_BITS,
+                msb: PROC_PIO_INTR_SM1_MSB,
+                lsb: PROC_PIO_INTR_SM1_LSB,
+                reset: Some(PROC_PIO_INTR_SM1_RESET),
+                access: PROC_PIO_INTR_SM1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0",
+                bits: PROC_PIO_INTR_SM0_BITS,
+                msb: PROC_PIO_INTR_SM0_MSB,
+                lsb: PROC_PIO_INTR_SM0_LSB,
+                reset: Some(PROC_PIO_INTR_SM0_RESET),
+                access: PROC_PIO_INTR_SM0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_TXNFULL",
+                bits: PROC_PIO_INTR_SM3_TXNFULL_BITS,
+                msb: PROC_PIO_INTR_SM3_TXNFULL_MSB,
+                lsb: PROC_PIO_INTR_SM3_TXNFULL_LSB,
+                reset: Some(PROC_PIO_INTR_SM3_TXNFULL_RESET),
+                access: PROC_PIO_INTR_SM3_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_TXNFULL",
+                bits: PROC_PIO_INTR_SM2_TXNFULL_BITS,
+                msb: PROC_PIO_INTR_SM2_TXNFULL_MSB,
+                lsb: PROC_PIO_INTR_SM2_TXNFULL_LSB,
+                reset: Some(PROC_PIO_INTR_SM2_TXNFULL_RESET),
+                access: PROC_PIO_INTR_SM2_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_TXNFULL",
+                bits: PROC_PIO_INTR_SM1_TXNFULL_BITS,
+                msb: PROC_PIO_INTR_SM1_TXNFULL_MSB,
+                lsb: PROC_PIO_INTR_SM1_TXNFULL_LSB,
+                reset: Some(PROC_PIO_INTR_SM1_TXNFULL_RESET),
+                access: PROC_PIO_INTR_SM1_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_TXNFULL",
+                bits: PROC_PIO_INTR_SM0_TXNFULL_BITS,
+                msb: PROC_PIO_INTR_SM0_TXNFULL_MSB,
+                lsb: PROC_PIO_INTR_SM0_TXNFULL_LSB,
+                reset: Some(PROC_PIO_INTR_SM0_TXNFULL_RESET),
+                access: PROC_PIO_INTR_SM0_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_RXNEMPTY",
+                bits: PROC_PIO_INTR_SM3_RXNEMPTY_BITS,
+                msb: PROC_PIO_INTR_SM3_RXNEMPTY_MSB,
+                lsb: PROC_PIO_INTR_SM3_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_INTR_SM3_RXNEMPTY_RESET),
+                access: PROC_PIO_INTR_SM3_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_RXNEMPTY",
+                bits: PROC_PIO_INTR_SM2_RXNEMPTY_BITS,
+                msb: PROC_PIO_INTR_SM2_RXNEMPTY_MSB,
+                lsb: PROC_PIO_INTR_SM2_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_INTR_SM2_RXNEMPTY_RESET),
+                access: PROC_PIO_INTR_SM2_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_RXNEMPTY",
+                bits: PROC_PIO_INTR_SM1_RXNEMPTY_BITS,
+                msb: PROC_PIO_INTR_SM1_RXNEMPTY_MSB,
+                lsb: PROC_PIO_INTR_SM1_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_INTR_SM1_RXNEMPTY_RESET),
+                access: PROC_PIO_INTR_SM1_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_RXNEMPTY",
+                bits: PROC_PIO_INTR_SM0_RXNEMPTY_BITS,
+                msb: PROC_PIO_INTR_SM0_RXNEMPTY_MSB,
+                lsb: PROC_PIO_INTR_SM0_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_INTR_SM0_RXNEMPTY_RESET),
+                access: PROC_PIO_INTR_SM0_RXNEMPTY_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ0_INTE",
+        offset: PROC_PIO_IRQ0_INTE_OFFSET,
+        bits: PROC_PIO_IRQ0_INTE_BITS,
+        reset: Some(PROC_PIO_IRQ0_INTE_RESET),
+        description: "Interrupt Enable for irq0",
+        fields: &[
+            FieldInfo {
+                name: "SM3",
+                bits: PROC_PIO_IRQ0_INTE_SM3_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM3_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM3_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM3_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2",
+                bits: PROC_PIO_IRQ0_INTE_SM2_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM2_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM2_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM2_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1",
+                bits: PROC_PIO_IRQ0_INTE_SM1_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM1_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM1_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM1_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0",
+                bits: PROC_PIO_IRQ0_INTE_SM0_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM0_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM0_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM0_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTE_SM3_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM3_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM3_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM3_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM3_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTE_SM2_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM2_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM2_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM2_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM2_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTE_SM1_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM1_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM1_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM1_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM1_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTE_SM0_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM0_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM0_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM0_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM0_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM3_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM2_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM1_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ0_INTF",
+        offset: PROC_PIO_IRQ0_INTF_OFFSET,
+        bits: PROC_PIO_IRQ0_INTF_BITS,
+        reset: Some(PROC_PIO_IRQ0_INTF_RESET),
+        description: "Interrupt Force for irq0",
+        fields: &[
+            FieldInfo {
+                name: "SM3",
+                bits: PROC_PIO_IRQ0_INTF_SM3_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM3_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM3_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM3_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2",
+                bits: PROC_PIO_IRQ0_INTF_SM2_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM2_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM2_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM2_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1",
+                bits: PROC_PIO_IRQ0_INTF_SM1_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM1_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM1_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM1_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0",
+                bits: PROC_PIO_IRQ0_INTF_SM0_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM0_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM0_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM0_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTF_SM3_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM3_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM3_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM3_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM3_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTF_SM2_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM2_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM2_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM2_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM2_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTF_SM1_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM1_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM1_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM1_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM1_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTF_SM0_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM0_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM0_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM0_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM0_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM3_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM2_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM1_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ0_INTS",
+        offset: PROC_PIO_IRQ0_INTS_OFFSET,
+        bits: PROC_PIO_IRQ0_INTS_BITS,
+        reset: Some(PROC_PIO_IRQ0_INTS_RESET),
+        description: "Interrupt status after masking & forcing for irq0",
+        fields: &[
+            FieldInfo {
+                name: "SM3",
+                bits: PROC_PIO_IRQ0_INTS_SM3_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM3_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM3_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM3_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2",
+                bits: PROC_PIO_IRQ0_INTS_SM2_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM2_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM2_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM2_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1",
+                bits: PROC_PIO_IRQ0_INTS_SM1_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM1_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM1_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM1_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0",
+                bits: PROC_PIO_IRQ0_INTS_SM0_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM0_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM0_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM0_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTS_SM3_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM3_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM3_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM3_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM3_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTS_SM2_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM2_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM2_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM2_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM2_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTS_SM1_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM1_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM1_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM1_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM1_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_TXNFULL",
+                bits: PROC_PIO_IRQ0_INTS_SM0_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM0_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM0_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM0_TXNFULL_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM0_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM3_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM2_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM1_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_RXNEMPTY",
+                bits: PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ1_INTE",
+        offset: PROC_PIO_IRQ1_INTE_OFFSET,
+        bits: PROC_PIO_IRQ1_INTE_BITS,
+        reset: Some(PROC_PIO_IRQ1_INTE_RESET),
+        description: "Interrupt Enable for irq1",
+        fields: &[
+            FieldInfo {
+                name: "SM3",
+                bits: PROC_PIO_IRQ1_INTE_SM3_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM3_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM3_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM3_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2",
+                bits: PROC_PIO_IRQ1_INTE_SM2_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM2_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM2_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM2_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1",
+                bits: PROC_PIO_IRQ1_INTE_SM1_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM1_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM1_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM1_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0",
+                bits: PROC_PIO_IRQ1_INTE_SM0_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM0_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM0_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM0_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTE_SM3_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM3_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM3_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM3_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM3_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTE_SM2_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM2_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM2_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM2_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM2_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTE_SM1_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM1_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM1_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM1_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM1_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTE_SM0_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM0_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM0_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM0_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM0_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM3_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM2_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM1_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTE_SM0_RXNEMPTY_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ1_INTF",
+        offset: PROC_PIO_IRQ1_INTF_OFFSET,
+        bits: PROC_PIO_IRQ1_INTF_BITS,
+        reset: Some(PROC_PIO_IRQ1_INTF_RESET),
+        description: "Interrupt Force for irq1",
+        fields: &[
+            FieldInfo {
+                name: "SM3",
+                bits: PROC_PIO_IRQ1_INTF_SM3_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM3_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM3_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM3_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2",
+                bits: PROC_PIO_IRQ1_INTF_SM2_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM2_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM2_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM2_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1",
+                bits: PROC_PIO_IRQ1_INTF_SM1_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM1_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM1_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM1_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0",
+                bits: PROC_PIO_IRQ1_INTF_SM0_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM0_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM0_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM0_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTF_SM3_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM3_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM3_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM3_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM3_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTF_SM2_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM2_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM2_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM2_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM2_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTF_SM1_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM1_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM1_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM1_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM1_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTF_SM0_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM0_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM0_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM0_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM0_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM3_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM2_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM1_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTF_SM0_RXNEMPTY_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "IRQ1_INTS",
+        offset: PROC_PIO_IRQ1_INTS_OFFSET,
+        bits: PROC_PIO_IRQ1_INTS_BITS,
+        reset: Some(PROC_PIO_IRQ1_INTS_RESET),
+        description: "Interrupt status after masking & forcing for irq1",
+        fields: &[
+            FieldInfo {
+                name: "SM3",
+                bits: PROC_PIO_IRQ1_INTS_SM3_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM3_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM3_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM3_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM3_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2",
+                bits: PROC_PIO_IRQ1_INTS_SM2_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM2_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM2_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM2_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM2_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1",
+                bits: PROC_PIO_IRQ1_INTS_SM1_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM1_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM1_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM1_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM1_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0",
+                bits: PROC_PIO_IRQ1_INTS_SM0_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM0_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM0_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM0_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM0_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTS_SM3_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM3_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM3_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM3_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM3_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTS_SM2_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM2_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM2_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM2_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM2_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTS_SM1_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM1_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM1_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM1_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM1_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_TXNFULL",
+                bits: PROC_PIO_IRQ1_INTS_SM0_TXNFULL_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM0_TXNFULL_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM0_TXNFULL_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM0_TXNFULL_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM0_TXNFULL_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM3_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM3_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM2_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM2_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM1_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM1_RXNEMPTY_ACCESS,
+                description: "",
+            },
+            FieldInfo {
+                name: "SM0_RXNEMPTY",
+                bits: PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_BITS,
+                msb: PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_MSB,
+                lsb: PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_LSB,
+                reset: Some(PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_RESET),
+                access: PROC_PIO_IRQ1_INTS_SM0_RXNEMPTY_ACCESS,
+                description: "",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "BLOCK_ID",
+        offset: PROC_PIO_BLOCK_ID_OFFSET,
+        bits: PROC_PIO_BLOCK_ID_BITS,
+        reset: Some(PROC_PIO_BLOCK_ID_RESET),
+        description: "Block Identifier Hexadecimal representation of : &str = \"pio2\";",
+        fields: &[
+            FieldInfo {
+                name: "BLOCK_ID",
+                bits: PROC_PIO_BLOCK_ID_BITS,
+                msb: PROC_PIO_BLOCK_ID_MSB,
+                lsb: PROC_PIO_BLOCK_ID_LSB,
+                reset: Some(PROC_PIO_BLOCK_ID_RESET),
+                access: PROC_PIO_BLOCK_ID_ACCESS,
+                description: "Block Identifier Hexadecimal representation of : &str = \"pio2\";",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "INSTANCE_ID",
+        offset: PROC_PIO_INSTANCE_ID_OFFSET,
+        bits: PROC_PIO_INSTANCE_ID_BITS,
+        reset: Some(PROC_PIO_INSTANCE_ID_RESET),
+        description: "Block Instance Identifier",
+        fields: &[
+            FieldInfo {
+                name: "INSTANCE_ID",
+                bits: PROC_PIO_INSTANCE_ID_BITS,
+                msb: PROC_PIO_INSTANCE_ID_MSB,
+                lsb: PROC_PIO_INSTANCE_ID_LSB,
+                reset: Some(PROC_PIO_INSTANCE_ID_RESET),
+                access: PROC_PIO_INSTANCE_ID_ACCESS,
+                description: "Block Instance Identifier",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RSTSEQ_AUTO",
+        offset: PROC_PIO_RSTSEQ_AUTO_OFFSET,
+        bits: PROC_PIO_RSTSEQ_AUTO_BITS,
+        reset: Some(PROC_PIO_RSTSEQ_AUTO_RESET),
+        description: "",
+        fields: &[
+            FieldInfo {
+                name: "BUSADAPTER",
+                bits: PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_BITS,
+                msb: PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_MSB,
+                lsb: PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_LSB,
+                reset: Some(PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_RESET),
+                access: PROC_PIO_RSTSEQ_AUTO_BUSADAPTER_ACCESS,
+                description: "1 = reset is controlled by the sequencer 0 = reset is controlled by rstseq_ctrl",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RSTSEQ_PARALLEL",
+        offset: PROC_PIO_RSTSEQ_PARALLEL_OFFSET,
+        bits: PROC_PIO_RSTSEQ_PARALLEL_BITS,
+        reset: Some(PROC_PIO_RSTSEQ_PARALLEL_RESET),
+        description: "",
+        fields: &[
+            FieldInfo {
+                name: "BUSADAPTER",
+                bits: PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_BITS,
+                msb: PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_MSB,
+                lsb: PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_LSB,
+                reset: Some(PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_RESET),
+                access: PROC_PIO_RSTSEQ_PARALLEL_BUSADAPTER_ACCESS,
+                description: "Is this reset parallel (i.e. not part of the sequence)",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RSTSEQ_CTRL",
+        offset: PROC_PIO_RSTSEQ_CTRL_OFFSET,
+        bits: PROC_PIO_RSTSEQ_CTRL_BITS,
+        reset: Some(PROC_PIO_RSTSEQ_CTRL_RESET),
+        description: "",
+        fields: &[
+            FieldInfo {
+                name: "BUSADAPTER",
+                bits: PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_BITS,
+                msb: PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_MSB,
+                lsb: PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_LSB,
+                reset: Some(PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_RESET),
+                access: PROC_PIO_RSTSEQ_CTRL_BUSADAPTER_ACCESS,
+                description: "1 = keep the reset asserted 0 = keep the reset deasserted This is ignored if rstseq_auto=1",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RSTSEQ_TRIG",
+        offset: PROC_PIO_RSTSEQ_TRIG_OFFSET,
+        bits: PROC_PIO_RSTSEQ_TRIG_BITS,
+        reset: Some(PROC_PIO_RSTSEQ_TRIG_RESET),
+        description: "",
+        fields: &[
+            FieldInfo {
+                name: "BUSADAPTER",
+                bits: PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_BITS,
+                msb: PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_MSB,
+                lsb: PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_LSB,
+                reset: Some(PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_RESET),
+                access: PROC_PIO_RSTSEQ_TRIG_BUSADAPTER_ACCESS,
+                description: "Pulses the reset output",
+            },
+        ],
+    },
+    RegisterInfo {
+        name: "RSTSEQ_DONE",
+        offset: PROC_PIO_RSTSEQ_DONE_OFFSET,
+        bits: PROC_PIO_RSTSEQ_DONE_BITS,
+        reset: Some(PROC_PIO_RSTSEQ_DONE_RESET),
+        description: "",
+        fields: &[
+            FieldInfo {
+                name: "BUSADAPTER",
+                bits: PROC_PIO_RSTSEQ_DONE_BUSADAPTER_BITS,
+                msb: PROC_PIO_RSTSEQ_DONE_BUSADAPTER_MSB,
+                lsb: PROC_PIO_RSTSEQ_DONE_BUSADAPTER_LSB,
+                reset: Some(PROC_PIO_RSTSEQ_DONE_BUSADAPTER_RESET),
+                access: PROC_PIO_RSTSEQ_DONE_BUSADAPTER_ACCESS,
+                description: "Indicates the current state of the reset",
+            },
+        ],
+    },
+];
@@ -1,5 +1,10 @@
 use std::ops::BitAnd;
 
+use crate::include::hardware::clocks::clock_index;
+use crate::include::hardware::gpio::{gpio_drive_strength, gpio_function, gpio_override};
+use crate::piolib::error::{PioError, PioResult};
+use crate::piolib::instance::Pio;
+
 pub const PARAM_ASSERTIONS_ENABLED_PIO: bool = cfg!(feature = "PARAM_ASSERTIONS_ENABLED_PIO");
 
 /*
@@ -9,14 +14,15 @@ pub const PARAM_ASSERTIONS_ENABLED_PIO: bool = cfg!(feature = "PARAM_ASSERTIONS_
 #define PIO_IS_ERR(x)(((uintptr_t)(x) >= (uintptr_t)-200))
 #define PIO_ERR_VAL(x)((int)(uintptr_t)(x))
 
-#define PIO_ORIGIN_ANY          ((uint)(~0))
-#define PIO_ORIGIN_INVALID      PIO_ORIGIN_ANY
-
-#define pio0 pio_open_helper(0)
 */
 
+pub const PIO_ORIGIN_ANY: u32 = !0;
+pub const PIO_ORIGIN_INVALID: u32 = PIO_ORIGIN_ANY;
+
 #[allow(non_camel_case_types)]
 #[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum pio_fifo_join {
     PIO_FIFO_JOIN_NONE = 0,
     PIO_FIFO_JOIN_TX = 1,
@@ -25,6 +31,8 @@ pub enum pio_fifo_join {
 
 #[allow(non_camel_case_types)]
 #[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum pio_mov_status_type {
     STATUS_TX_LESSTHAN = 0,
     STATUS_RX_LESSTHAN = 1,
@@ -95,6 +103,7 @@ impl BitAnd<u32> for &pio_src_dest {
 
 #[allow(non_camel_case_types)]
 #[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct pio_program<'a> {
     instructions: &'a [u16],
     length: u8,
@@ -102,775 +111,826 @@ pub struct pio_program<'a> {
     pio_version: u8,
 }
 
-#[allow(non_camel_case_types)]
-#[repr(C)]
-pub struct pio_sm_config {
-    content: [u32; 4],
-}
-/*
-
-
-typedef struct pio_instance *PIO;
-typedef const struct pio_chip PIO_CHIP_T;
-
-struct pio_chip {
-    const char *name;
-    const char *compatible;
-    uint16_t instr_count;
-    uint16_t sm_count;
-    uint16_t fifo_depth;
-    void *hw_state;
-
-    PIO (*create_instance)(PIO_CHIP_T *chip, uint index);
-    int (*open_instance)(PIO pio);
-    void (*close_instance)(PIO pio);
-
-    int (*pio_sm_config_xfer)(PIO pio, uint sm, uint dir, uint buf_size, uint buf_count);
-    int (*pio_sm_xfer_data)(PIO pio, uint sm, uint dir, uint data_bytes, void *data);
-
-    bool (*pio_can_add_program_at_offset)(PIO pio, const pio_program_t *program, uint offset);
-    uint (*pio_add_program_at_offset)(PIO pio, const pio_program_t *program, uint offset);
-    bool (*pio_remove_program)(PIO pio, const pio_program_t *program, uint loaded_offset);
-    bool (*pio_clear_instruction_memory)(PIO pio);
-    uint (*pio_encode_delay)(PIO pio, uint cycles);
-    uint (*pio_encode_sideset)(PIO pio, uint sideset_bit_count, uint value);
-    uint (*pio_encode_sideset_opt)(PIO pio, uint sideset_bit_count, uint value);
-    uint (*pio_encode_jmp)(PIO pio, uint addr);
-    uint (*pio_encode_jmp_not_x)(PIO pio, uint addr);
-    uint (*pio_encode_jmp_x_dec)(PIO pio, uint addr);
-    uint (*pio_encode_jmp_not_y)(PIO pio, uint addr);
-    uint (*pio_encode_jmp_y_dec)(PIO pio, uint addr);
-    uint (*pio_encode_jmp_x_ne_y)(PIO pio, uint addr);
-    uint (*pio_encode_jmp_pin)(PIO pio, uint addr);
-    uint (*pio_encode_jmp_not_osre)(PIO pio, uint addr);
-    uint (*pio_encode_wait_gpio)(PIO pio, bool polarity, uint gpio);
-    uint (*pio_encode_wait_pin)(PIO pio, bool polarity, uint pin);
-    uint (*pio_encode_wait_irq)(PIO pio, bool polarity, bool relative, uint irq);
-    uint (*pio_encode_in)(PIO pio, enum pio_src_dest src, uint count);
-    uint (*pio_encode_out)(PIO pio, enum pio_src_dest dest, uint count);
-    uint (*pio_encode_push)(PIO pio, bool if_full, bool block);
-    uint (*pio_encode_pull)(PIO pio, bool if_empty, bool block);
-    uint (*pio_encode_mov)(PIO pio, enum pio_src_dest dest, enum pio_src_dest src);
-    uint (*pio_encode_mov_not)(PIO pio, enum pio_src_dest dest, enum pio_src_dest src);
-    uint (*pio_encode_mov_reverse)(PIO pio, enum pio_src_dest dest, enum pio_src_dest src);
-    uint (*pio_encode_irq_set)(PIO pio, bool relative, uint irq);
-    uint (*pio_encode_irq_wait)(PIO pio, bool relative, uint irq);
-    uint (*pio_encode_irq_clear)(PIO pio, bool relative, uint irq);
-    uint (*pio_encode_set)(PIO pio, enum pio_src_dest dest, uint value);
-    uint (*pio_encode_nop)(PIO pio);
-
-    bool (*pio_sm_claim)(PIO pio, uint sm);
-    bool (*pio_sm_claim_mask)(PIO pio, uint mask);
-    int (*pio_sm_claim_unused)(PIO pio, bool required);
-    bool (*pio_sm_unclaim)(PIO pio, uint sm);
-    bool (*pio_sm_is_claimed)(PIO pio, uint sm);
-
-    void (*pio_sm_init)(PIO pio, uint sm, uint initial_pc, const pio_sm_config *config);
-    void (*pio_sm_set_config)(PIO pio, uint sm, const pio_sm_config *config);
-    void (*pio_sm_exec)(PIO pio, uint sm, uint instr, bool blocking);
-    void (*pio_sm_clear_fifos)(PIO pio, uint sm);
-    void (*pio_sm_set_clkdiv_int_frac)(PIO pio, uint sm, uint16_t div_int, uint8_t div_frac);
-    void (*pio_sm_set_clkdiv)(PIO pio, uint sm, float div);
-    void (*pio_sm_set_pins)(PIO pio, uint sm, uint32_t pin_values);
-    void (*pio_sm_set_pins_with_mask)(PIO pio, uint sm, uint32_t pin_values, uint32_t pin_mask);
-    void (*pio_sm_set_pindirs_with_mask)(PIO pio, uint sm, uint32_t pin_dirs, uint32_t pin_mask);
-    void (*pio_sm_set_consecutive_pindirs)(PIO pio, uint sm, uint pin_base, uint pin_count, bool is_out);
-    void (*pio_sm_set_enabled)(PIO pio, uint sm, bool enabled);
-    void (*pio_sm_set_enabled_mask)(PIO pio, uint32_t mask, bool enabled);
-    void (*pio_sm_restart)(PIO pio, uint sm);
-    void (*pio_sm_restart_mask)(PIO pio, uint32_t mask);
-    void (*pio_sm_clkdiv_restart)(PIO pio, uint sm);
-    void (*pio_sm_clkdiv_restart_mask)(PIO pio, uint32_t mask);
-    void (*pio_sm_enable_sync)(PIO pio, uint32_t mask);
-    void (*pio_sm_put)(PIO pio, uint sm, uint32_t data, bool blocking);
-    uint32_t (*pio_sm_get)(PIO pio, uint sm, bool blocking);
-    void (*pio_sm_set_dmactrl)(PIO pio, uint sm, bool is_tx, uint32_t ctrl);
-    bool (*pio_sm_is_rx_fifo_empty)(PIO pio, uint sm);
-    bool (*pio_sm_is_rx_fifo_full)(PIO pio, uint sm);
-    uint (*pio_sm_get_rx_fifo_level)(PIO pio, uint sm);
-    bool (*pio_sm_is_tx_fifo_empty)(PIO pio, uint sm);
-    bool (*pio_sm_is_tx_fifo_full)(PIO pio, uint sm);
-    uint (*pio_sm_get_tx_fifo_level)(PIO pio, uint sm);
-    void (*pio_sm_drain_tx_fifo)(PIO pio, uint sm);
-
-    pio_sm_config (*pio_get_default_sm_config)(PIO pio);
-    void (*smc_set_out_pins)(PIO pio, pio_sm_config *c, uint out_base, uint out_count);
-    void (*smc_set_set_pins)(PIO pio, pio_sm_config *c, uint set_base, uint set_count);
-    void (*smc_set_in_pins)(PIO pio, pio_sm_config *c, uint in_base);
-    void (*smc_set_sideset_pins)(PIO pio, pio_sm_config *c, uint sideset_base);
-    void (*smc_set_sideset)(PIO pio, pio_sm_config *c, uint bit_count, bool optional, bool pindirs);
-    void (*smc_set_clkdiv_int_frac)(PIO pio, pio_sm_config *c, uint16_t div_int, uint8_t div_frac);
-    void (*smc_set_clkdiv)(PIO pio, pio_sm_config *c, float div);
-    void (*smc_set_wrap)(PIO pio, pio_sm_config *c, uint wrap_target, uint wrap);
-    void (*smc_set_jmp_pin)(PIO pio, pio_sm_config *c, uint pin);
-    void (*smc_set_in_shift)(PIO pio, pio_sm_config *c, bool shift_right, bool autopush, uint push_threshold);
-    void (*smc_set_out_shift)(PIO pio, pio_sm_config *c, bool shift_right, bool autopull, uint pull_threshold);
-    void (*smc_set_fifo_join)(PIO pio, pio_sm_config *c, enum pio_fifo_join join);
-    void (*smc_set_out_special)(PIO pio, pio_sm_config *c, bool sticky, bool has_enable_pin, uint enable_pin_index);
-    void (*smc_set_mov_status)(PIO pio, pio_sm_config *c, enum pio_mov_status_type status_sel, uint status_n);
-
-    uint32_t (*clock_get_hz)(PIO pio, enum clock_index clk_index);
-    void (*pio_gpio_init)(PIO, uint pin);
-    void (*gpio_init)(PIO pio, uint gpio);
-    void (*gpio_set_function)(PIO pio, uint gpio, enum gpio_function fn);
-    void (*gpio_set_pulls)(PIO pio, uint gpio, bool up, bool down);
-    void (*gpio_set_outover)(PIO pio, uint gpio, uint value);
-    void (*gpio_set_inover)(PIO pio, uint gpio, uint value);
-    void (*gpio_set_oeover)(PIO pio, uint gpio, uint value);
-    void (*gpio_set_input_enabled)(PIO pio, uint gpio, bool enabled);
-    void (*gpio_set_drive_strength)(PIO pio, uint gpio, enum gpio_drive_strength drive);
-};
-
-struct pio_instance {
-    const PIO_CHIP_T *chip;
-    int in_use;
-    bool errors_are_fatal;
-    bool error;
-};
-
-int pio_init(void);
-PIO pio_open(uint idx);
-PIO pio_open_by_name(const char *name);
-PIO pio_open_helper(uint idx);
-void pio_close(PIO pio);
-void pio_panic(const char *msg);
-int pio_get_index(PIO pio);
-void pio_select(PIO pio);
-PIO pio_get_current(void);
-
-static inline void pio_error(PIO pio, const char *msg)
-{
-    pio->error = true;
-    if (pio->errors_are_fatal)
-        pio_panic(msg);
-}
-
-static inline bool pio_get_error(PIO pio)
-{
-    return pio->error;
-}
-
-static inline void pio_clear_error(PIO pio)
-{
-    pio->error = false;
-}
-
-static inline void pio_enable_fatal_errors(PIO pio, bool enable)
-{
-    pio->errors_are_fatal = enable;
-}
-
-static inline uint pio_get_sm_count(PIO pio)
-{
-    return pio->chip->sm_count;
-}
-
-static inline uint pio_get_instruction_count(PIO pio)
-{
-    return pio->chip->instr_count;
-}
-
-static inline uint pio_get_fifo_depth(PIO pio)
-{
-    return pio->chip->fifo_depth;
-}
-
-static inline void check_pio_param(__unused PIO pio)
-{
-    valid_params_if(PIO, pio_get_index(pio) >= 0);
-}
-
-static inline int pio_sm_config_xfer(PIO pio, uint sm, uint dir, uint buf_size, uint buf_count)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_config_xfer(pio, sm, dir, buf_size, buf_count);
-}
-
-static inline int pio_sm_xfer_data(PIO pio, uint sm, uint dir, uint data_bytes, void *data)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_xfer_data(pio, sm, dir, data_bytes, data);
-}
-
-static inline bool pio_can_add_program(PIO pio, const pio_program_t *program)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_can_add_program_at_offset(pio, program, PIO_ORIGIN_ANY);
-}
-
-static inline bool pio_can_add_program_at_offset(PIO pio, const pio_program_t *program, uint offset)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_can_add_program_at_offset(pio, program, offset);
-}
-
-static inline uint pio_add_program(PIO pio, const pio_program_t *program)
-{
-    uint offset;
-    check_pio_param(pio);
-    offset = pio->chip->pio_add_program_at_offset(pio, program, PIO_ORIGIN_ANY);
-    if (offset == PIO_ORIGIN_INVALID)
-        pio_error(pio, "No program space");
-    return offset;
-}
-
-static inline void pio_add_program_at_offset(PIO pio, const pio_program_t *program, uint offset)
-{
-    check_pio_param(pio);
-    if (pio->chip->pio_add_program_at_offset(pio, program, offset) == PIO_ORIGIN_INVALID)
-        pio_error(pio, "No program space");
-}
-
-static inline void pio_remove_program(PIO pio, const pio_program_t *program, uint loaded_offset)
-{
-    check_pio_param(pio);
-    if (!pio->chip->pio_remove_program(pio, program, loaded_offset))
-        pio_error(pio, "Failed to remove program");
-}
-
-static inline void pio_clear_instruction_memory(PIO pio)
-{
-    check_pio_param(pio);
-    if (!pio->chip->pio_clear_instruction_memory(pio))
-        pio_error(pio, "Failed to clear instruction memory");
-}
-
-static inline uint pio_encode_delay(uint cycles)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_delay(pio, cycles);
-}
-
-static inline uint pio_encode_sideset(uint sideset_bit_count, uint value)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_sideset(pio, sideset_bit_count, value);
-}
-
-static inline uint pio_encode_sideset_opt(uint sideset_bit_count, uint value)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_sideset_opt(pio, sideset_bit_count, value);
-}
-
-static inline uint pio_encode_jmp(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp(pio, addr);
-}
-
-static inline uint pio_encode_jmp_not_x(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp_not_x(pio, addr);
-}
-
-static inline uint pio_encode_jmp_x_dec(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp_x_dec(pio, addr);
+/// Reason [`pio_program::new`] rejected a program
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PioProgramError {
+    Empty,
+    TooLong { length: usize },
+    OriginOutOfRange { origin: u8, length: u8 },
+    OriginMismatch { origin: u8, offset: u8 },
+    UnsupportedPioVersion(u8),
+    WrapOutOfRange { wrap: u8, offset: u8 },
+}
+
+impl std::fmt::Display for PioProgramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PioProgramError::Empty => write!(f, "program has no instructions"),
+            PioProgramError::TooLong { length } => write!(
+                f,
+                "program has {length} instructions, more than the {PIO_INSTRUCTION_COUNT} that fit"
+            ),
+            PioProgramError::OriginOutOfRange { origin, length } => write!(
+                f,
+                "program of {length} instructions does not fit at origin {origin}"
+            ),
+            PioProgramError::OriginMismatch { origin, offset } => write!(
+                f,
+                "program with origin {origin} cannot be loaded at offset {offset}"
+            ),
+            PioProgramError::UnsupportedPioVersion(version) => {
+                write!(f, "unsupported PIO version {version}")
+            }
+            PioProgramError::WrapOutOfRange { wrap, offset } => write!(
+                f,
+                "wrap {wrap} moved by offset {offset} is past the end of instruction memory"
+            ),
+        }
+    }
 }
 
-static inline uint pio_encode_jmp_not_y(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp_not_y(pio, addr);
-}
+impl std::error::Error for PioProgramError {}
 
-static inline uint pio_encode_jmp_y_dec(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp_y_dec(pio, addr);
-}
+const fn check_program(len: usize, origin: Option<u8>, pio_version: u8) -> Result<(), PioProgramError> {
+    if len == 0 {
+        return Err(PioProgramError::Empty);
+    }
+    if len > PIO_INSTRUCTION_COUNT as usize {
+        return Err(PioProgramError::TooLong { length: len });
+    }
+    if let Some(origin) = origin {
+        if origin as usize + len > PIO_INSTRUCTION_COUNT as usize {
+            return Err(PioProgramError::OriginOutOfRange { origin, length: len as u8 });
+        }
+    }
+    // 0 is RP2040/RP1, 1 is RP2350
+    if pio_version > 1 {
+        return Err(PioProgramError::UnsupportedPioVersion(pio_version));
+    }
+    Ok(())
+}
+
+impl<'a> pio_program<'a> {
+    /// Build a program, checking it fits in instruction memory; `origin` is `None` to load anywhere
+    pub const fn new(
+        instructions: &'a [u16],
+        origin: Option<u8>,
+        pio_version: u8,
+    ) -> Result<Self, PioProgramError> {
+        if let Err(err) = check_program(instructions.len(), origin, pio_version) {
+            return Err(err);
+        }
+        let origin = match origin {
+            Some(origin) => origin as i8,
+            None => -1,
+        };
+        Ok(pio_program { instructions, length: instructions.len() as u8, origin, pio_version })
+    }
 
-static inline uint pio_encode_jmp_x_ne_y(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp_x_ne_y(pio, addr);
-}
+    /// Check the program as [`pio_program::new`] does, and that its origin allows `offset`
+    ///
+    /// Backends call this on loading, so programs built with [`pio_program::from_parts`] are
+    /// checked too.
+    pub const fn check(&self, offset: Option<u8>) -> Result<(), PioProgramError> {
+        if let Err(err) = check_program(self.instructions.len(), self.origin(), self.pio_version) {
+            return Err(err);
+        }
+        match (self.origin(), offset) {
+            (Some(origin), Some(offset)) if origin != offset => Err(PioProgramError::OriginMismatch { origin, offset }),
+            _ => Ok(()),
+        }
+    }
 
-static inline uint pio_encode_jmp_pin(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp_pin(pio, addr);
-}
+    /// Build a program from raw parts without validation; `origin` is -1 for any origin
+    pub const fn from_parts(instructions: &'a [u16], origin: i8, pio_version: u8) -> Self {
+        pio_program {
+            instructions,
+            length: instructions.len() as u8,
+            origin,
+            pio_version,
+        }
+    }
 
-static inline uint pio_encode_jmp_not_osre(uint addr)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_jmp_not_osre(pio, addr);
-}
+    pub const fn instructions(&self) -> &'a [u16] {
+        self.instructions
+    }
 
-static inline uint pio_encode_wait_gpio(bool polarity, uint gpio)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_wait_gpio(pio, polarity, gpio);
-}
+    pub const fn length(&self) -> u8 {
+        self.length
+    }
 
-static inline uint pio_encode_wait_pin(bool polarity, uint pin)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_wait_pin(pio, polarity, pin);
-}
+    /// Fixed load offset, or `None` for `PIO_ORIGIN_ANY`
+    pub const fn origin(&self) -> Option<u8> {
+        if self.origin < 0 {
+            None
+        } else {
+            Some(self.origin as u8)
+        }
+    }
 
-static inline uint pio_encode_wait_irq(bool polarity, bool relative, uint irq)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_wait_irq(pio, polarity, relative, irq);
-}
+    /// Load offset in the C representation, `PIO_ORIGIN_ANY` if the program can go anywhere
+    pub const fn origin_raw(&self) -> u32 {
+        match self.origin() {
+            Some(origin) => origin as u32,
+            None => PIO_ORIGIN_ANY,
+        }
+    }
 
-static inline uint pio_encode_in(enum pio_src_dest src, uint count)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_in(pio, src, count);
-}
+    pub const fn pio_version(&self) -> u8 {
+        self.pio_version
+    }
 
-static inline uint pio_encode_out(enum pio_src_dest dest, uint count)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_out(pio, dest, count);
-}
+    pub fn iter(&self) -> std::iter::Copied<std::slice::Iter<'a, u16>> {
+        self.instructions.iter().copied()
+    }
 
-static inline uint pio_encode_push(bool if_full, bool block)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_push(pio, if_full, block);
+    /// Instruction image for loading at `offset`, with every JMP target shifted by `offset`
+    /// the way `pio_add_program_at_offset` writes it to instruction memory
+    pub fn relocated(&self, offset: u8) -> Result<Vec<u16>, PioProgramError> {
+        use crate::include::hardware::pio_instructions::{_pio_major_instr_bits, pio_instr_bits};
+        if let Some(origin) = self.origin() {
+            if origin != offset {
+                return Err(PioProgramError::OriginMismatch { origin, offset });
+            }
+        }
+        if offset as usize + self.instructions.len() > PIO_INSTRUCTION_COUNT as usize {
+            return Err(PioProgramError::OriginOutOfRange { origin: offset, length: self.length });
+        }
+        Ok(self
+            .iter()
+            .map(|instr| {
+                if _pio_major_instr_bits(instr as u32) == pio_instr_bits::pio_instr_bits_jmp as u32 {
+                    // targets stay within the 5 bit address field; the delay/side-set bits are untouched
+                    (instr & !0x1f) | (instr.wrapping_add(offset as u16) & 0x1f)
+                } else {
+                    instr
+                }
+            })
+            .collect())
+    }
 }
 
-static inline uint pio_encode_pull(bool if_empty, bool block)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_pull(pio, if_empty, block);
-}
+impl<'a> IntoIterator for &pio_program<'a> {
+    type Item = u16;
+    type IntoIter = std::iter::Copied<std::slice::Iter<'a, u16>>;
 
-static inline uint pio_encode_mov(enum pio_src_dest dest, enum pio_src_dest src)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_mov(pio, dest, src);
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
-static inline uint pio_encode_mov_not(enum pio_src_dest dest, enum pio_src_dest src)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_mov_not(pio, dest, src);
+/// Owned counterpart of [`pio_program`], for programs loaded at run time rather than compiled in
+///
+/// With the `serde` feature both serialize as `instructions`, `origin` (absent or null for any)
+/// and `pio_version`. Only this one can be deserialized since `pio_program` borrows its
+/// instructions; [`PioProgramBuf::as_program`] then checks it like [`pio_program::new`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PioProgramBuf {
+    pub instructions: Vec<u16>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub origin: Option<u8>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pio_version: u8,
+}
+
+impl PioProgramBuf {
+    pub fn as_program(&self) -> Result<pio_program<'_>, PioProgramError> {
+        pio_program::new(&self.instructions, self.origin, self.pio_version)
+    }
 }
 
-static inline uint pio_encode_mov_reverse(enum pio_src_dest dest, enum pio_src_dest src)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_mov_reverse(pio, dest, src);
+impl From<&pio_program<'_>> for PioProgramBuf {
+    fn from(program: &pio_program<'_>) -> Self {
+        PioProgramBuf {
+            instructions: program.instructions().to_vec(),
+            origin: program.origin(),
+            pio_version: program.pio_version(),
+        }
+    }
 }
 
-static inline uint pio_encode_irq_set(bool relative, uint irq)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_irq_set(pio, relative, irq);
+#[cfg(feature = "serde")]
+impl serde::Serialize for pio_program<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut program = serializer.serialize_struct("pio_program", 3)?;
+        program.serialize_field("instructions", self.instructions)?;
+        program.serialize_field("origin", &self.origin())?;
+        program.serialize_field("pio_version", &self.pio_version)?;
+        program.end()
+    }
 }
 
-static inline uint pio_encode_irq_wait(bool relative, uint irq)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_irq_wait(pio, relative, irq);
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct pio_sm_config {
+    content: [u32; 4],
 }
 
-static inline uint pio_encode_irq_clear(bool relative, uint irq)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_irq_clear(pio, relative, irq);
-}
+impl pio_sm_config {
+    /// Build a configuration from raw state machine register values
+    pub const fn from_registers(clkdiv: u32, execctrl: u32, shiftctrl: u32, pinctrl: u32) -> Self {
+        let mut content = [0; 4];
+        content[SMC_CLKDIV] = clkdiv;
+        content[SMC_EXECCTRL] = execctrl;
+        content[SMC_SHIFTCTRL] = shiftctrl;
+        content[SMC_PINCTRL] = pinctrl;
+        pio_sm_config { content }
+    }
 
-static inline uint pio_encode_set(enum pio_src_dest dest, uint value)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_set(pio, dest, value);
-}
+    /// Value for the SMx_CLKDIV register
+    pub const fn clkdiv(&self) -> u32 {
+        self.content[SMC_CLKDIV]
+    }
 
-static inline uint pio_encode_nop(void)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_encode_nop(pio);
-}
+    /// Value for the SMx_EXECCTRL register
+    pub const fn execctrl(&self) -> u32 {
+        self.content[SMC_EXECCTRL]
+    }
 
-static inline void pio_sm_claim(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    if (!pio->chip->pio_sm_claim(pio, sm))
-        pio_error(pio, "Failed to claim SM");
-}
+    /// Value for the SMx_SHIFTCTRL register
+    pub const fn shiftctrl(&self) -> u32 {
+        self.content[SMC_SHIFTCTRL]
+    }
 
-static inline void pio_claim_sm_mask(PIO pio, uint mask)
-{
-    check_pio_param(pio);
-    if (!pio->chip->pio_sm_claim_mask(pio, mask))
-        pio_error(pio, "Failed to claim masked SMs");
+    /// Value for the SMx_PINCTRL register
+    pub const fn pinctrl(&self) -> u32 {
+        self.content[SMC_PINCTRL]
+    }
 }
 
-static inline void pio_sm_unclaim(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_unclaim(pio, sm);
+pub const PIO_INSTRUCTION_COUNT: u32 = 32;
+
+// pio_sm_config content words, laid out as the RP1 backend stores them
+const SMC_CLKDIV: usize = 0;
+const SMC_EXECCTRL: usize = 1;
+const SMC_SHIFTCTRL: usize = 2;
+const SMC_PINCTRL: usize = 3;
+
+/* \brief Get the default state machine configuration
+ *
+ * Clock divider 1.0, wrap 0..31, IN and OUT shifting right with a threshold of 32 and
+ * no pin mappings; this matches the state machine register reset values apart from PINCTRL.
+ */
+pub const fn pio_get_default_sm_config() -> pio_sm_config {
+    use crate::include::hardware::regs::proc_pio::*;
+    pio_sm_config::from_registers(PROC_PIO_SM0_CLKDIV_RESET, PROC_PIO_SM0_EXECCTRL_RESET, PROC_PIO_SM0_SHIFTCTRL_RESET, 0)
+}
+
+/* \brief Set the 'wrap' settings in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param wrap_target the instruction memory address to wrap to
+ * \param wrap        the instruction memory address after which to set the program counter to wrap_target
+ *                    if the instruction does not itself update the program_counter
+ */
+pub fn sm_config_set_wrap(c: &mut pio_sm_config, wrap_target: u32, wrap: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, wrap < PIO_INSTRUCTION_COUNT);
+    crate::valid_params_if!(PIO, wrap_target < PIO_INSTRUCTION_COUNT);
+    c.content[SMC_EXECCTRL] = (c.content[SMC_EXECCTRL]
+        & !(PROC_PIO_SM0_EXECCTRL_WRAP_TOP_BITS | PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_BITS))
+        | ((wrap_target << PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_LSB) & PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_BITS)
+        | ((wrap << PROC_PIO_SM0_EXECCTRL_WRAP_TOP_LSB) & PROC_PIO_SM0_EXECCTRL_WRAP_TOP_BITS);
+}
+
+/* \brief Relocate a program loaded at `offset` together with the configuration for it
+ *
+ * The configuration's wrap and wrap_target are taken as relative to the start of the program (as
+ * produced by `<name>_program_get_default_config(0)`) and moved by `offset`; moving either past
+ * the end of instruction memory is an error rather than wrapping round.
+ *
+ * \return the instruction image to write to instruction memory starting at `offset`
+ */
+pub fn pio_relocate_program(
+    program: &pio_program,
+    offset: u8,
+    c: &mut pio_sm_config,
+) -> Result<Vec<u16>, PioProgramError> {
+    use crate::include::hardware::regs::proc_pio::*;
+    let image = program.relocated(offset)?;
+    let execctrl = c.content[SMC_EXECCTRL];
+    let wrap_target = (execctrl & PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_BITS) >> PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_LSB;
+    let wrap = (execctrl & PROC_PIO_SM0_EXECCTRL_WRAP_TOP_BITS) >> PROC_PIO_SM0_EXECCTRL_WRAP_TOP_LSB;
+    // wrap_target is at most wrap for any program that runs, but check both
+    let furthest = wrap.max(wrap_target);
+    if furthest + offset as u32 >= PIO_INSTRUCTION_COUNT {
+        return Err(PioProgramError::WrapOutOfRange { wrap: furthest as u8, offset });
+    }
+    sm_config_set_wrap(c, wrap_target + offset as u32, wrap + offset as u32);
+    Ok(image)
+}
+
+/* \brief Set the 'sideset' options in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param bit_count Number of bits to steal from delay field in the instruction for use of side set (max 5)
+ * \param optional True if the topmost side set bit is used as a flag for whether to apply side set on that instruction
+ * \param pindirs True if the side set affects pin directions rather than values
+ */
+pub fn sm_config_set_sideset(c: &mut pio_sm_config, bit_count: u32, optional: bool, pindirs: bool) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, bit_count <= 5);
+    crate::valid_params_if!(PIO, !optional || bit_count >= 1);
+    c.content[SMC_PINCTRL] = (c.content[SMC_PINCTRL] & !PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_BITS)
+        | ((bit_count << PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_LSB) & PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_BITS);
+    c.content[SMC_EXECCTRL] = (c.content[SMC_EXECCTRL]
+        & !(PROC_PIO_SM0_EXECCTRL_SIDE_EN_BITS | PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_BITS))
+        | (crate::bool_to_bit!(optional) << PROC_PIO_SM0_EXECCTRL_SIDE_EN_LSB)
+        | (crate::bool_to_bit!(pindirs) << PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_LSB);
+}
+/* \brief Set the 'out' pins in a state machine configuration
+ *
+ * Can overlap with the 'in', 'set' and 'sideset' pins
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param out_base 0-31 First pin to set as output
+ * \param out_count 0-32 Number of pins to set.
+ */
+pub fn sm_config_set_out_pins(c: &mut pio_sm_config, out_base: u32, out_count: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, out_base < 32);
+    crate::valid_params_if!(PIO, out_count <= 32);
+    c.content[SMC_PINCTRL] = (c.content[SMC_PINCTRL]
+        & !(PROC_PIO_SM0_PINCTRL_OUT_BASE_BITS | PROC_PIO_SM0_PINCTRL_OUT_COUNT_BITS))
+        | ((out_base << PROC_PIO_SM0_PINCTRL_OUT_BASE_LSB) & PROC_PIO_SM0_PINCTRL_OUT_BASE_BITS)
+        | ((out_count << PROC_PIO_SM0_PINCTRL_OUT_COUNT_LSB) & PROC_PIO_SM0_PINCTRL_OUT_COUNT_BITS);
+}
+
+/* \brief Set the 'set' pins in a state machine configuration
+ *
+ * Can overlap with the 'in', 'out' and 'sideset' pins
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param set_base 0-31 First pin to set as
+ * \param set_count 0-5 Number of pins to set.
+ */
+pub fn sm_config_set_set_pins(c: &mut pio_sm_config, set_base: u32, set_count: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, set_base < 32);
+    crate::valid_params_if!(PIO, set_count <= 5);
+    c.content[SMC_PINCTRL] = (c.content[SMC_PINCTRL]
+        & !(PROC_PIO_SM0_PINCTRL_SET_BASE_BITS | PROC_PIO_SM0_PINCTRL_SET_COUNT_BITS))
+        | ((set_base << PROC_PIO_SM0_PINCTRL_SET_BASE_LSB) & PROC_PIO_SM0_PINCTRL_SET_BASE_BITS)
+        | ((set_count << PROC_PIO_SM0_PINCTRL_SET_COUNT_LSB) & PROC_PIO_SM0_PINCTRL_SET_COUNT_BITS);
+}
+
+/* \brief Set the 'in' pins in a state machine configuration
+ *
+ * Can overlap with the 'out', 'set' and 'sideset' pins
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param in_base 0-31 First pin to use as input
+ */
+pub fn sm_config_set_in_pins(c: &mut pio_sm_config, in_base: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, in_base < 32);
+    c.content[SMC_PINCTRL] = (c.content[SMC_PINCTRL] & !PROC_PIO_SM0_PINCTRL_IN_BASE_BITS)
+        | ((in_base << PROC_PIO_SM0_PINCTRL_IN_BASE_LSB) & PROC_PIO_SM0_PINCTRL_IN_BASE_BITS);
+}
+
+/* \brief Set the 'sideset' pins in a state machine configuration
+ *
+ * Can overlap with the 'in', 'out' and 'set' pins
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param sideset_base 0-31 base pin for 'side set'
+ */
+pub fn sm_config_set_sideset_pins(c: &mut pio_sm_config, sideset_base: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, sideset_base < 32);
+    c.content[SMC_PINCTRL] = (c.content[SMC_PINCTRL] & !PROC_PIO_SM0_PINCTRL_SIDESET_BASE_BITS)
+        | ((sideset_base << PROC_PIO_SM0_PINCTRL_SIDESET_BASE_LSB) & PROC_PIO_SM0_PINCTRL_SIDESET_BASE_BITS);
+}
+
+/* \brief Set the state machine clock divider (from integer and fractional parts - 16:8) in a state machine configuration
+ *
+ * The clock divider can slow the state machine's execution to some rate below
+ * the system clock frequency, by enabling the state machine on some cycles
+ * but not on others, in a regular pattern. This can be used to generate e.g.
+ * a given UART baud rate. See the datasheet for further detail.
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param div_int Integer part of the divisor
+ * \param div_frac Fractional part in 1/256ths
+ */
+pub fn sm_config_set_clkdiv_int_frac(c: &mut pio_sm_config, div_int: u16, div_frac: u8) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::invalid_params_if!(PIO, div_int == 0 && div_frac != 0);
+    c.content[SMC_CLKDIV] = ((div_frac as u32) << PROC_PIO_SM0_CLKDIV_FRAC_LSB) | ((div_int as u32) << PROC_PIO_SM0_CLKDIV_INT_LSB);
+}
+
+/* \brief Split a clock divider into its 16.8 integer and fractional parts
+ *
+ * A divider of 65536 comes back as an integer part of 0, which the hardware treats as 65536.
+ */
+pub fn pio_calculate_clkdiv_from_float(div: f32) -> (u16, u8) {
+    crate::valid_params_if!(PIO, (1.0..=65536.0).contains(&div));
+    let div_int = div as u32 as u16;
+    let div_frac = if div_int == 0 { 0 } else { ((div - div_int as f32) * 256.0) as u8 };
+    (div_int, div_frac)
+}
+
+/* \brief Set the state machine clock divider (from a floating point value) in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param div The fractional divisor to be set. 1 for full speed. An integer clock divisor of n
+ *  will cause the state machine to run 1 cycle in every n.
+ *  Note that for small n, the jitter introduced by a fractional divider (e.g. 2.5) may be unacceptable
+ *  although it will depend on the use case.
+ */
+pub fn sm_config_set_clkdiv(c: &mut pio_sm_config, div: f32) {
+    let (div_int, div_frac) = pio_calculate_clkdiv_from_float(div);
+    sm_config_set_clkdiv_int_frac(c, div_int, div_frac);
+}
+
+/* \brief Set the 'jmp' pin in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param pin The raw GPIO pin number to use as the source for a `jmp pin` instruction
+ */
+pub fn sm_config_set_jmp_pin(c: &mut pio_sm_config, pin: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, pin < 32);
+    c.content[SMC_EXECCTRL] = (c.content[SMC_EXECCTRL] & !PROC_PIO_SM0_EXECCTRL_JMP_PIN_BITS)
+        | ((pin << PROC_PIO_SM0_EXECCTRL_JMP_PIN_LSB) & PROC_PIO_SM0_EXECCTRL_JMP_PIN_BITS);
+}
+
+/* \brief Setup 'in' shifting parameters in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param shift_right true to shift ISR to right, false to shift ISR to left
+ * \param autopush whether autopush is enabled
+ * \param push_threshold threshold in bits to shift in before auto/conditional re-pushing of the ISR, 1-32 (32 is stored as 0)
+ */
+pub fn sm_config_set_in_shift(c: &mut pio_sm_config, shift_right: bool, autopush: bool, push_threshold: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, push_threshold <= 32);
+    c.content[SMC_SHIFTCTRL] = (c.content[SMC_SHIFTCTRL]
+        & !(PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_BITS
+            | PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_BITS
+            | PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_BITS))
+        | (crate::bool_to_bit!(shift_right) << PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_LSB)
+        | (crate::bool_to_bit!(autopush) << PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_LSB)
+        | (((push_threshold & 0x1f) << PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_LSB) & PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_BITS);
+}
+
+/* \brief Setup 'out' shifting parameters in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param shift_right true to shift OSR to right, false to shift OSR to left
+ * \param autopull whether autopull is enabled
+ * \param pull_threshold threshold in bits to shift out before auto/conditional re-pulling of the OSR, 1-32 (32 is stored as 0)
+ */
+pub fn sm_config_set_out_shift(c: &mut pio_sm_config, shift_right: bool, autopull: bool, pull_threshold: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, pull_threshold <= 32);
+    c.content[SMC_SHIFTCTRL] = (c.content[SMC_SHIFTCTRL]
+        & !(PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_BITS
+            | PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_BITS
+            | PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_BITS))
+        | (crate::bool_to_bit!(shift_right) << PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_LSB)
+        | (crate::bool_to_bit!(autopull) << PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_LSB)
+        | (((pull_threshold & 0x1f) << PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_LSB) & PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_BITS);
+}
+
+/* \brief Setup the FIFO joining in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param join Specifies the join type. \see enum pio_fifo_join
+ */
+pub fn sm_config_set_fifo_join(c: &mut pio_sm_config, join: pio_fifo_join) {
+    use crate::include::hardware::regs::proc_pio::*;
+    c.content[SMC_SHIFTCTRL] = (c.content[SMC_SHIFTCTRL]
+        & !(PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_BITS | PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS))
+        | (crate::bool_to_bit!(join == pio_fifo_join::PIO_FIFO_JOIN_TX) << PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_LSB)
+        | (crate::bool_to_bit!(join == pio_fifo_join::PIO_FIFO_JOIN_RX) << PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_LSB);
+}
+
+/* \brief Set special 'out' operations in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param sticky to enable 'sticky' output (i.e. re-asserting most recent OUT/SET pin values on subsequent cycles)
+ * \param has_enable_pin true to enable auxiliary OUT enable pin
+ * \param enable_pin_index pin index for auxiliary OUT enable
+ */
+pub fn sm_config_set_out_special(c: &mut pio_sm_config, sticky: bool, has_enable_pin: bool, enable_pin_index: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, enable_pin_index < 32);
+    c.content[SMC_EXECCTRL] = (c.content[SMC_EXECCTRL]
+        & !(PROC_PIO_SM0_EXECCTRL_OUT_STICKY_BITS
+            | PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_BITS
+            | PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_BITS))
+        | (crate::bool_to_bit!(sticky) << PROC_PIO_SM0_EXECCTRL_OUT_STICKY_LSB)
+        | (crate::bool_to_bit!(has_enable_pin) << PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_LSB)
+        | ((enable_pin_index << PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_LSB) & PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_BITS);
+}
+
+/* \brief Set source for 'mov status' in a state machine configuration
+ *
+ * \param c Pointer to the configuration structure to modify
+ * \param status_sel the status operation selector. \see enum pio_mov_status_type
+ * \param status_n parameter for the mov status operation (currently a bit count)
+ */
+pub fn sm_config_set_mov_status(c: &mut pio_sm_config, status_sel: pio_mov_status_type, status_n: u32) {
+    use crate::include::hardware::regs::proc_pio::*;
+    crate::valid_params_if!(PIO, status_n < 32);
+    c.content[SMC_EXECCTRL] = (c.content[SMC_EXECCTRL]
+        & !(PROC_PIO_SM0_EXECCTRL_STATUS_SEL_BITS | PROC_PIO_SM0_EXECCTRL_STATUS_N_BITS))
+        | (((status_sel as u32) << PROC_PIO_SM0_EXECCTRL_STATUS_SEL_LSB) & PROC_PIO_SM0_EXECCTRL_STATUS_SEL_BITS)
+        | ((status_n << PROC_PIO_SM0_EXECCTRL_STATUS_N_LSB) & PROC_PIO_SM0_EXECCTRL_STATUS_N_BITS);
+}
+
+// The C `struct pio_instance` with its `errors_are_fatal` and sticky `error` flags is
+// crate::piolib::instance::Pio. Every wrapper below returns the error as well as recording it, so
+// `pio_get_error` is only needed by code ported from C.
+
+pub fn pio_get_error(pio: &Pio) -> bool {
+    pio.last_error().is_some()
+}
+
+pub fn pio_clear_error(pio: &mut Pio) {
+    pio.clear_error()
+}
+
+pub fn pio_enable_fatal_errors(pio: &mut Pio, enable: bool) {
+    pio.enable_fatal_errors(enable)
+}
+
+pub fn pio_get_sm_count(pio: &Pio) -> u32 {
+    pio.sm_count()
+}
+
+pub fn pio_get_instruction_count(pio: &Pio) -> u32 {
+    pio.instruction_count()
+}
+
+pub fn pio_get_fifo_depth(pio: &Pio) -> u32 {
+    pio.fifo_depth()
+}
+
+/// `PIO_ORIGIN_ANY` becomes `None`, anything else must fit the chip's `u8` offsets
+fn offset_param(offset: u32) -> PioResult<Option<u8>> {
+    match offset {
+        PIO_ORIGIN_ANY => Ok(None),
+        _ => u8::try_from(offset).map(Some).map_err(|_| PioError::InvalidParam { which: "offset" }),
+    }
 }
 
-static inline int pio_claim_unused_sm(PIO pio, bool required)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_claim_unused(pio, required);
+pub fn pio_sm_config_xfer(pio: &mut Pio, sm: u32, dir: pio_xfer_dir, buf_size: u32, buf_count: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_config_xfer(sm, dir, buf_size, buf_count))
 }
 
-static inline bool pio_sm_is_claimed(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_is_claimed(pio, sm);
+pub fn pio_sm_xfer_data(pio: &mut Pio, sm: u32, dir: pio_xfer_dir, data: &mut [u8]) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_xfer_data(sm, dir, data))
 }
 
-static inline void pio_sm_init(PIO pio, uint sm, uint initial_pc, const pio_sm_config *config)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_init(pio, sm, initial_pc, config);
+pub fn pio_can_add_program(pio: &mut Pio, program: &pio_program) -> PioResult<bool> {
+    pio_can_add_program_at_offset(pio, program, PIO_ORIGIN_ANY)
 }
 
-static inline void pio_sm_set_config(PIO pio, uint sm, const pio_sm_config *config)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_config(pio, sm, config);
+pub fn pio_can_add_program_at_offset(pio: &mut Pio, program: &pio_program, offset: u32) -> PioResult<bool> {
+    pio.with_chip(|chip| chip.can_add_program_at_offset(program, offset_param(offset)?))
 }
 
-static inline void pio_sm_exec(PIO pio, uint sm, uint instr)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_exec(pio, sm, instr, false);
+/* \brief Load `program` wherever it fits, returning the offset it was loaded at
+ */
+pub fn pio_add_program(pio: &mut Pio, program: &pio_program) -> PioResult<u32> {
+    pio.with_chip(|chip| chip.add_program_at_offset(program, None)).map(u32::from)
 }
 
-static inline void pio_sm_exec_wait_blocking(PIO pio, uint sm, uint instr)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_exec(pio, sm, instr, true);
+pub fn pio_add_program_at_offset(pio: &mut Pio, program: &pio_program, offset: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.add_program_at_offset(program, offset_param(offset)?)).map(drop)
 }
 
-static inline void pio_sm_clear_fifos(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_clear_fifos(pio, sm);
+pub fn pio_remove_program(pio: &mut Pio, program: &pio_program, loaded_offset: u32) -> PioResult<()> {
+    pio.with_chip(|chip| {
+        let loaded_offset = offset_param(loaded_offset)?.ok_or(PioError::InvalidParam { which: "offset" })?;
+        chip.remove_program(program, loaded_offset)
+    })
 }
 
-static inline void pio_sm_set_clkdiv_int_frac(PIO pio, uint sm, uint16_t div_int, uint8_t div_frac)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_clkdiv_int_frac(pio, sm, div_int, div_frac);
+pub fn pio_clear_instruction_memory(pio: &mut Pio) -> PioResult<()> {
+    pio.with_chip(|chip| chip.clear_instruction_memory())
 }
 
-static inline void pio_sm_set_clkdiv(PIO pio, uint sm, float div)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_clkdiv(pio, sm, div);
+pub fn pio_sm_claim(pio: &mut Pio, sm: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_claim(sm))
 }
 
-static inline void pio_sm_set_pins(PIO pio, uint sm, uint32_t pin_values)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_pins(pio, sm, pin_values);
+pub fn pio_claim_sm_mask(pio: &mut Pio, mask: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_claim_mask(mask))
 }
 
-static inline void pio_sm_set_pins_with_mask(PIO pio, uint sm, uint32_t pin_values, uint32_t pin_mask)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_pins_with_mask(pio, sm, pin_values, pin_mask);
+pub fn pio_sm_unclaim(pio: &mut Pio, sm: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_unclaim(sm))
 }
 
-static inline void pio_sm_set_pindirs_with_mask(PIO pio, uint sm, uint32_t pin_dirs, uint32_t pin_mask)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_pindirs_with_mask(pio, sm, pin_dirs, pin_mask);
+/* \brief Claim a free state machine
+ *
+ * With `required` false, running out of state machines gives `Ok(None)` rather than an error,
+ * like the -1 the C version returns.
+ */
+pub fn pio_claim_unused_sm(pio: &mut Pio, required: bool) -> PioResult<Option<u32>> {
+    pio.with_chip(|chip| match chip.sm_claim_unused() {
+        Err(PioError::SmAlreadyClaimed) if !required => Ok(None),
+        result => result.map(Some),
+    })
 }
 
-static inline void pio_sm_set_consecutive_pindirs(PIO pio, uint sm, uint pin_base, uint pin_count, bool is_out)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_consecutive_pindirs(pio, sm, pin_base, pin_count, is_out);
+pub fn pio_sm_is_claimed(pio: &mut Pio, sm: u32) -> PioResult<bool> {
+    pio.with_chip(|chip| chip.sm_is_claimed(sm))
 }
 
-static inline void pio_sm_set_enabled(PIO pio, uint sm, bool enabled)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_enabled(pio, sm, enabled);
+pub fn pio_sm_init(pio: &mut Pio, sm: u32, initial_pc: u32, config: &pio_sm_config) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_init(sm, initial_pc, config))
 }
 
-static inline void pio_set_sm_mask_enabled(PIO pio, uint32_t mask, bool enabled)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_enabled_mask(pio, mask, enabled);
+pub fn pio_sm_set_config(pio: &mut Pio, sm: u32, config: &pio_sm_config) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_config(sm, config))
 }
 
-static inline void pio_sm_restart(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_restart(pio, sm);
+fn sm_exec(pio: &mut Pio, sm: u32, instr: u32, blocking: bool) -> PioResult<()> {
+    pio.with_chip(|chip| {
+        let instr = u16::try_from(instr).map_err(|_| PioError::InvalidParam { which: "instr" })?;
+        chip.sm_exec(sm, instr, blocking)
+    })
 }
 
-static inline void pio_restart_sm_mask(PIO pio, uint32_t mask)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_restart_mask(pio, mask);
+pub fn pio_sm_exec(pio: &mut Pio, sm: u32, instr: u32) -> PioResult<()> {
+    sm_exec(pio, sm, instr, false)
 }
 
-static inline void pio_sm_clkdiv_restart(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_clkdiv_restart(pio, sm);
+pub fn pio_sm_exec_wait_blocking(pio: &mut Pio, sm: u32, instr: u32) -> PioResult<()> {
+    sm_exec(pio, sm, instr, true)
 }
 
-static inline void pio_clkdiv_restart_sm_mask(PIO pio, uint32_t mask)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_clkdiv_restart_mask(pio, mask);
+pub fn pio_sm_clear_fifos(pio: &mut Pio, sm: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_clear_fifos(sm))
 }
 
-static inline void pio_enable_sm_in_sync_mask(PIO pio, uint32_t mask)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_enable_sync(pio, mask);
-};
-
-static inline void pio_sm_set_dmactrl(PIO pio, uint sm, bool is_tx, uint32_t ctrl)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_set_dmactrl(pio, sm, is_tx, ctrl);
-};
-
-static inline bool pio_sm_is_rx_fifo_empty(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_is_rx_fifo_empty(pio, sm);
+pub fn pio_sm_set_clkdiv_int_frac(pio: &mut Pio, sm: u32, div_int: u16, div_frac: u8) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_clkdiv_int_frac(sm, div_int, div_frac))
 }
 
-static inline bool pio_sm_is_rx_fifo_full(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_is_rx_fifo_full(pio, sm);
+pub fn pio_sm_set_clkdiv(pio: &mut Pio, sm: u32, div: f32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_clkdiv(sm, div))
 }
 
-static inline uint pio_sm_get_rx_fifo_level(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_get_rx_fifo_level(pio, sm);
+pub fn pio_sm_set_pins(pio: &mut Pio, sm: u32, pin_values: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_pins(sm, pin_values))
 }
 
-static inline bool pio_sm_is_tx_fifo_empty(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_is_tx_fifo_empty(pio, sm);
+pub fn pio_sm_set_pins_with_mask(pio: &mut Pio, sm: u32, pin_values: u32, pin_mask: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_pins_with_mask(sm, pin_values, pin_mask))
 }
 
-static inline bool pio_sm_is_tx_fifo_full(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_is_tx_fifo_full(pio, sm);
+pub fn pio_sm_set_pindirs_with_mask(pio: &mut Pio, sm: u32, pin_dirs: u32, pin_mask: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_pindirs_with_mask(sm, pin_dirs, pin_mask))
 }
 
-static inline uint pio_sm_get_tx_fifo_level(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_get_tx_fifo_level(pio, sm);
+pub fn pio_sm_set_consecutive_pindirs(pio: &mut Pio, sm: u32, pin_base: u32, pin_count: u32, is_out: bool) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_consecutive_pindirs(sm, pin_base, pin_count, is_out))
 }
 
-static inline void pio_sm_drain_tx_fifo(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_drain_tx_fifo(pio, sm);
+pub fn pio_sm_set_enabled(pio: &mut Pio, sm: u32, enabled: bool) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_enabled(sm, enabled))
 }
 
-static inline void pio_sm_put(PIO pio, uint sm, uint32_t data)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_put(pio, sm, data, false);
+pub fn pio_set_sm_mask_enabled(pio: &mut Pio, mask: u32, enabled: bool) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_enabled_mask(mask, enabled))
 }
 
-static inline void pio_sm_put_blocking(PIO pio, uint sm, uint32_t data)
-{
-    check_pio_param(pio);
-    pio->chip->pio_sm_put(pio, sm, data, true);
+pub fn pio_sm_restart(pio: &mut Pio, sm: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_restart(sm))
 }
 
-static inline uint32_t pio_sm_get(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_get(pio, sm, false);
+pub fn pio_restart_sm_mask(pio: &mut Pio, mask: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_restart_mask(mask))
 }
 
-static inline uint32_t pio_sm_get_blocking(PIO pio, uint sm)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_sm_get(pio, sm, true);
+pub fn pio_sm_clkdiv_restart(pio: &mut Pio, sm: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_clkdiv_restart(sm))
 }
 
-static inline pio_sm_config pio_get_default_sm_config_for_pio(PIO pio)
-{
-    check_pio_param(pio);
-    return pio->chip->pio_get_default_sm_config(pio);
+pub fn pio_clkdiv_restart_sm_mask(pio: &mut Pio, mask: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_clkdiv_restart_mask(mask))
 }
 
-static inline pio_sm_config pio_get_default_sm_config(void)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->pio_get_default_sm_config(pio);
+pub fn pio_enable_sm_in_sync_mask(pio: &mut Pio, mask: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_enable_sync(mask))
 }
 
-static inline void sm_config_set_out_pins(pio_sm_config *c, uint out_base, uint out_count)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_out_pins(pio, c, out_base, out_count);
+pub fn pio_sm_set_dmactrl(pio: &mut Pio, sm: u32, is_tx: bool, ctrl: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_set_dmactrl(sm, is_tx, ctrl))
 }
 
-static inline void sm_config_set_set_pins(pio_sm_config *c, uint set_base, uint set_count)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_set_pins(pio, c, set_base, set_count);
+pub fn pio_sm_is_rx_fifo_empty(pio: &mut Pio, sm: u32) -> PioResult<bool> {
+    pio.with_chip(|chip| chip.sm_is_rx_fifo_empty(sm))
 }
 
-static inline void sm_config_set_in_pins(pio_sm_config *c, uint in_base)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_in_pins(pio, c, in_base);
+pub fn pio_sm_is_rx_fifo_full(pio: &mut Pio, sm: u32) -> PioResult<bool> {
+    pio.with_chip(|chip| chip.sm_is_rx_fifo_full(sm))
 }
 
-static inline void sm_config_set_sideset_pins(pio_sm_config *c, uint sideset_base)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_sideset_pins(pio, c, sideset_base);
+pub fn pio_sm_get_rx_fifo_level(pio: &mut Pio, sm: u32) -> PioResult<u32> {
+    pio.with_chip(|chip| chip.sm_get_rx_fifo_level(sm))
 }
 
-static inline void sm_config_set_sideset(pio_sm_config *c, uint bit_count, bool optional, bool pindirs)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_sideset(pio, c, bit_count, optional, pindirs);
+pub fn pio_sm_is_tx_fifo_empty(pio: &mut Pio, sm: u32) -> PioResult<bool> {
+    pio.with_chip(|chip| chip.sm_is_tx_fifo_empty(sm))
 }
 
-static inline void sm_config_set_clkdiv_int_frac(pio_sm_config *c, uint16_t div_int, uint8_t div_frac)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_clkdiv_int_frac(pio, c, div_int, div_frac);
+pub fn pio_sm_is_tx_fifo_full(pio: &mut Pio, sm: u32) -> PioResult<bool> {
+    pio.with_chip(|chip| chip.sm_is_tx_fifo_full(sm))
 }
 
-static inline void sm_config_set_clkdiv(pio_sm_config *c, float div)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_clkdiv(pio, c, div);
+pub fn pio_sm_get_tx_fifo_level(pio: &mut Pio, sm: u32) -> PioResult<u32> {
+    pio.with_chip(|chip| chip.sm_get_tx_fifo_level(sm))
 }
 
-static inline void sm_config_set_wrap(pio_sm_config *c, uint wrap_target, uint wrap)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_wrap(pio, c, wrap_target, wrap);
+pub fn pio_sm_drain_tx_fifo(pio: &mut Pio, sm: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_drain_tx_fifo(sm))
 }
 
-static inline void sm_config_set_jmp_pin(pio_sm_config *c, uint pin)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_jmp_pin(pio, c, pin);
+pub fn pio_sm_put(pio: &mut Pio, sm: u32, data: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_put(sm, data, false))
 }
 
-static inline void sm_config_set_in_shift(pio_sm_config *c, bool shift_right, bool autopush, uint push_threshold)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_in_shift(pio, c, shift_right, autopush, push_threshold);
+pub fn pio_sm_put_blocking(pio: &mut Pio, sm: u32, data: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.sm_put(sm, data, true))
 }
 
-static inline void sm_config_set_out_shift(pio_sm_config *c, bool shift_right, bool autopull, uint pull_threshold)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_out_shift(pio, c, shift_right, autopull, pull_threshold);
+pub fn pio_sm_get(pio: &mut Pio, sm: u32) -> PioResult<u32> {
+    pio.with_chip(|chip| chip.sm_get(sm, false))
 }
 
-static inline void sm_config_set_fifo_join(pio_sm_config *c, enum pio_fifo_join join)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_fifo_join(pio, c, join);
+pub fn pio_sm_get_blocking(pio: &mut Pio, sm: u32) -> PioResult<u32> {
+    pio.with_chip(|chip| chip.sm_get(sm, true))
 }
+// The C header picks the PIO for these with pio_select()/pio_get_current(); here it is passed in
+// explicitly so each thread can drive its own instance. The encoders and sm_config_set_* need no
+// PIO at all.
 
-static inline void sm_config_set_out_special(pio_sm_config *c, bool sticky, bool has_enable_pin, uint enable_pin_index)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_out_special(pio, c, sticky, has_enable_pin, enable_pin_index);
+pub fn pio_get_default_sm_config_for_pio(pio: &Pio) -> pio_sm_config {
+    pio.chip().get_default_sm_config()
 }
 
-static inline void sm_config_set_mov_status(pio_sm_config *c, enum pio_mov_status_type status_sel, uint status_n)
-{
-    PIO pio = pio_get_current();
-    pio->chip->smc_set_mov_status(pio, c, status_sel, status_n);
+pub fn pio_gpio_init(pio: &mut Pio, pin: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.pio_gpio_init(pin))
 }
 
-static inline void pio_gpio_init(PIO pio, uint pin)
-{
-    check_pio_param(pio);
-    pio->chip->pio_gpio_init(pio, pin);
+pub fn clock_get_hz(pio: &mut Pio, clk_index: clock_index) -> PioResult<u32> {
+    pio.with_chip(|chip| chip.clock_get_hz(clk_index))
 }
 
-static inline uint32_t clock_get_hz(enum clock_index clk_index)
-{
-    PIO pio = pio_get_current();
-    return pio->chip->clock_get_hz(pio, clk_index);
+pub fn gpio_init(pio: &mut Pio, gpio: u32) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_init(gpio))
 }
 
-static inline void gpio_init(uint gpio)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_init(pio, gpio);
+pub fn gpio_set_function(pio: &mut Pio, gpio: u32, func: gpio_function) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_set_function(gpio, func))
 }
 
-static inline void gpio_set_function(uint gpio, enum gpio_function fn)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_set_function(pio, gpio, fn);
+pub fn gpio_set_pulls(pio: &mut Pio, gpio: u32, up: bool, down: bool) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_set_pulls(gpio, up, down))
 }
 
-
-static inline void gpio_set_pulls(uint gpio, bool up, bool down)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_set_pulls(pio, gpio, up, down);
+pub fn gpio_set_outover(pio: &mut Pio, gpio: u32, value: gpio_override) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_set_outover(gpio, value))
 }
 
-static inline void gpio_set_outover(uint gpio, uint value)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_set_outover(pio, gpio, value);
+pub fn gpio_set_inover(pio: &mut Pio, gpio: u32, value: gpio_override) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_set_inover(gpio, value))
 }
 
-static inline void gpio_set_inover(uint gpio, uint value)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_set_inover(pio, gpio, value);
+pub fn gpio_set_oeover(pio: &mut Pio, gpio: u32, value: gpio_override) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_set_oeover(gpio, value))
 }
 
-static inline void gpio_set_oeover(uint gpio, uint value)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_set_oeover(pio, gpio, value);
+pub fn gpio_set_input_enabled(pio: &mut Pio, gpio: u32, enabled: bool) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_set_input_enabled(gpio, enabled))
 }
 
-static inline void gpio_set_input_enabled(uint gpio, bool enabled)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_set_input_enabled(pio, gpio, enabled);
+pub fn gpio_set_drive_strength(pio: &mut Pio, gpio: u32, drive: gpio_drive_strength) -> PioResult<()> {
+    pio.with_chip(|chip| chip.gpio_set_drive_strength(gpio, drive))
 }
 
-static inline void gpio_set_drive_strength(uint gpio, enum gpio_drive_strength drive)
-{
-    PIO pio = pio_get_current();
-    pio->chip->gpio_set_drive_strength(pio, gpio, drive);
+pub fn gpio_pull_up(pio: &mut Pio, gpio: u32) -> PioResult<()> {
+    gpio_set_pulls(pio, gpio, true, false)
 }
 
-static inline void gpio_pull_up(uint gpio) {
-    gpio_set_pulls(gpio, true, false);
+pub fn gpio_pull_down(pio: &mut Pio, gpio: u32) -> PioResult<()> {
+    gpio_set_pulls(pio, gpio, false, true)
 }
 
-static inline void gpio_pull_down(uint gpio) {
-    gpio_set_pulls(gpio, false, true);
+pub fn gpio_disable_pulls(pio: &mut Pio, gpio: u32) -> PioResult<()> {
+    gpio_set_pulls(pio, gpio, false, false)
 }
 
-static inline void gpio_disable_pulls(uint gpio) {
-    gpio_set_pulls(gpio, false, false);
-}
+/*
 
 static inline void stdio_init_all(void)
 {
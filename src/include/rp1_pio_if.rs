@@ -0,0 +1,278 @@
+use crate::include::piolib::pio_sm_config;
+
+pub const RP1_PIO_INSTRUCTION_COUNT: usize = 32;
+pub const RP1_PIO_SM_COUNT: u32 = 4;
+pub const RP1_PIO_GPIO_COUNT: u32 = 28;
+pub const RP1_GPIO_FUNC_PIO: u16 = 7;
+
+pub const RP1_PIO_ORIGIN_ANY: u16 = !0;
+
+pub const RP1_PIO_DIR_TO_SM: u16 = 0;
+pub const RP1_PIO_DIR_FROM_SM: u16 = 1;
+pub const RP1_PIO_DIR_COUNT: u16 = 2;
+
+/// Argument structs for the rp1-pio ioctls
+///
+/// # Safety
+/// Implementors are `#[repr(C)]` plain data with no padding, so any byte pattern of the right
+/// size is a valid value and every byte of a value is initialised.
+pub unsafe trait Rp1PioArgs: Copy + Default {
+    fn as_bytes(&self) -> &[u8] {
+        // SAFETY: guaranteed by the trait contract
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
+    }
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // SAFETY: guaranteed by the trait contract
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut u8, size_of::<Self>()) }
+    }
+    /// Read the struct back out of an ioctl argument buffer, `None` if the size is wrong
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != size_of::<Self>() {
+            return None;
+        }
+        // SAFETY: the size matches and any byte pattern is valid by the trait contract
+        Some(unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const Self) })
+    }
+}
+
+macro_rules! rp1_pio_args {
+    ($($(#[$meta:meta])* pub struct $name:ident { $(pub $field:ident: $ty:ty,)* })*) => {$(
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub struct $name { $(pub $field: $ty,)* }
+        // SAFETY: repr(C) and the fields are laid out without padding
+        unsafe impl Rp1PioArgs for $name {}
+    )*};
+}
+
+rp1_pio_args! {
+    pub struct rp1_pio_sm_config {
+        pub clkdiv: u32,
+        pub execctrl: u32,
+        pub shiftctrl: u32,
+        pub pinctrl: u32,
+    }
+    pub struct rp1_pio_add_program_args {
+        pub num_instrs: u16,
+        pub origin: u16,
+        pub instrs: [u16; RP1_PIO_INSTRUCTION_COUNT],
+    }
+    pub struct rp1_pio_remove_program_args {
+        pub num_instrs: u16,
+        pub origin: u16,
+    }
+    pub struct rp1_pio_sm_claim_args {
+        pub mask: u16,
+    }
+    pub struct rp1_pio_sm_init_args {
+        pub sm: u16,
+        pub initial_pc: u16,
+        pub config: rp1_pio_sm_config,
+    }
+    pub struct rp1_pio_sm_set_config_args {
+        pub sm: u16,
+        pub rsvd: u16,
+        pub config: rp1_pio_sm_config,
+    }
+    pub struct rp1_pio_sm_exec_args {
+        pub sm: u16,
+        pub instr: u16,
+        pub blocking: u8,
+        pub rsvd: u8,
+    }
+    pub struct rp1_pio_sm_clear_fifos_args {
+        pub sm: u16,
+    }
+    pub struct rp1_pio_sm_set_clkdiv_args {
+        pub sm: u16,
+        pub div_int: u16,
+        pub div_frac: u8,
+        pub rsvd: u8,
+    }
+    pub struct rp1_pio_sm_set_pins_args {
+        pub sm: u16,
+        pub rsvd: u16,
+        pub values: u32,
+        pub mask: u32,
+    }
+    pub struct rp1_pio_sm_set_pindirs_args {
+        pub sm: u16,
+        pub rsvd: u16,
+        pub dirs: u32,
+        pub mask: u32,
+    }
+    pub struct rp1_pio_sm_set_enabled_args {
+        pub mask: u16,
+        pub enable: u8,
+        pub rsvd: u8,
+    }
+    pub struct rp1_pio_sm_restart_args {
+        pub mask: u16,
+    }
+    pub struct rp1_pio_sm_clkdiv_restart_args {
+        pub mask: u16,
+    }
+    pub struct rp1_pio_sm_enable_sync_args {
+        pub mask: u16,
+    }
+    pub struct rp1_pio_sm_put_args {
+        pub sm: u16,
+        pub blocking: u8,
+        pub rsvd: u8,
+        pub data: u32,
+    }
+    pub struct rp1_pio_sm_get_args {
+        pub sm: u16,
+        pub blocking: u8,
+        pub rsvd: u8,
+        pub data: u32, // IN/OUT
+    }
+    pub struct rp1_pio_sm_set_dmactrl_args {
+        pub sm: u16,
+        pub is_tx: u8,
+        pub rsvd: u8,
+        pub ctrl: u32,
+    }
+    pub struct rp1_pio_sm_fifo_state_args {
+        pub sm: u16,
+        pub tx: u8,
+        pub rsvd: u8,
+        pub level: u16, // OUT
+        pub empty: u8,  // OUT
+        pub full: u8,   // OUT
+    }
+    pub struct rp1_gpio_init_args {
+        pub gpio: u16,
+    }
+    pub struct rp1_gpio_set_function_args {
+        pub gpio: u16,
+        pub func: u16,
+    }
+    pub struct rp1_gpio_set_pulls_args {
+        pub gpio: u16,
+        pub up: u8,
+        pub down: u8,
+    }
+    pub struct rp1_gpio_set_args {
+        pub gpio: u16,
+        pub value: u16,
+    }
+    pub struct rp1_pio_sm_config_xfer_args {
+        pub sm: u16,
+        pub dir: u16,
+        pub buf_size: u16,
+        pub buf_count: u16,
+    }
+    pub struct rp1_pio_sm_config_xfer32_args {
+        pub sm: u32,
+        pub dir: u32,
+        pub buf_size: u32,
+        pub buf_count: u32,
+    }
+    pub struct rp1_access_hw_args {
+        pub addr: u32,
+        pub len: u32,
+        pub data: usize,
+    }
+    /// `data` is a user pointer; `rsvd` is the padding the C struct has before it
+    pub struct rp1_pio_sm_xfer_data_args {
+        pub sm: u16,
+        pub dir: u16,
+        pub data_bytes: u16,
+        pub rsvd: u16,
+        pub data: usize,
+    }
+    /// `data` is a user pointer
+    pub struct rp1_pio_sm_xfer_data32_args {
+        pub sm: u16,
+        pub dir: u16,
+        pub data_bytes: u32,
+        pub data: usize,
+    }
+}
+
+impl From<&pio_sm_config> for rp1_pio_sm_config {
+    fn from(c: &pio_sm_config) -> Self {
+        rp1_pio_sm_config {
+            clkdiv: c.clkdiv(),
+            execctrl: c.execctrl(),
+            shiftctrl: c.shiftctrl(),
+            pinctrl: c.pinctrl(),
+        }
+    }
+}
+
+const _IOC_NRBITS: u32 = 8;
+const _IOC_TYPEBITS: u32 = 8;
+const _IOC_SIZEBITS: u32 = 14;
+const _IOC_NRSHIFT: u32 = 0;
+const _IOC_TYPESHIFT: u32 = _IOC_NRSHIFT + _IOC_NRBITS;
+const _IOC_SIZESHIFT: u32 = _IOC_TYPESHIFT + _IOC_TYPEBITS;
+const _IOC_DIRSHIFT: u32 = _IOC_SIZESHIFT + _IOC_SIZEBITS;
+const _IOC_NONE: u32 = 0;
+const _IOC_WRITE: u32 = 1;
+const _IOC_READ: u32 = 2;
+
+#[allow(non_snake_case)]
+const fn _IOC(dir: u32, ty: u32, nr: u32, size: usize) -> u32 {
+    (dir << _IOC_DIRSHIFT) | (ty << _IOC_TYPESHIFT) | (nr << _IOC_NRSHIFT) | ((size as u32) << _IOC_SIZESHIFT)
+}
+#[allow(non_snake_case)]
+const fn _IO(ty: u32, nr: u32) -> u32 {
+    _IOC(_IOC_NONE, ty, nr, 0)
+}
+#[allow(non_snake_case)]
+const fn _IOW<T>(ty: u32, nr: u32) -> u32 {
+    _IOC(_IOC_WRITE, ty, nr, size_of::<T>())
+}
+#[allow(non_snake_case)]
+const fn _IOWR<T>(ty: u32, nr: u32) -> u32 {
+    _IOC(_IOC_READ | _IOC_WRITE, ty, nr, size_of::<T>())
+}
+
+pub const PIO_IOC_MAGIC: u32 = 102;
+
+pub const PIO_IOC_SM_CONFIG_XFER: u32 = _IOW::<rp1_pio_sm_config_xfer_args>(PIO_IOC_MAGIC, 0);
+pub const PIO_IOC_SM_XFER_DATA: u32 = _IOW::<rp1_pio_sm_xfer_data_args>(PIO_IOC_MAGIC, 1);
+pub const PIO_IOC_SM_XFER_DATA32: u32 = _IOW::<rp1_pio_sm_xfer_data32_args>(PIO_IOC_MAGIC, 2);
+pub const PIO_IOC_SM_CONFIG_XFER32: u32 = _IOW::<rp1_pio_sm_config_xfer32_args>(PIO_IOC_MAGIC, 3);
+
+pub const PIO_IOC_READ_HW: u32 = _IOW::<rp1_access_hw_args>(PIO_IOC_MAGIC, 8);
+pub const PIO_IOC_WRITE_HW: u32 = _IOW::<rp1_access_hw_args>(PIO_IOC_MAGIC, 9);
+
+pub const PIO_IOC_CAN_ADD_PROGRAM: u32 = _IOW::<rp1_pio_add_program_args>(PIO_IOC_MAGIC, 10);
+pub const PIO_IOC_ADD_PROGRAM: u32 = _IOW::<rp1_pio_add_program_args>(PIO_IOC_MAGIC, 11);
+pub const PIO_IOC_REMOVE_PROGRAM: u32 = _IOW::<rp1_pio_remove_program_args>(PIO_IOC_MAGIC, 12);
+pub const PIO_IOC_CLEAR_INSTR_MEM: u32 = _IO(PIO_IOC_MAGIC, 13);
+
+pub const PIO_IOC_SM_CLAIM: u32 = _IOW::<rp1_pio_sm_claim_args>(PIO_IOC_MAGIC, 20);
+pub const PIO_IOC_SM_UNCLAIM: u32 = _IOW::<rp1_pio_sm_claim_args>(PIO_IOC_MAGIC, 21);
+pub const PIO_IOC_SM_IS_CLAIMED: u32 = _IOW::<rp1_pio_sm_claim_args>(PIO_IOC_MAGIC, 22);
+
+pub const PIO_IOC_SM_INIT: u32 = _IOW::<rp1_pio_sm_init_args>(PIO_IOC_MAGIC, 30);
+pub const PIO_IOC_SM_SET_CONFIG: u32 = _IOW::<rp1_pio_sm_set_config_args>(PIO_IOC_MAGIC, 31);
+pub const PIO_IOC_SM_EXEC: u32 = _IOW::<rp1_pio_sm_exec_args>(PIO_IOC_MAGIC, 32);
+pub const PIO_IOC_SM_CLEAR_FIFOS: u32 = _IOW::<rp1_pio_sm_clear_fifos_args>(PIO_IOC_MAGIC, 33);
+pub const PIO_IOC_SM_SET_CLKDIV: u32 = _IOW::<rp1_pio_sm_set_clkdiv_args>(PIO_IOC_MAGIC, 34);
+pub const PIO_IOC_SM_SET_PINS: u32 = _IOW::<rp1_pio_sm_set_pins_args>(PIO_IOC_MAGIC, 35);
+pub const PIO_IOC_SM_SET_PINDIRS: u32 = _IOW::<rp1_pio_sm_set_pindirs_args>(PIO_IOC_MAGIC, 36);
+pub const PIO_IOC_SM_SET_ENABLED: u32 = _IOW::<rp1_pio_sm_set_enabled_args>(PIO_IOC_MAGIC, 37);
+pub const PIO_IOC_SM_RESTART: u32 = _IOW::<rp1_pio_sm_restart_args>(PIO_IOC_MAGIC, 38);
+pub const PIO_IOC_SM_CLKDIV_RESTART: u32 = _IOW::<rp1_pio_sm_restart_args>(PIO_IOC_MAGIC, 39);
+pub const PIO_IOC_SM_ENABLE_SYNC: u32 = _IOW::<rp1_pio_sm_enable_sync_args>(PIO_IOC_MAGIC, 40);
+pub const PIO_IOC_SM_PUT: u32 = _IOW::<rp1_pio_sm_put_args>(PIO_IOC_MAGIC, 41);
+pub const PIO_IOC_SM_GET: u32 = _IOWR::<rp1_pio_sm_get_args>(PIO_IOC_MAGIC, 42);
+pub const PIO_IOC_SM_SET_DMACTRL: u32 = _IOW::<rp1_pio_sm_set_dmactrl_args>(PIO_IOC_MAGIC, 43);
+pub const PIO_IOC_SM_FIFO_STATE: u32 = _IOW::<rp1_pio_sm_fifo_state_args>(PIO_IOC_MAGIC, 44);
+pub const PIO_IOC_SM_DRAIN_TX: u32 = _IOW::<rp1_pio_sm_clear_fifos_args>(PIO_IOC_MAGIC, 45);
+
+pub const PIO_IOC_GPIO_INIT: u32 = _IOW::<rp1_gpio_init_args>(PIO_IOC_MAGIC, 50);
+pub const PIO_IOC_GPIO_SET_FUNCTION: u32 = _IOW::<rp1_gpio_set_function_args>(PIO_IOC_MAGIC, 51);
+pub const PIO_IOC_GPIO_SET_PULLS: u32 = _IOW::<rp1_gpio_set_pulls_args>(PIO_IOC_MAGIC, 52);
+pub const PIO_IOC_GPIO_SET_OUTOVER: u32 = _IOW::<rp1_gpio_set_args>(PIO_IOC_MAGIC, 53);
+pub const PIO_IOC_GPIO_SET_INOVER: u32 = _IOW::<rp1_gpio_set_args>(PIO_IOC_MAGIC, 54);
+pub const PIO_IOC_GPIO_SET_OEOVER: u32 = _IOW::<rp1_gpio_set_args>(PIO_IOC_MAGIC, 55);
+pub const PIO_IOC_GPIO_SET_INPUT_ENABLED: u32 = _IOW::<rp1_gpio_set_args>(PIO_IOC_MAGIC, 56);
+pub const PIO_IOC_GPIO_SET_DRIVE_STRENGTH: u32 = _IOW::<rp1_gpio_set_args>(PIO_IOC_MAGIC, 57);
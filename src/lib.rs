@@ -1,6 +1,7 @@
 pub mod include {
     pub mod hardware {
         pub mod regs {
+            pub mod info;
             pub mod proc_pio;
         }
         pub mod clocks;
@@ -14,6 +15,24 @@ pub mod include {
     }
     pub mod pio_platform;
     pub mod piolib;
+    pub mod rp1_pio_if;
+}
+pub mod piolib {
+    pub mod chip;
+    pub mod error;
+    pub mod instance;
+    pub mod instr_mem;
+    pub mod pio_mmio;
+    pub mod pio_rp1;
+    pub mod pio_sim;
+    pub mod pio_sim_pads;
+    pub mod regs;
+    pub mod sm_config;
+}
+pub mod pioasm {
+    pub mod assembler;
+    pub mod build;
+    pub mod disassembler;
 }
 #[allow(non_snake_case)]
 pub mod PARAM_ASSERTIONS_ENABLED_;
@@ -0,0 +1,1092 @@
+//! Assembly of pioasm-compatible `.pio` source into instruction words
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::include::hardware::pio_instructions::*;
+use crate::include::piolib::pio_program;
+
+/// Maximum number of instructions in a single program (the size of PIO instruction memory)
+pub const PIO_ASM_MAX_INSTRUCTIONS: usize = 32;
+
+/// An assembly error, located by 1-based line and column within the source
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PioAsmError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PioAsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for PioAsmError {}
+
+/// A program assembled from a `.program` section, with the metadata pioasm would emit alongside it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PioAssembledProgram {
+    pub name: String,
+    pub instructions: Vec<u16>,
+    /// fixed load offset from `.origin`, or `None` if the program can be loaded anywhere
+    pub origin: Option<u8>,
+    pub wrap_target: u8,
+    pub wrap: u8,
+    pub side_set: PioSideSet,
+    pub pio_version: u8,
+    /// `public` labels and defines, in definition order (global public defines first)
+    pub public_symbols: Vec<(String, i32)>,
+    /// the `public` labels among `public_symbols`, as instruction offsets
+    pub public_labels: Vec<(String, u8)>,
+}
+
+impl PioAssembledProgram {
+    pub fn program(&self) -> pio_program<'_> {
+        pio_program::from_parts(
+            &self.instructions,
+            self.origin.map_or(-1, |origin| origin as i8),
+            self.pio_version,
+        )
+    }
+}
+
+/// A program assembled at compile time by `pio_asm!`
+pub struct PioStaticProgram {
+    pub name: &'static str,
+    pub program: pio_program<'static>,
+    pub wrap_target: u8,
+    pub wrap: u8,
+    pub side_set: PioSideSet,
+}
+
+/// Assemble every `.program` in `source`
+pub fn assemble(source: &str) -> Result<Vec<PioAssembledProgram>, PioAsmError> {
+    Assembler::default().run(source)
+}
+
+/// Assemble `source`, which must contain exactly one program
+///
+/// If `source` starts without a `.program` directive, its contents form a program named `program`.
+pub fn assemble_program(source: &str) -> Result<PioAssembledProgram, PioAsmError> {
+    let assembler = Assembler { implicit_program: true, ..Default::default() };
+    let mut programs = assembler.run(source)?;
+    match programs.len() {
+        1 => Ok(programs.remove(0)),
+        n => Err(PioAsmError {
+            line: 1,
+            column: 1,
+            message: format!("expected exactly one .program, found {n}"),
+        }),
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Pos {
+    line: usize,
+    column: usize,
+}
+
+impl Pos {
+    fn error<T>(self, message: impl Into<String>) -> Result<T, PioAsmError> {
+        Err(PioAsmError { line: self.line, column: self.column, message: message.into() })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Tok {
+    Ident(String),
+    Int(i64),
+    Punct(&'static str),
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    tok: Tok,
+    pos: Pos,
+}
+
+const PUNCTS: [&str; 20] = [
+    "::", "--", "!=", "<<", ">>", ",", ":", "[", "]", "(", ")", "+", "-", "*", "/", "|", "&", "^",
+    "~", "!",
+];
+
+/// Replace comments and `% target { ... %}` code blocks with spaces, keeping line/column positions intact
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut in_code_block = false;
+    let mut in_block_comment = false;
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if in_code_block {
+            in_code_block = !trimmed.starts_with("%}");
+            out.extend(line.chars().map(|c| if c == '\n' { c } else { ' ' }));
+            continue;
+        }
+        if !in_block_comment && trimmed.starts_with('%') {
+            in_code_block = true;
+            out.extend(line.chars().map(|c| if c == '\n' { c } else { ' ' }));
+            continue;
+        }
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if in_block_comment {
+                if c == '*' && chars.peek() == Some(&'/') {
+                    chars.next();
+                    out.push_str("  ");
+                    in_block_comment = false;
+                } else {
+                    out.push(if c == '\n' { c } else { ' ' });
+                }
+            } else if c == '/' && chars.peek() == Some(&'*') {
+                chars.next();
+                out.push_str("  ");
+                in_block_comment = true;
+            } else if c == ';' || (c == '/' && chars.peek() == Some(&'/')) {
+                out.push(' ');
+                for c in chars.by_ref() {
+                    out.push(if c == '\n' { c } else { ' ' });
+                }
+            } else {
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+fn tokenize_line(text: &str, line: usize) -> Result<Vec<Token>, PioAsmError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let pos = Pos { line, column: i + 1 };
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_alphanumeric() {
+                i += 1;
+            }
+            let literal: String = chars[start..i].iter().collect();
+            let lower = literal.to_ascii_lowercase();
+            let parsed = if let Some(hex) = lower.strip_prefix("0x") {
+                i64::from_str_radix(hex, 16)
+            } else if let Some(bin) = lower.strip_prefix("0b") {
+                i64::from_str_radix(bin, 2)
+            } else {
+                lower.parse()
+            };
+            match parsed {
+                Ok(value) => tokens.push(Token { tok: Tok::Int(value), pos }),
+                Err(_) => return pos.error(format!("invalid number '{literal}'")),
+            }
+        } else if c.is_ascii_alphabetic() || c == '_' || c == '.' {
+            let start = i;
+            i += 1;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token { tok: Tok::Ident(chars[start..i].iter().collect()), pos });
+        } else if let Some(punct) = PUNCTS
+            .iter()
+            .find(|p| p.chars().enumerate().all(|(n, pc)| chars.get(i + n) == Some(&pc)))
+        {
+            i += punct.len();
+            tokens.push(Token { tok: Tok::Punct(punct), pos });
+        } else {
+            return pos.error(format!("unexpected character '{c}'"));
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Clone, Copy, Debug)]
+enum UnaryOp {
+    Neg,
+    Not,
+    Reverse,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Or,
+    And,
+    Xor,
+    Shl,
+    Shr,
+}
+
+#[derive(Clone, Debug)]
+enum Expr {
+    Int(i64, Pos),
+    Symbol(String, Pos),
+    Unary(UnaryOp, Box<Expr>, Pos),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>, Pos),
+}
+
+impl Expr {
+    fn pos(&self) -> Pos {
+        match self {
+            Expr::Int(_, pos) | Expr::Symbol(_, pos) | Expr::Unary(_, _, pos) => *pos,
+            Expr::Binary(_, lhs, _, _) => lhs.pos(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum IrqMode {
+    Set,
+    Wait,
+    Clear,
+}
+
+#[derive(Clone, Debug)]
+enum Statement {
+    Jmp { condition: PioJmpCondition, target: Expr },
+    Wait { polarity: Option<Expr>, source: PioWaitSource, index: Expr, relative: bool },
+    In { source: PioInSource, bit_count: Expr },
+    Out { destination: PioOutDestination, bit_count: Expr },
+    Push { if_full: bool, block: bool },
+    Pull { if_empty: bool, block: bool },
+    Mov { destination: PioMovDestination, op: PioMovOp, source: PioMovSource },
+    Nop,
+    Irq { mode: IrqMode, index: Expr, relative: bool },
+    Set { destination: PioSetDestination, data: Expr },
+    Word(Expr),
+}
+
+#[derive(Clone, Debug)]
+struct PendingInstruction {
+    pos: Pos,
+    statement: Statement,
+    side_set: Option<(Expr, Pos)>,
+    delay: Option<(Expr, Pos)>,
+}
+
+#[derive(Clone, Debug)]
+struct Define {
+    expr: Expr,
+    pos: Pos,
+}
+
+#[derive(Default)]
+struct ProgramState {
+    name: String,
+    pos: Pos,
+    instructions: Vec<PendingInstruction>,
+    labels: HashMap<String, (i64, Pos)>,
+    defines: HashMap<String, Define>,
+    public_symbols: Vec<String>,
+    origin: Option<(Expr, Pos)>,
+    side_set: Option<(Expr, Pos, bool, bool)>,
+    pio_version: Option<(Expr, Pos)>,
+    wrap_target: Option<usize>,
+    wrap: Option<usize>,
+}
+
+#[derive(Default)]
+struct Assembler {
+    global_defines: HashMap<String, Define>,
+    global_public: Vec<String>,
+    program: Option<ProgramState>,
+    programs: Vec<PioAssembledProgram>,
+    implicit_program: bool,
+}
+
+struct Cursor<'t> {
+    tokens: &'t [Token],
+    index: usize,
+    end: Pos,
+}
+
+impl<'t> Cursor<'t> {
+    fn peek(&self) -> Option<&'t Token> {
+        self.tokens.get(self.index)
+    }
+
+    fn peek_at(&self, n: usize) -> Option<&'t Token> {
+        self.tokens.get(self.index + n)
+    }
+
+    fn pos(&self) -> Pos {
+        self.peek().map_or(self.end, |t| t.pos)
+    }
+
+    fn next(&mut self) -> Option<&'t Token> {
+        let token = self.tokens.get(self.index);
+        self.index += 1;
+        token
+    }
+
+    fn at_end(&self) -> bool {
+        self.index >= self.tokens.len()
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some(Token { tok: Tok::Punct(p), .. }) if *p == punct)
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        let found = self.is_punct(punct);
+        if found {
+            self.index += 1;
+        }
+        found
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<(), PioAsmError> {
+        if self.eat_punct(punct) {
+            Ok(())
+        } else {
+            self.pos().error(format!("expected '{punct}'"))
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        is_keyword(self.peek(), keyword)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.is_keyword(keyword);
+        if found {
+            self.index += 1;
+        }
+        found
+    }
+
+    fn ident(&mut self, what: &str) -> Result<(String, Pos), PioAsmError> {
+        match self.peek() {
+            Some(Token { tok: Tok::Ident(name), pos }) => {
+                self.index += 1;
+                Ok((name.clone(), *pos))
+            }
+            _ => self.pos().error(format!("expected {what}")),
+        }
+    }
+
+    fn keyword(&mut self, what: &str) -> Result<(String, Pos), PioAsmError> {
+        let (name, pos) = self.ident(what)?;
+        Ok((name.to_ascii_lowercase(), pos))
+    }
+
+    fn expect_end(&self) -> Result<(), PioAsmError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(token) => token.pos.error("unexpected trailing input"),
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, PioAsmError> {
+        self.binary_expr(0)
+    }
+
+    fn binary_expr(&mut self, level: usize) -> Result<Expr, PioAsmError> {
+        const LEVELS: [&[(&str, BinaryOp)]; 6] = [
+            &[("|", BinaryOp::Or)],
+            &[("^", BinaryOp::Xor)],
+            &[("&", BinaryOp::And)],
+            &[("<<", BinaryOp::Shl), (">>", BinaryOp::Shr)],
+            &[("+", BinaryOp::Add), ("-", BinaryOp::Sub)],
+            &[("*", BinaryOp::Mul), ("/", BinaryOp::Div)],
+        ];
+        if level == LEVELS.len() {
+            return self.unary_expr();
+        }
+        let mut lhs = self.binary_expr(level + 1)?;
+        'outer: loop {
+            for (punct, op) in LEVELS[level] {
+                if self.is_punct(punct) {
+                    let pos = self.pos();
+                    self.index += 1;
+                    let rhs = self.binary_expr(level + 1)?;
+                    lhs = Expr::Binary(*op, Box::new(lhs), Box::new(rhs), pos);
+                    continue 'outer;
+                }
+            }
+            return Ok(lhs);
+        }
+    }
+
+    fn unary_expr(&mut self) -> Result<Expr, PioAsmError> {
+        let pos = self.pos();
+        match self.next().map(|t| &t.tok) {
+            Some(Tok::Int(value)) => Ok(Expr::Int(*value, pos)),
+            Some(Tok::Ident(name)) => Ok(Expr::Symbol(name.clone(), pos)),
+            Some(Tok::Punct("-")) => {
+                Ok(Expr::Unary(UnaryOp::Neg, Box::new(self.unary_expr()?), pos))
+            }
+            Some(Tok::Punct("~" | "!")) => {
+                Ok(Expr::Unary(UnaryOp::Not, Box::new(self.unary_expr()?), pos))
+            }
+            Some(Tok::Punct("::")) => {
+                Ok(Expr::Unary(UnaryOp::Reverse, Box::new(self.unary_expr()?), pos))
+            }
+            Some(Tok::Punct("(")) => {
+                let expr = self.expr()?;
+                self.expect_punct(")")?;
+                Ok(expr)
+            }
+            _ => pos.error("expected expression"),
+        }
+    }
+}
+
+fn is_keyword(token: Option<&Token>, keyword: &str) -> bool {
+    matches!(token, Some(Token { tok: Tok::Ident(name), .. }) if name.eq_ignore_ascii_case(keyword))
+}
+
+fn parse_jmp_condition(cursor: &mut Cursor) -> PioJmpCondition {
+    let not = cursor.is_punct("!") || cursor.is_punct("~");
+    let next = cursor.peek_at(1);
+    let (condition, len) = if not && is_keyword(next, "x") {
+        (PioJmpCondition::NotX, 2)
+    } else if not && is_keyword(next, "y") {
+        (PioJmpCondition::NotY, 2)
+    } else if not && is_keyword(next, "osre") {
+        (PioJmpCondition::NotOsre, 2)
+    } else if cursor.is_keyword("x") && matches!(next, Some(Token { tok: Tok::Punct("--"), .. })) {
+        (PioJmpCondition::XDec, 2)
+    } else if cursor.is_keyword("y") && matches!(next, Some(Token { tok: Tok::Punct("--"), .. })) {
+        (PioJmpCondition::YDec, 2)
+    } else if cursor.is_keyword("x")
+        && matches!(next, Some(Token { tok: Tok::Punct("!="), .. }))
+        && is_keyword(cursor.peek_at(2), "y")
+    {
+        (PioJmpCondition::XNeY, 3)
+    } else if cursor.is_keyword("pin") {
+        (PioJmpCondition::Pin, 1)
+    } else {
+        (PioJmpCondition::Always, 0)
+    };
+    cursor.index += len;
+    if len > 0 {
+        cursor.eat_punct(",");
+    }
+    condition
+}
+
+fn parse_operand<T: Copy>(
+    cursor: &mut Cursor,
+    what: &str,
+    names: &[(&str, T)],
+) -> Result<T, PioAsmError> {
+    let (name, pos) = cursor.keyword(what)?;
+    match names.iter().find(|(n, _)| *n == name) {
+        Some((_, value)) => Ok(*value),
+        None => pos.error(format!("invalid {what} '{name}'")),
+    }
+}
+
+fn parse_statement(
+    mnemonic: &str,
+    pos: Pos,
+    cursor: &mut Cursor,
+) -> Result<Statement, PioAsmError> {
+    Ok(match mnemonic {
+        "nop" => Statement::Nop,
+        "jmp" => {
+            let condition = parse_jmp_condition(cursor);
+            Statement::Jmp { condition, target: cursor.expr()? }
+        }
+        "wait" => {
+            let sources = [
+                ("gpio", PioWaitSource::Gpio),
+                ("pin", PioWaitSource::Pin),
+                ("irq", PioWaitSource::Irq),
+            ];
+            let polarity = if sources.iter().any(|(name, _)| cursor.is_keyword(name)) {
+                None
+            } else {
+                Some(cursor.expr()?)
+            };
+            let source = parse_operand(cursor, "wait source", &sources)?;
+            cursor.eat_punct(",");
+            let index = cursor.expr()?;
+            let relative = source == PioWaitSource::Irq && cursor.eat_keyword("rel");
+            Statement::Wait { polarity, source, index, relative }
+        }
+        "in" => {
+            let source = parse_operand(
+                cursor,
+                "in source",
+                &[
+                    ("pins", PioInSource::Pins),
+                    ("x", PioInSource::X),
+                    ("y", PioInSource::Y),
+                    ("null", PioInSource::Null),
+                    ("isr", PioInSource::Isr),
+                    ("osr", PioInSource::Osr),
+                ],
+            )?;
+            cursor.eat_punct(",");
+            Statement::In { source, bit_count: cursor.expr()? }
+        }
+        "out" => {
+            let destination = parse_operand(
+                cursor,
+                "out destination",
+                &[
+                    ("pins", PioOutDestination::Pins),
+                    ("x", PioOutDestination::X),
+                    ("y", PioOutDestination::Y),
+                    ("null", PioOutDestination::Null),
+                    ("pindirs", PioOutDestination::Pindirs),
+                    ("pc", PioOutDestination::Pc),
+                    ("isr", PioOutDestination::Isr),
+                    ("exec", PioOutDestination::Exec),
+                ],
+            )?;
+            cursor.eat_punct(",");
+            Statement::Out { destination, bit_count: cursor.expr()? }
+        }
+        "push" | "pull" => {
+            let conditional =
+                cursor.eat_keyword(if mnemonic == "push" { "iffull" } else { "ifempty" });
+            let block = if cursor.eat_keyword("noblock") {
+                false
+            } else {
+                cursor.eat_keyword("block");
+                true
+            };
+            if mnemonic == "push" {
+                Statement::Push { if_full: conditional, block }
+            } else {
+                Statement::Pull { if_empty: conditional, block }
+            }
+        }
+        "mov" => {
+            let destination = parse_operand(
+                cursor,
+                "mov destination",
+                &[
+                    ("pins", PioMovDestination::Pins),
+                    ("x", PioMovDestination::X),
+                    ("y", PioMovDestination::Y),
+                    ("exec", PioMovDestination::Exec),
+                    ("pc", PioMovDestination::Pc),
+                    ("isr", PioMovDestination::Isr),
+                    ("osr", PioMovDestination::Osr),
+                ],
+            )?;
+            cursor.eat_punct(",");
+            let op = if cursor.eat_punct("!") || cursor.eat_punct("~") {
+                PioMovOp::Invert
+            } else if cursor.eat_punct("::") {
+                PioMovOp::BitReverse
+            } else {
+                PioMovOp::None
+            };
+            let source = parse_operand(
+                cursor,
+                "mov source",
+                &[
+                    ("pins", PioMovSource::Pins),
+                    ("x", PioMovSource::X),
+                    ("y", PioMovSource::Y),
+                    ("null", PioMovSource::Null),
+                    ("status", PioMovSource::Status),
+                    ("isr", PioMovSource::Isr),
+                    ("osr", PioMovSource::Osr),
+                ],
+            )?;
+            Statement::Mov { destination, op, source }
+        }
+        "irq" => {
+            let mode = if cursor.eat_keyword("set") || cursor.eat_keyword("nowait") {
+                IrqMode::Set
+            } else if cursor.eat_keyword("wait") {
+                IrqMode::Wait
+            } else if cursor.eat_keyword("clear") {
+                IrqMode::Clear
+            } else {
+                IrqMode::Set
+            };
+            let index = cursor.expr()?;
+            let relative = cursor.eat_keyword("rel");
+            Statement::Irq { mode, index, relative }
+        }
+        "set" => {
+            let destination = parse_operand(
+                cursor,
+                "set destination",
+                &[
+                    ("pins", PioSetDestination::Pins),
+                    ("x", PioSetDestination::X),
+                    ("y", PioSetDestination::Y),
+                    ("pindirs", PioSetDestination::Pindirs),
+                ],
+            )?;
+            cursor.eat_punct(",");
+            Statement::Set { destination, data: cursor.expr()? }
+        }
+        _ => return pos.error(format!("unknown instruction '{mnemonic}'")),
+    })
+}
+
+fn reverse_bits(value: i64) -> i64 {
+    (value as u32).reverse_bits() as i64
+}
+
+impl Assembler {
+    fn run(mut self, source: &str) -> Result<Vec<PioAssembledProgram>, PioAsmError> {
+        let stripped = strip_comments(source);
+        for (n, text) in stripped.lines().enumerate() {
+            // `.lang_opt` arguments are free-form and only meaningful to language-specific output generators
+            if text.trim_start().get(..9).is_some_and(|d| d.eq_ignore_ascii_case(".lang_opt")) {
+                continue;
+            }
+            let tokens = tokenize_line(text, n + 1)?;
+            if tokens.is_empty() {
+                continue;
+            }
+            let mut cursor = Cursor {
+                tokens: &tokens,
+                index: 0,
+                end: Pos { line: n + 1, column: text.trim_end().chars().count() + 1 },
+            };
+            self.line(&mut cursor)?;
+        }
+        self.finish_program()?;
+        if self.programs.is_empty() {
+            return Pos { line: 1, column: 1 }.error("no .program found");
+        }
+        Ok(self.programs)
+    }
+
+    fn current_program(&mut self, pos: Pos, what: &str) -> Result<&mut ProgramState, PioAsmError> {
+        if self.program.is_none() && self.implicit_program && self.programs.is_empty() {
+            self.program =
+                Some(ProgramState { name: "program".to_string(), pos, ..Default::default() });
+        }
+        match self.program.as_mut() {
+            Some(program) => Ok(program),
+            None => pos.error(format!("{what} must be inside a .program")),
+        }
+    }
+
+    fn define_symbol(
+        &mut self,
+        name: String,
+        pos: Pos,
+        define: Option<Define>,
+        public: bool,
+    ) -> Result<(), PioAsmError> {
+        if define.is_none() {
+            self.current_program(pos, "labels")?;
+        }
+        let in_program = self.program.is_some();
+        let exists = match self.program.as_ref() {
+            Some(program) => {
+                program.labels.contains_key(&name) || program.defines.contains_key(&name)
+            }
+            None => self.global_defines.contains_key(&name),
+        };
+        if exists {
+            return pos.error(format!("symbol '{name}' is already defined"));
+        }
+        match (self.program.as_mut(), define) {
+            (Some(program), Some(define)) => {
+                program.defines.insert(name.clone(), define);
+            }
+            (Some(program), None) => {
+                let index = program.instructions.len() as i64;
+                program.labels.insert(name.clone(), (index, pos));
+            }
+            (None, Some(define)) => {
+                self.global_defines.insert(name.clone(), define);
+            }
+            (None, None) => unreachable!("labels always create or require a program"),
+        }
+        if public {
+            if in_program {
+                self.program.as_mut().unwrap().public_symbols.push(name);
+            } else {
+                self.global_public.push(name);
+            }
+        }
+        Ok(())
+    }
+
+    fn line(&mut self, cursor: &mut Cursor) -> Result<(), PioAsmError> {
+        // labels, optionally public, optionally followed by an instruction
+        let public = cursor.is_keyword("public")
+            && matches!(cursor.peek_at(1), Some(Token { tok: Tok::Ident(_), .. }));
+        let label_at = public as usize;
+        if matches!(cursor.peek_at(label_at + 1), Some(Token { tok: Tok::Punct(":"), .. })) {
+            cursor.index += label_at;
+            let (name, pos) = cursor.ident("label")?;
+            cursor.expect_punct(":")?;
+            self.define_symbol(name, pos, None, public)?;
+            if cursor.at_end() {
+                return Ok(());
+            }
+        }
+        let (word, pos) = cursor.keyword("instruction or directive")?;
+        if word.starts_with('.') {
+            return self.directive(&word, pos, cursor);
+        }
+        let statement = parse_statement(&word, pos, cursor)?;
+        let mut side_set = None;
+        let mut delay = None;
+        while !cursor.at_end() {
+            let at = cursor.pos();
+            if cursor.eat_keyword("side")
+                || cursor.eat_keyword("sideset")
+                || cursor.eat_keyword("side_set")
+            {
+                if side_set.is_some() {
+                    return at.error("side set specified more than once");
+                }
+                side_set = Some((cursor.expr()?, at));
+            } else if cursor.eat_punct("[") {
+                if delay.is_some() {
+                    return at.error("delay specified more than once");
+                }
+                delay = Some((cursor.expr()?, at));
+                cursor.expect_punct("]")?;
+            } else {
+                return at.error("unexpected trailing input");
+            }
+        }
+        let program = self.current_program(pos, "instructions")?;
+        if program.instructions.len() == PIO_ASM_MAX_INSTRUCTIONS {
+            return pos.error(format!("program exceeds {PIO_ASM_MAX_INSTRUCTIONS} instructions"));
+        }
+        program.instructions.push(PendingInstruction { pos, statement, side_set, delay });
+        Ok(())
+    }
+
+    fn directive(
+        &mut self,
+        directive: &str,
+        pos: Pos,
+        cursor: &mut Cursor,
+    ) -> Result<(), PioAsmError> {
+        match directive {
+            ".program" => {
+                let (name, _) = cursor.ident("program name")?;
+                cursor.expect_end()?;
+                self.finish_program()?;
+                if self.programs.iter().any(|p| p.name == name) {
+                    return pos.error(format!("program '{name}' is already defined"));
+                }
+                self.program = Some(ProgramState { name, pos, ..Default::default() });
+            }
+            ".define" => {
+                let public = cursor.eat_keyword("public");
+                let (name, name_pos) = cursor.ident("symbol name")?;
+                let expr = cursor.expr()?;
+                cursor.expect_end()?;
+                self.define_symbol(name, name_pos, Some(Define { expr, pos }), public)?;
+            }
+            ".origin" => {
+                let expr = cursor.expr()?;
+                cursor.expect_end()?;
+                let program = self.current_program(pos, ".origin")?;
+                if !program.instructions.is_empty() {
+                    return pos.error(".origin must appear before the first instruction");
+                }
+                program.origin = Some((expr, pos));
+            }
+            ".side_set" => {
+                let expr = cursor.expr()?;
+                let optional = cursor.eat_keyword("opt");
+                let pindirs = cursor.eat_keyword("pindirs");
+                cursor.expect_end()?;
+                let program = self.current_program(pos, ".side_set")?;
+                if !program.instructions.is_empty() {
+                    return pos.error(".side_set must appear before the first instruction");
+                }
+                if program.side_set.is_some() {
+                    return pos.error(".side_set specified more than once");
+                }
+                program.side_set = Some((expr, pos, optional, pindirs));
+            }
+            ".pio_version" => {
+                let expr = cursor.expr()?;
+                cursor.expect_end()?;
+                self.current_program(pos, ".pio_version")?.pio_version = Some((expr, pos));
+            }
+            ".wrap_target" => {
+                cursor.expect_end()?;
+                let program = self.current_program(pos, ".wrap_target")?;
+                if program.wrap_target.is_some() {
+                    return pos.error(".wrap_target specified more than once");
+                }
+                program.wrap_target = Some(program.instructions.len());
+            }
+            ".wrap" => {
+                cursor.expect_end()?;
+                let program = self.current_program(pos, ".wrap")?;
+                if program.wrap.is_some() {
+                    return pos.error(".wrap specified more than once");
+                }
+                if program.instructions.is_empty() {
+                    return pos.error(".wrap must follow an instruction");
+                }
+                program.wrap = Some(program.instructions.len() - 1);
+            }
+            ".word" => {
+                let expr = cursor.expr()?;
+                cursor.expect_end()?;
+                let program = self.current_program(pos, ".word")?;
+                if program.instructions.len() == PIO_ASM_MAX_INSTRUCTIONS {
+                    return pos
+                        .error(format!("program exceeds {PIO_ASM_MAX_INSTRUCTIONS} instructions"));
+                }
+                program.instructions.push(PendingInstruction {
+                    pos,
+                    statement: Statement::Word(expr),
+                    side_set: None,
+                    delay: None,
+                });
+            }
+            _ => return pos.error(format!("unknown directive '{directive}'")),
+        }
+        Ok(())
+    }
+
+    fn lookup(
+        &self,
+        program: &ProgramState,
+        name: &str,
+        pos: Pos,
+        depth: usize,
+    ) -> Result<i64, PioAsmError> {
+        if depth > 64 {
+            return pos.error(format!("symbol '{name}' is defined recursively"));
+        }
+        if let Some((value, _)) = program.labels.get(name) {
+            return Ok(*value);
+        }
+        match program.defines.get(name).or_else(|| self.global_defines.get(name)) {
+            Some(define) => self.eval(program, &define.expr, depth + 1),
+            None => pos.error(format!("undefined symbol '{name}'")),
+        }
+    }
+
+    fn eval(&self, program: &ProgramState, expr: &Expr, depth: usize) -> Result<i64, PioAsmError> {
+        Ok(match expr {
+            Expr::Int(value, _) => *value,
+            Expr::Symbol(name, pos) => self.lookup(program, name, *pos, depth)?,
+            Expr::Unary(op, inner, _) => {
+                let value = self.eval(program, inner, depth)?;
+                match op {
+                    UnaryOp::Neg => value.wrapping_neg(),
+                    UnaryOp::Not => !value,
+                    UnaryOp::Reverse => reverse_bits(value),
+                }
+            }
+            Expr::Binary(op, lhs, rhs, pos) => {
+                let lhs = self.eval(program, lhs, depth)?;
+                let rhs = self.eval(program, rhs, depth)?;
+                match op {
+                    BinaryOp::Add => lhs.wrapping_add(rhs),
+                    BinaryOp::Sub => lhs.wrapping_sub(rhs),
+                    BinaryOp::Mul => lhs.wrapping_mul(rhs),
+                    BinaryOp::Div if rhs == 0 => return pos.error("division by zero"),
+                    BinaryOp::Div => lhs / rhs,
+                    BinaryOp::Or => lhs | rhs,
+                    BinaryOp::And => lhs & rhs,
+                    BinaryOp::Xor => lhs ^ rhs,
+                    BinaryOp::Shl => lhs.wrapping_shl(rhs as u32),
+                    BinaryOp::Shr => lhs.wrapping_shr(rhs as u32),
+                }
+            }
+        })
+    }
+
+    fn eval_in_range(
+        &self,
+        program: &ProgramState,
+        expr: &Expr,
+        what: &str,
+        range: std::ops::RangeInclusive<i64>,
+    ) -> Result<u8, PioAsmError> {
+        let value = self.eval(program, expr, 0)?;
+        if !range.contains(&value) {
+            return expr.pos().error(format!(
+                "{what} {value} is out of range {}-{}",
+                range.start(),
+                range.end()
+            ));
+        }
+        Ok(value as u8)
+    }
+
+    fn encode(
+        &self,
+        program: &ProgramState,
+        pending: &PendingInstruction,
+        side_set: &PioSideSet,
+    ) -> Result<u16, PioAsmError> {
+        let pos = pending.pos;
+        let instruction = match &pending.statement {
+            Statement::Word(expr) => {
+                let value = self.eval(program, expr, 0)?;
+                if !(0..=0xffff).contains(&value) {
+                    return expr
+                        .pos()
+                        .error(format!(".word value {value} is out of range 0-65535"));
+                }
+                return Ok(value as u16);
+            }
+            Statement::Nop => PioInstruction::Mov {
+                destination: PioMovDestination::Y,
+                op: PioMovOp::None,
+                source: PioMovSource::Y,
+            },
+            Statement::Jmp { condition, target } => PioInstruction::Jmp {
+                condition: *condition,
+                address: self.eval_in_range(program, target, "jmp target", 0..=31)?,
+            },
+            Statement::Wait { polarity, source, index, relative } => {
+                let polarity = match polarity {
+                    Some(expr) => self.eval_in_range(program, expr, "wait polarity", 0..=1)? != 0,
+                    None => true,
+                };
+                let index = match source {
+                    PioWaitSource::Irq => {
+                        self.eval_in_range(program, index, "irq number", 0..=7)?
+                            | if *relative { 0x10 } else { 0 }
+                    }
+                    _ => self.eval_in_range(program, index, "pin number", 0..=31)?,
+                };
+                PioInstruction::Wait { polarity, source: *source, index }
+            }
+            Statement::In { source, bit_count } => PioInstruction::In {
+                source: *source,
+                bit_count: self.eval_in_range(program, bit_count, "bit count", 1..=32)?,
+            },
+            Statement::Out { destination, bit_count } => PioInstruction::Out {
+                destination: *destination,
+                bit_count: self.eval_in_range(program, bit_count, "bit count", 1..=32)?,
+            },
+            Statement::Push { if_full, block } => {
+                PioInstruction::Push { if_full: *if_full, block: *block }
+            }
+            Statement::Pull { if_empty, block } => {
+                PioInstruction::Pull { if_empty: *if_empty, block: *block }
+            }
+            Statement::Mov { destination, op, source } => {
+                PioInstruction::Mov { destination: *destination, op: *op, source: *source }
+            }
+            Statement::Irq { mode, index, relative } => PioInstruction::Irq {
+                clear: matches!(mode, IrqMode::Clear),
+                wait: matches!(mode, IrqMode::Wait),
+                index: self.eval_in_range(program, index, "irq number", 0..=7)?
+                    | if *relative { 0x10 } else { 0 },
+            },
+            Statement::Set { destination, data } => PioInstruction::Set {
+                destination: *destination,
+                data: self.eval_in_range(program, data, "set value", 0..=31)?,
+            },
+        };
+        let side_set_value = match &pending.side_set {
+            Some((_, at)) if side_set.bit_count == 0 => {
+                return at.error("side set used but no .side_set is specified");
+            }
+            Some((expr, _)) => Some(self.eval_in_range(
+                program,
+                expr,
+                "side set value",
+                0..=(1 << side_set.bit_count) - 1,
+            )?),
+            None if side_set.bit_count > 0 && !side_set.optional => {
+                return pos.error("instruction requires a side set value");
+            }
+            None => None,
+        };
+        let delay = match &pending.delay {
+            Some((expr, _)) => {
+                self.eval_in_range(program, expr, "delay", 0..=side_set.max_delay() as i64)?
+            }
+            None => 0,
+        };
+        Ok(PioInstructionWord::with_delay_side_set(instruction, side_set, delay, side_set_value)
+            .encode())
+    }
+
+    fn finish_program(&mut self) -> Result<(), PioAsmError> {
+        let Some(program) = self.program.take() else {
+            return Ok(());
+        };
+        let program_pos = program.pos;
+        if program.instructions.is_empty() {
+            return program_pos.error(format!("program '{}' has no instructions", program.name));
+        }
+        if program.wrap_target == Some(program.instructions.len()) {
+            return program_pos.error(".wrap_target must be followed by an instruction");
+        }
+        let side_set = match &program.side_set {
+            Some((expr, _, optional, pindirs)) => {
+                let max = if *optional { 4 } else { 5 };
+                PioSideSet {
+                    bit_count: self.eval_in_range(&program, expr, "side set count", 0..=max)?,
+                    optional: *optional,
+                    pindirs: *pindirs,
+                }
+            }
+            None => PioSideSet::default(),
+        };
+        let origin = match &program.origin {
+            Some((expr, _)) => Some(self.eval_in_range(&program, expr, "origin", 0..=31)?),
+            None => None,
+        };
+        if let Some(origin) = origin {
+            if origin as usize + program.instructions.len() > PIO_ASM_MAX_INSTRUCTIONS {
+                return program_pos
+                    .error(format!("program '{}' does not fit at origin {origin}", program.name));
+            }
+        }
+        let pio_version = match &program.pio_version {
+            Some((expr, _)) => self.eval_in_range(&program, expr, "PIO version", 0..=1)?,
+            None => 0,
+        };
+        let instructions = program
+            .instructions
+            .iter()
+            .map(|pending| self.encode(&program, pending, &side_set))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut public_symbols = Vec::new();
+        let mut public_labels = Vec::new();
+        for name in self.global_public.iter().chain(&program.public_symbols) {
+            if let Some((index, _)) = program.labels.get(name) {
+                public_labels.push((name.clone(), *index as u8));
+            }
+            let pos = program
+                .labels
+                .get(name)
+                .map(|(_, pos)| *pos)
+                .or_else(|| program.defines.get(name).map(|d| d.pos))
+                .or_else(|| self.global_defines.get(name).map(|d| d.pos))
+                .unwrap_or(program_pos);
+            let value = self.lookup(&program, name, pos, 0)?;
+            let Ok(value) = i32::try_from(value) else {
+                return pos.error(format!(
+                    "public symbol '{name}' value {value} does not fit in 32 bits"
+                ));
+            };
+            public_symbols.push((name.clone(), value));
+        }
+        self.programs.push(PioAssembledProgram {
+            name: program.name,
+            wrap_target: program.wrap_target.unwrap_or(0) as u8,
+            wrap: program.wrap.unwrap_or(instructions.len() - 1) as u8,
+            instructions,
+            origin,
+            side_set,
+            pio_version,
+            public_symbols,
+            public_labels,
+        });
+        Ok(())
+    }
+}
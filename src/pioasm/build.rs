@@ -0,0 +1,276 @@
+//! Build script support: turn a directory of `.pio` files into Rust modules, the way pioasm
+//! turns them into C headers
+//!
+//! ```ignore
+//! // build.rs
+//! fn main() {
+//!     piolib_rs::pioasm::build::build_pio_dir("src/pio");
+//! }
+//!
+//! // src/main.rs
+//! include!(concat!(env!("OUT_DIR"), "/pio_programs.rs"));
+//! let c = ws2812::ws2812_program_get_default_config(offset);
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::assembler::{assemble, PioAsmError, PioAssembledProgram};
+use super::disassembler::disassemble_instruction;
+
+/// File written into `OUT_DIR` by [`build_pio_dir`]
+pub const PIO_BUILD_OUTPUT: &str = "pio_programs.rs";
+
+/// Error from compiling a `.pio` file
+#[derive(Debug)]
+pub enum PioBuildError {
+    Io(PathBuf, io::Error),
+    Asm(PathBuf, PioAsmError),
+    /// the file's module name is already taken by the second file
+    ModuleClash(PathBuf, PathBuf, String),
+    /// the file's stem gives a module name Rust cannot use
+    ModuleName(PathBuf, String),
+}
+
+impl fmt::Display for PioBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PioBuildError::Io(path, err) => write!(f, "{}: {err}", path.display()),
+            PioBuildError::Asm(path, err) => write!(f, "{}:{err}", path.display()),
+            PioBuildError::ModuleClash(path, other, module) => write!(
+                f,
+                "{}: module `{module}` is already generated from {}",
+                path.display(),
+                other.display()
+            ),
+            PioBuildError::ModuleName(path, module) => {
+                write!(f, "{}: `{module}` cannot be a module name", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for PioBuildError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PioBuildError::Io(_, err) => Some(err),
+            PioBuildError::Asm(_, err) => Some(err),
+            PioBuildError::ModuleClash(..) | PioBuildError::ModuleName(..) => None,
+        }
+    }
+}
+
+/// Compile every `.pio` file in `pio_dir` into `$OUT_DIR/pio_programs.rs` from a build script
+///
+/// Emits `cargo:rerun-if-changed` for the directory and each file, and panics on error so the
+/// message shows up in the build output.
+pub fn build_pio_dir(pio_dir: impl AsRef<Path>) {
+    let pio_dir = pio_dir.as_ref();
+    let out_dir = std::env::var_os("OUT_DIR").expect("OUT_DIR is not set; call from build.rs");
+    println!("cargo:rerun-if-changed={}", pio_dir.display());
+    match compile_pio_dir(pio_dir, Path::new(&out_dir).join(PIO_BUILD_OUTPUT)) {
+        Ok(files) => {
+            for file in files {
+                println!("cargo:rerun-if-changed={}", file.display());
+            }
+        }
+        Err(err) => panic!("{err}"),
+    }
+}
+
+/// Compile every `.pio` file in `pio_dir` into a single Rust file with one module per `.pio` file
+///
+/// Returns the compiled `.pio` files in the order they were emitted (sorted by name).
+pub fn compile_pio_dir(
+    pio_dir: impl AsRef<Path>,
+    out_file: impl AsRef<Path>,
+) -> Result<Vec<PathBuf>, PioBuildError> {
+    let pio_dir = pio_dir.as_ref();
+    let out_file = out_file.as_ref();
+    let entries = fs::read_dir(pio_dir).map_err(|err| PioBuildError::Io(pio_dir.into(), err))?;
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| PioBuildError::Io(pio_dir.into(), err))?
+            .path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "pio") {
+            files.push(path);
+        }
+    }
+    files.sort();
+
+    let mut output = String::from("// Generated by piolib-rs from .pio sources; do not edit\n");
+    let mut modules: Vec<(String, &PathBuf)> = Vec::new();
+    for file in &files {
+        let stem = file.file_stem().unwrap_or_default().to_string_lossy();
+        let module = module_name(&stem)
+            .ok_or_else(|| PioBuildError::ModuleName(file.clone(), stem.to_string()))?;
+        if let Some((_, other)) = modules.iter().find(|(taken, _)| *taken == module) {
+            return Err(PioBuildError::ModuleClash(
+                file.clone(),
+                (*other).clone(),
+                module,
+            ));
+        }
+        let source =
+            fs::read_to_string(file).map_err(|err| PioBuildError::Io(file.clone(), err))?;
+        let programs = assemble(&source).map_err(|err| PioBuildError::Asm(file.clone(), err))?;
+        output.push('\n');
+        output.push_str(&generate_module(&module, &programs));
+        modules.push((module, file));
+    }
+    fs::write(out_file, output).map_err(|err| PioBuildError::Io(out_file.into(), err))?;
+    Ok(files)
+}
+
+/// Generate the Rust module for the programs of one `.pio` file
+pub fn generate_module(module: &str, programs: &[PioAssembledProgram]) -> String {
+    let mut out = String::new();
+    writeln!(out, "pub mod {module} {{").unwrap();
+    writeln!(out, "    #![allow(dead_code, non_upper_case_globals)]").unwrap();
+    // import only what is used, so the module builds cleanly under -D warnings
+    let sideset = if programs
+        .iter()
+        .any(|program| program.side_set.bit_count > 0)
+    {
+        " sm_config_set_sideset,"
+    } else {
+        ""
+    };
+    writeln!(
+        out,
+        "    use ::piolib_rs::include::piolib::{{pio_get_default_sm_config, pio_program, pio_sm_config,{sideset} sm_config_set_wrap}};"
+    )
+    .unwrap();
+    for program in programs {
+        out.push('\n');
+        generate_program(&mut out, program);
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn generate_program(out: &mut String, program: &PioAssembledProgram) {
+    let name = &program.name;
+    let rule = "-".repeat(name.len());
+    writeln!(
+        out,
+        "    // {rule} //\n    // {name} //\n    // {rule} //\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    pub const {name}_wrap_target: u32 = {};",
+        program.wrap_target
+    )
+    .unwrap();
+    writeln!(out, "    pub const {name}_wrap: u32 = {};", program.wrap).unwrap();
+    writeln!(
+        out,
+        "    pub const {name}_pio_version: u32 = {};",
+        program.pio_version
+    )
+    .unwrap();
+    if !program.public_symbols.is_empty() {
+        out.push('\n');
+    }
+    let is_label = |symbol: &String| {
+        program
+            .public_labels
+            .iter()
+            .any(|(label, _)| label == symbol)
+    };
+    for (symbol, value) in program
+        .public_symbols
+        .iter()
+        .filter(|(symbol, _)| !is_label(symbol))
+    {
+        writeln!(out, "    pub const {name}_{symbol}: i32 = {value};").unwrap();
+    }
+    for (label, offset) in &program.public_labels {
+        writeln!(out, "    pub const {name}_offset_{label}: u32 = {offset};").unwrap();
+    }
+
+    let count = program.instructions.len();
+    writeln!(
+        out,
+        "\n    pub const {name}_program_instructions: [u16; {count}] = ["
+    )
+    .unwrap();
+    for (index, &instr) in program.instructions.iter().enumerate() {
+        if index == program.wrap_target as usize {
+            writeln!(out, "        //     .wrap_target").unwrap();
+        }
+        let text = disassemble_instruction(instr, &program.side_set);
+        writeln!(out, "        0x{instr:04x}, // {index:2}: {text}").unwrap();
+        if index == program.wrap as usize {
+            writeln!(out, "        //     .wrap").unwrap();
+        }
+    }
+    writeln!(out, "    ];\n").unwrap();
+
+    let origin = program.origin.map_or(-1, |origin| origin as i8);
+    writeln!(
+        out,
+        "    pub const {name}_program: pio_program<'static> = pio_program::from_parts(&{name}_program_instructions, {origin}, {});\n",
+        program.pio_version
+    )
+    .unwrap();
+
+    writeln!(
+        out,
+        "    pub fn {name}_program_get_default_config(offset: u32) -> pio_sm_config {{"
+    )
+    .unwrap();
+    writeln!(out, "        let mut c = pio_get_default_sm_config();").unwrap();
+    writeln!(
+        out,
+        "        sm_config_set_wrap(&mut c, offset + {name}_wrap_target, offset + {name}_wrap);"
+    )
+    .unwrap();
+    let side_set = &program.side_set;
+    if side_set.bit_count > 0 {
+        writeln!(
+            out,
+            "        sm_config_set_sideset(&mut c, {}, {}, {});",
+            side_set.total_bit_count(),
+            side_set.optional,
+            side_set.pindirs
+        )
+        .unwrap();
+    }
+    writeln!(out, "        c\n    }}").unwrap();
+}
+
+/// Keywords that can be used as identifiers with an `r#` prefix
+const RAW_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Turn a file stem into a module identifier, escaping keywords; `None` for the path keywords
+/// and `_`, which cannot name a module at all
+fn module_name(stem: &str) -> Option<String> {
+    let mut name: String = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    if RAW_KEYWORDS.contains(&name.as_str()) {
+        name.insert_str(0, "r#");
+    }
+    (!matches!(name.as_str(), "_" | "crate" | "self" | "super")).then_some(name)
+}
@@ -0,0 +1,221 @@
+//! Disassembly of PIO instruction words into pioasm syntax
+
+use std::fmt;
+use std::fmt::Write;
+
+use crate::include::hardware::pio_instructions::*;
+
+impl fmt::Display for PioJmpCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioJmpCondition::Always => "",
+            PioJmpCondition::NotX => "!x",
+            PioJmpCondition::XDec => "x--",
+            PioJmpCondition::NotY => "!y",
+            PioJmpCondition::YDec => "y--",
+            PioJmpCondition::XNeY => "x!=y",
+            PioJmpCondition::Pin => "pin",
+            PioJmpCondition::NotOsre => "!osre",
+        })
+    }
+}
+
+impl fmt::Display for PioWaitSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioWaitSource::Gpio => "gpio",
+            PioWaitSource::Pin => "pin",
+            PioWaitSource::Irq => "irq",
+        })
+    }
+}
+
+impl fmt::Display for PioInSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioInSource::Pins => "pins",
+            PioInSource::X => "x",
+            PioInSource::Y => "y",
+            PioInSource::Null => "null",
+            PioInSource::Isr => "isr",
+            PioInSource::Osr => "osr",
+        })
+    }
+}
+
+impl fmt::Display for PioOutDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioOutDestination::Pins => "pins",
+            PioOutDestination::X => "x",
+            PioOutDestination::Y => "y",
+            PioOutDestination::Null => "null",
+            PioOutDestination::Pindirs => "pindirs",
+            PioOutDestination::Pc => "pc",
+            PioOutDestination::Isr => "isr",
+            PioOutDestination::Exec => "exec",
+        })
+    }
+}
+
+impl fmt::Display for PioMovDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioMovDestination::Pins => "pins",
+            PioMovDestination::X => "x",
+            PioMovDestination::Y => "y",
+            PioMovDestination::Exec => "exec",
+            PioMovDestination::Pc => "pc",
+            PioMovDestination::Isr => "isr",
+            PioMovDestination::Osr => "osr",
+        })
+    }
+}
+
+impl fmt::Display for PioMovOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioMovOp::None => "",
+            PioMovOp::Invert => "!",
+            PioMovOp::BitReverse => "::",
+        })
+    }
+}
+
+impl fmt::Display for PioMovSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioMovSource::Pins => "pins",
+            PioMovSource::X => "x",
+            PioMovSource::Y => "y",
+            PioMovSource::Null => "null",
+            PioMovSource::Status => "status",
+            PioMovSource::Isr => "isr",
+            PioMovSource::Osr => "osr",
+        })
+    }
+}
+
+impl fmt::Display for PioSetDestination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PioSetDestination::Pins => "pins",
+            PioSetDestination::X => "x",
+            PioSetDestination::Y => "y",
+            PioSetDestination::Pindirs => "pindirs",
+        })
+    }
+}
+
+fn fmt_irq_index(f: &mut fmt::Formatter<'_>, index: u8) -> fmt::Result {
+    write!(f, "{}", index & 0x7)?;
+    if index & 0x10 != 0 {
+        f.write_str(" rel")?;
+    }
+    Ok(())
+}
+
+/// Formats the instruction in pioasm syntax, without side set or delay
+impl fmt::Display for PioInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PioInstruction::Jmp { condition: PioJmpCondition::Always, address } => {
+                write!(f, "jmp {address}")
+            }
+            PioInstruction::Jmp { condition, address } => write!(f, "jmp {condition} {address}"),
+            PioInstruction::Wait { polarity, source: PioWaitSource::Irq, index } => {
+                write!(f, "wait {} irq ", polarity as u8)?;
+                fmt_irq_index(f, index)
+            }
+            PioInstruction::Wait { polarity, source, index } => {
+                write!(f, "wait {} {source} {index}", polarity as u8)
+            }
+            PioInstruction::In { source, bit_count } => write!(f, "in {source}, {bit_count}"),
+            PioInstruction::Out { destination, bit_count } => {
+                write!(f, "out {destination}, {bit_count}")
+            }
+            PioInstruction::Push { if_full, block } => write!(
+                f,
+                "push {}{}",
+                if if_full { "iffull " } else { "" },
+                if block { "block" } else { "noblock" }
+            ),
+            PioInstruction::Pull { if_empty, block } => write!(
+                f,
+                "pull {}{}",
+                if if_empty { "ifempty " } else { "" },
+                if block { "block" } else { "noblock" }
+            ),
+            PioInstruction::Mov {
+                destination: PioMovDestination::Y,
+                op: PioMovOp::None,
+                source: PioMovSource::Y,
+            } => f.write_str("nop"),
+            PioInstruction::Mov { destination, op, source } => {
+                write!(f, "mov {destination}, {op}{source}")
+            }
+            PioInstruction::Irq { clear, wait, index } => {
+                f.write_str(if clear {
+                    "irq clear "
+                } else if wait {
+                    "irq wait "
+                } else {
+                    "irq nowait "
+                })?;
+                fmt_irq_index(f, index)
+            }
+            PioInstruction::Set { destination, data } => write!(f, "set {destination}, {data}"),
+        }
+    }
+}
+
+/// Whether `instruction` can be written in pioasm syntax such that it assembles back to the same bits
+fn is_representable(instruction: &PioInstruction) -> bool {
+    match *instruction {
+        PioInstruction::Wait { source: PioWaitSource::Irq, index, .. } => index & 0x08 == 0,
+        PioInstruction::Irq { clear, wait, index } => index & 0x08 == 0 && !(clear && wait),
+        _ => true,
+    }
+}
+
+/// Disassemble a single instruction word, given the side-set configuration of its program
+///
+/// Words that cannot be expressed as an instruction (reserved encodings) are emitted as `.word 0x....`.
+pub fn disassemble_instruction(instr: u16, side_set: &PioSideSet) -> String {
+    crate::valid_params_if!(PIO_INSTRUCTIONS, side_set.total_bit_count() <= 5);
+    let word = match PioInstructionWord::decode(instr) {
+        Some(word) if is_representable(&word.instruction) => word,
+        _ => return format!(".word {instr:#06x}"),
+    };
+    let mut text = word.instruction.to_string();
+    if let Some(value) = word.side_set(side_set) {
+        write!(text, " side {value}").unwrap();
+    }
+    let delay = word.delay(side_set);
+    if delay != 0 {
+        write!(text, " [{delay}]").unwrap();
+    }
+    text
+}
+
+/// Disassemble a whole program into pioasm source, one instruction per line
+///
+/// A `.side_set` directive is emitted first when the program uses side set, so that the output can
+/// be fed back into pioasm.
+pub fn disassemble(program: &[u16], side_set: &PioSideSet) -> String {
+    let mut text = String::new();
+    if side_set.bit_count > 0 {
+        write!(text, ".side_set {}", side_set.bit_count).unwrap();
+        if side_set.optional {
+            text.push_str(" opt");
+        }
+        if side_set.pindirs {
+            text.push_str(" pindirs");
+        }
+        text.push('\n');
+    }
+    for &instr in program {
+        writeln!(text, "    {}", disassemble_instruction(instr, side_set)).unwrap();
+    }
+    text
+}
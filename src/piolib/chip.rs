@@ -0,0 +1,154 @@
+use crate::include::hardware::clocks::clock_index;
+use crate::include::hardware::gpio::{gpio_drive_strength, gpio_function, gpio_override};
+use crate::include::piolib::{
+    pio_calculate_clkdiv_from_float, pio_get_default_sm_config, pio_program, pio_sm_config,
+    pio_xfer_dir,
+};
+
+use super::error::{PioError, PioResult};
+
+/// Fixed properties of a PIO chip, the data half of `struct pio_chip`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PioChipInfo {
+    pub name: &'static str,
+    pub compatible: &'static str,
+    pub instr_count: u16,
+    pub sm_count: u16,
+    pub fifo_depth: u16,
+}
+
+/// One opened PIO instance, the function half of `struct pio_chip`
+///
+/// Opening is the backend's constructor (`create_instance` + `open_instance`) and `close` is
+/// `close_instance`. The `pio_encode_*` entries are not part of the trait because every supported
+/// chip uses the encoding in `pio_instructions`, and the `smc_set_*` entries are the free
+/// `sm_config_set_*` functions since every backend shares the RP1 config layout.
+pub trait PioChip: Send {
+    fn info(&self) -> &PioChipInfo;
+
+    fn close(&mut self) -> PioResult<()> {
+        Ok(())
+    }
+
+    fn sm_config_xfer(
+        &mut self,
+        sm: u32,
+        dir: pio_xfer_dir,
+        buf_size: u32,
+        buf_count: u32,
+    ) -> PioResult<()>;
+    fn sm_xfer_data(&mut self, sm: u32, dir: pio_xfer_dir, data: &mut [u8]) -> PioResult<()>;
+
+    /// Whether `program` fits at `offset`, or anywhere for `None`
+    fn can_add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<bool>;
+    /// Load `program` at `offset`, or wherever it fits for `None`, returning the offset used
+    fn add_program_at_offset(&mut self, program: &pio_program, offset: Option<u8>)
+        -> PioResult<u8>;
+    fn remove_program(&mut self, program: &pio_program, loaded_offset: u8) -> PioResult<()>;
+    fn clear_instruction_memory(&mut self) -> PioResult<()>;
+
+    fn sm_claim(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.sm_claim_mask(1 << sm)
+    }
+    fn sm_claim_mask(&mut self, mask: u32) -> PioResult<()>;
+    /// Claim the lowest numbered free state machine
+    fn sm_claim_unused(&mut self) -> PioResult<u32> {
+        for sm in 0..self.info().sm_count as u32 {
+            if !self.sm_is_claimed(sm)? {
+                match self.sm_claim(sm) {
+                    Err(PioError::SmAlreadyClaimed) => continue,
+                    result => return result.map(|()| sm),
+                }
+            }
+        }
+        Err(PioError::SmAlreadyClaimed)
+    }
+    fn sm_unclaim(&mut self, sm: u32) -> PioResult<()>;
+    fn sm_is_claimed(&mut self, sm: u32) -> PioResult<bool>;
+
+    fn sm_init(&mut self, sm: u32, initial_pc: u32, config: &pio_sm_config) -> PioResult<()>;
+    fn sm_set_config(&mut self, sm: u32, config: &pio_sm_config) -> PioResult<()>;
+    fn sm_exec(&mut self, sm: u32, instr: u16, blocking: bool) -> PioResult<()>;
+    fn sm_clear_fifos(&mut self, sm: u32) -> PioResult<()>;
+    fn sm_set_clkdiv_int_frac(&mut self, sm: u32, div_int: u16, div_frac: u8) -> PioResult<()>;
+    fn sm_set_clkdiv(&mut self, sm: u32, div: f32) -> PioResult<()> {
+        let (div_int, div_frac) = pio_calculate_clkdiv_from_float(div);
+        self.sm_set_clkdiv_int_frac(sm, div_int, div_frac)
+    }
+    fn sm_set_pins(&mut self, sm: u32, pin_values: u32) -> PioResult<()> {
+        self.sm_set_pins_with_mask(sm, pin_values, !0)
+    }
+    fn sm_set_pins_with_mask(&mut self, sm: u32, pin_values: u32, pin_mask: u32) -> PioResult<()>;
+    fn sm_set_pindirs_with_mask(&mut self, sm: u32, pin_dirs: u32, pin_mask: u32) -> PioResult<()>;
+    fn sm_set_consecutive_pindirs(
+        &mut self,
+        sm: u32,
+        pin_base: u32,
+        pin_count: u32,
+        is_out: bool,
+    ) -> PioResult<()> {
+        if pin_base >= 32 {
+            return Err(PioError::InvalidParam { which: "pin_base" });
+        }
+        if pin_count > 32 {
+            return Err(PioError::InvalidParam { which: "pin_count" });
+        }
+        let mask = (((1u64 << pin_count) - 1) as u32).rotate_left(pin_base);
+        self.sm_set_pindirs_with_mask(sm, if is_out { mask } else { 0 }, mask)
+    }
+    fn sm_set_enabled(&mut self, sm: u32, enabled: bool) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.sm_set_enabled_mask(1 << sm, enabled)
+    }
+    fn sm_set_enabled_mask(&mut self, mask: u32, enabled: bool) -> PioResult<()>;
+    fn sm_restart(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.sm_restart_mask(1 << sm)
+    }
+    fn sm_restart_mask(&mut self, mask: u32) -> PioResult<()>;
+    fn sm_clkdiv_restart(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.sm_clkdiv_restart_mask(1 << sm)
+    }
+    fn sm_clkdiv_restart_mask(&mut self, mask: u32) -> PioResult<()>;
+    fn sm_enable_sync(&mut self, mask: u32) -> PioResult<()>;
+    fn sm_put(&mut self, sm: u32, data: u32, blocking: bool) -> PioResult<()>;
+    fn sm_get(&mut self, sm: u32, blocking: bool) -> PioResult<u32>;
+    fn sm_set_dmactrl(&mut self, sm: u32, is_tx: bool, ctrl: u32) -> PioResult<()>;
+    fn sm_is_rx_fifo_empty(&mut self, sm: u32) -> PioResult<bool>;
+    fn sm_is_rx_fifo_full(&mut self, sm: u32) -> PioResult<bool>;
+    fn sm_get_rx_fifo_level(&mut self, sm: u32) -> PioResult<u32>;
+    fn sm_is_tx_fifo_empty(&mut self, sm: u32) -> PioResult<bool>;
+    fn sm_is_tx_fifo_full(&mut self, sm: u32) -> PioResult<bool>;
+    fn sm_get_tx_fifo_level(&mut self, sm: u32) -> PioResult<u32>;
+    fn sm_drain_tx_fifo(&mut self, sm: u32) -> PioResult<()>;
+
+    fn get_default_sm_config(&self) -> pio_sm_config {
+        pio_get_default_sm_config()
+    }
+
+    fn clock_get_hz(&mut self, clk_index: clock_index) -> PioResult<u32>;
+    fn pio_gpio_init(&mut self, pin: u32) -> PioResult<()>;
+    fn gpio_init(&mut self, gpio: u32) -> PioResult<()>;
+    fn gpio_set_function(&mut self, gpio: u32, func: gpio_function) -> PioResult<()>;
+    fn gpio_set_pulls(&mut self, gpio: u32, up: bool, down: bool) -> PioResult<()>;
+    fn gpio_set_outover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()>;
+    fn gpio_set_inover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()>;
+    fn gpio_set_oeover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()>;
+    fn gpio_set_input_enabled(&mut self, gpio: u32, enabled: bool) -> PioResult<()>;
+    fn gpio_set_drive_strength(&mut self, gpio: u32, drive: gpio_drive_strength) -> PioResult<()>;
+
+    /// `InvalidParam` unless `sm` exists on this chip
+    fn check_sm(&self, sm: u32) -> PioResult<()> {
+        if sm < self.info().sm_count as u32 {
+            Ok(())
+        } else {
+            Err(PioError::InvalidParam { which: "sm" })
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt;
+
+use crate::include::piolib::PioProgramError;
+
+/// Error from a PIO operation
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PioError {
+    /// a parameter was out of range for the chip, `which` names it
+    InvalidParam { which: &'static str },
+    /// the program cannot be loaded as given
+    InvalidProgram(PioProgramError),
+    /// no room in instruction memory for the program
+    NoProgramSpace,
+    /// the state machine is claimed already
+    SmAlreadyClaimed,
+    /// the driver does not understand the request, so it is older or newer than this library
+    DriverVersion,
+    /// the backend cannot do this operation
+    Unsupported,
+    /// the driver or device failed with this errno
+    Io(i32),
+}
+
+pub type PioResult<T> = Result<T, PioError>;
+
+impl fmt::Display for PioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PioError::InvalidParam { which } => write!(f, "invalid {which}"),
+            PioError::InvalidProgram(err) => write!(f, "invalid program: {err}"),
+            PioError::NoProgramSpace => write!(f, "no program space"),
+            PioError::SmAlreadyClaimed => write!(f, "state machine already claimed"),
+            PioError::DriverVersion => write!(f, "driver version mismatch"),
+            PioError::Unsupported => write!(f, "not supported by this backend"),
+            PioError::Io(errno) => write!(f, "{}", std::io::Error::from_raw_os_error(*errno)),
+        }
+    }
+}
+
+impl std::error::Error for PioError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PioError::InvalidProgram(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<PioProgramError> for PioError {
+    fn from(err: PioProgramError) -> Self {
+        PioError::InvalidProgram(err)
+    }
+}
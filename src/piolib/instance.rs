@@ -0,0 +1,155 @@
+use std::fmt;
+
+use super::chip::{PioChip, PioChipInfo};
+use super::error::{PioError, PioResult};
+
+/// Highest number of PIO instances `open_by_name` looks through
+pub const PIO_INSTANCE_COUNT: u32 = 4;
+
+const ENOENT: i32 = 2;
+
+/// An open PIO instance, closed again when dropped
+///
+/// `Pio::open_helper(0)` stands in for the C `pio0` macro.
+///
+/// Operations run through [`Pio::with_chip`] also keep the C sticky error: the last error is kept
+/// until [`Pio::clear_error`], and with [`Pio::enable_fatal_errors`] any error panics instead of
+/// being returned, for code ported from C that never checks.
+pub struct Pio {
+    chip: Box<dyn PioChip>,
+    index: u32,
+    closed: bool,
+    errors_are_fatal: bool,
+    error: Option<PioError>,
+}
+
+impl Pio {
+    /// Open PIO instance `index`, `/dev/pio<index>` on a Raspberry Pi 5
+    #[cfg(target_os = "linux")]
+    pub fn open(index: u32) -> PioResult<Pio> {
+        let chip = super::pio_rp1::Rp1Pio::open(index)?;
+        Ok(Pio::from_chip(chip, index))
+    }
+
+    /// Open the first instance whose chip is called `name`
+    #[cfg(target_os = "linux")]
+    pub fn open_by_name(name: &str) -> PioResult<Pio> {
+        for index in 0..PIO_INSTANCE_COUNT {
+            match Pio::open(index) {
+                Ok(pio) if pio.info().name == name => return Ok(pio),
+                Ok(_) => {}
+                Err(PioError::Io(ENOENT)) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        Err(PioError::Io(ENOENT))
+    }
+
+    /// Open instance `index`, panicking if it cannot be opened
+    #[cfg(target_os = "linux")]
+    pub fn open_helper(index: u32) -> Pio {
+        match Pio::open(index) {
+            Ok(pio) => pio,
+            Err(err) => panic!("Failed to open PIO device {index} - {err}"),
+        }
+    }
+
+    /// Wrap an already opened backend as instance `index`
+    pub fn from_chip(chip: impl PioChip + 'static, index: u32) -> Pio {
+        Pio {
+            chip: Box::new(chip),
+            index,
+            closed: false,
+            errors_are_fatal: false,
+            error: None,
+        }
+    }
+
+    /// Close the instance, reporting any error that dropping it would ignore
+    pub fn close(mut self) -> PioResult<()> {
+        self.closed = true;
+        self.chip.close()
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn info(&self) -> &PioChipInfo {
+        self.chip.info()
+    }
+
+    pub fn sm_count(&self) -> u32 {
+        self.info().sm_count as u32
+    }
+
+    pub fn instruction_count(&self) -> u32 {
+        self.info().instr_count as u32
+    }
+
+    pub fn fifo_depth(&self) -> u32 {
+        self.info().fifo_depth as u32
+    }
+
+    pub fn chip(&self) -> &dyn PioChip {
+        &*self.chip
+    }
+
+    pub fn chip_mut(&mut self) -> &mut dyn PioChip {
+        &mut *self.chip
+    }
+
+    /// Run `op` on the chip, passing any error it returns through [`Pio::record_error`]
+    pub fn with_chip<T>(
+        &mut self,
+        op: impl FnOnce(&mut dyn PioChip) -> PioResult<T>,
+    ) -> PioResult<T> {
+        let result = op(&mut *self.chip);
+        if let Err(err) = result {
+            self.record_error(err);
+        }
+        result
+    }
+
+    /// Remember `err` as the sticky error, or panic with it if errors are fatal
+    pub fn record_error(&mut self, err: PioError) {
+        self.error = Some(err);
+        if self.errors_are_fatal {
+            panic!("PIOLIB fatal error: {err}");
+        }
+    }
+
+    /// The last error recorded since the instance was opened or the error was cleared
+    pub fn last_error(&self) -> Option<PioError> {
+        self.error
+    }
+
+    pub fn clear_error(&mut self) {
+        self.error = None;
+    }
+
+    pub fn enable_fatal_errors(&mut self, enable: bool) {
+        self.errors_are_fatal = enable;
+    }
+
+    pub fn errors_are_fatal(&self) -> bool {
+        self.errors_are_fatal
+    }
+}
+
+impl Drop for Pio {
+    fn drop(&mut self) {
+        if !self.closed {
+            let _ = self.chip.close();
+        }
+    }
+}
+
+impl fmt::Debug for Pio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pio")
+            .field("chip", &self.info().name)
+            .field("index", &self.index)
+            .finish()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::include::piolib::{pio_program, PIO_INSTRUCTION_COUNT};
+
+/// Allocation state of the 32 instruction memory slots of one PIO block
+///
+/// Places programs the way the SDK does: a fixed `.origin` must be free, anything else goes in
+/// the highest free range that fits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PioInstrMem {
+    used_mask: u32,
+}
+
+impl PioInstrMem {
+    pub const fn new() -> Self {
+        PioInstrMem { used_mask: 0 }
+    }
+
+    pub const fn from_used_mask(used_mask: u32) -> Self {
+        PioInstrMem { used_mask }
+    }
+
+    /// Bit N is set when instruction slot N is in use
+    pub const fn used_mask(&self) -> u32 {
+        self.used_mask
+    }
+
+    /// Offset `program` would be placed at, if it fits anywhere it is allowed to go
+    pub fn find_offset(&self, program: &pio_program) -> Option<u8> {
+        match program.origin() {
+            Some(origin) => self.is_free(program, origin).then_some(origin),
+            None => {
+                let highest = PIO_INSTRUCTION_COUNT.checked_sub(program.length() as u32)?;
+                // work down from the top always
+                (0..=highest as u8)
+                    .rev()
+                    .find(|&offset| self.is_free(program, offset))
+            }
+        }
+    }
+
+    /// Whether `program` can be placed at `offset`, or anywhere for `None`
+    pub fn can_add_program_at_offset(&self, program: &pio_program, offset: Option<u8>) -> bool {
+        match offset {
+            None => self.find_offset(program).is_some(),
+            Some(offset) => {
+                program.origin().is_none_or(|origin| origin == offset)
+                    && self.is_free(program, offset)
+            }
+        }
+    }
+
+    /// Claim space for `program` at `offset`, or wherever it fits for `None`
+    ///
+    /// Returns the offset the program was placed at, or `None` (`PIO_ORIGIN_INVALID`) if there
+    /// is no room.
+    pub fn add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> Option<u8> {
+        let offset = match offset {
+            None => self.find_offset(program)?,
+            Some(offset) => offset,
+        };
+        if !self.can_add_program_at_offset(program, Some(offset)) {
+            return None;
+        }
+        self.used_mask |= program_mask(program, offset)?;
+        Some(offset)
+    }
+
+    /// Release the space taken by `program` loaded at `loaded_offset`
+    ///
+    /// Returns false, leaving the allocation unchanged, if that space was not fully in use.
+    pub fn remove_program(&mut self, program: &pio_program, loaded_offset: u8) -> bool {
+        match program_mask(program, loaded_offset) {
+            Some(mask) if self.used_mask & mask == mask => {
+                self.used_mask &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn clear_instruction_memory(&mut self) {
+        self.used_mask = 0;
+    }
+
+    fn is_free(&self, program: &pio_program, offset: u8) -> bool {
+        program_mask(program, offset).is_some_and(|mask| self.used_mask & mask == 0)
+    }
+}
+
+/// Slots covered by `program` at `offset`, or `None` if it would run off the end of memory
+fn program_mask(program: &pio_program, offset: u8) -> Option<u32> {
+    let end = offset as u32 + program.length() as u32;
+    if end > PIO_INSTRUCTION_COUNT {
+        return None;
+    }
+    Some((((1u64 << program.length()) - 1) << offset) as u32)
+}
@@ -0,0 +1,577 @@
+//! Backend driving the PIO registers directly through a mapped register window
+//!
+//! Meant for bring-up and debugging without the rp1-pio driver: instruction memory allocation
+//! and state machine claims are only tracked within this process, and the GPIO block is not
+//! mapped, so the `gpio_*` operations return [`PioError::Unsupported`].
+
+#[cfg(target_os = "linux")]
+use std::io;
+
+use crate::include::hardware::clocks::clock_index;
+use crate::include::hardware::gpio::{gpio_drive_strength, gpio_function, gpio_override};
+use crate::include::hardware::pio_instructions::{
+    pio_encode_jmp, pio_encode_out, pio_encode_pull, pio_encode_set, pio_src_dest,
+};
+use crate::include::hardware::regs::proc_pio::*;
+use crate::include::piolib::{pio_program, pio_sm_config, pio_xfer_dir};
+
+use super::chip::{PioChip, PioChipInfo};
+use super::error::{PioError, PioResult};
+use super::instr_mem::PioInstrMem;
+use super::pio_rp1::RP1_PIO_CHIP_INFO;
+use super::regs::*;
+
+pub const MMIO_PIO_CHIP_INFO: PioChipInfo = PioChipInfo {
+    name: "rp1-mmio",
+    ..RP1_PIO_CHIP_INFO
+};
+
+/// Physical address of the RP1 PIO block as seen from the Raspberry Pi 5 CPU
+pub const RP1_PIO_PHYS_BASE: u64 = 0x1f_0017_8000;
+
+/// Size of the PROC_PIO register block
+pub const PROC_PIO_REGS_SIZE: usize = PROC_PIO_RSTSEQ_DONE_OFFSET as usize + 4;
+
+/// 32-bit register access to a PIO register block
+///
+/// [`MmioRegion`] is a real mapping; tests can stand in a fake register file.
+pub trait MemoryRegion: Send {
+    /// Read the register at byte `offset`, which may have side effects such as popping RXFn
+    fn read32(&mut self, offset: u32) -> u32;
+    fn write32(&mut self, offset: u32, value: u32);
+}
+
+/// A register window mapped from `/dev/mem` or a similar device
+#[cfg(target_os = "linux")]
+pub struct MmioRegion {
+    mapping: *mut u8,
+    mapping_len: usize,
+    regs: *mut u32,
+    len: usize,
+}
+
+// SAFETY: the mapping is owned by the region and only accessed through `&mut self`
+#[cfg(target_os = "linux")]
+unsafe impl Send for MmioRegion {}
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::ffi::{c_int, c_long, c_void};
+
+    pub const PROT_READ: c_int = 1;
+    pub const PROT_WRITE: c_int = 2;
+    pub const MAP_SHARED: c_int = 1;
+    pub const SC_PAGESIZE: c_int = 30;
+
+    extern "C" {
+        // the 64-bit offset variant, since `off_t` is 32 bits on 32-bit glibc and bionic; musl's
+        // `off_t` is always 64 bits and it only keeps `mmap64` for old binaries
+        #[cfg_attr(not(target_env = "musl"), link_name = "mmap64")]
+        pub fn mmap(
+            addr: *mut c_void,
+            len: usize,
+            prot: c_int,
+            flags: c_int,
+            fd: c_int,
+            offset: i64,
+        ) -> *mut c_void;
+        pub fn munmap(addr: *mut c_void, len: usize) -> c_int;
+        pub fn sysconf(name: c_int) -> c_long;
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl MmioRegion {
+    /// Map the RP1 PIO registers through `/dev/mem`, which needs root
+    pub fn open_rp1() -> io::Result<Self> {
+        Self::map("/dev/mem", RP1_PIO_PHYS_BASE, PROC_PIO_REGS_SIZE)
+    }
+
+    /// Map `len` bytes at physical address `phys_base` of the memory device at `path`
+    pub fn map(path: impl AsRef<std::path::Path>, phys_base: u64, len: usize) -> io::Result<Self> {
+        use std::os::fd::AsRawFd;
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)?;
+        // SAFETY: sysconf has no preconditions
+        let page_size = unsafe { sys::sysconf(sys::SC_PAGESIZE) } as u64;
+        let page_base = phys_base & !(page_size - 1);
+        let skip = (phys_base - page_base) as usize;
+        let offset = page_base
+            .try_into()
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+        let mapping_len = skip + len;
+        // SAFETY: a fresh shared mapping of the device; the fd may be closed once it is mapped
+        let mapping = unsafe {
+            sys::mmap(
+                std::ptr::null_mut(),
+                mapping_len,
+                sys::PROT_READ | sys::PROT_WRITE,
+                sys::MAP_SHARED,
+                file.as_raw_fd(),
+                offset,
+            )
+        };
+        if mapping as isize == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        let mapping = mapping as *mut u8;
+        Ok(MmioRegion {
+            mapping,
+            mapping_len,
+            // SAFETY: `skip` is within the mapping
+            regs: unsafe { mapping.add(skip) } as *mut u32,
+            len,
+        })
+    }
+
+    fn reg(&self, offset: u32) -> *mut u32 {
+        assert!(
+            offset.is_multiple_of(4) && (offset as usize) < self.len,
+            "register offset {offset:#x} outside the mapped window"
+        );
+        // SAFETY: checked to be an aligned word within the mapping
+        unsafe { self.regs.add(offset as usize / 4) }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl MemoryRegion for MmioRegion {
+    fn read32(&mut self, offset: u32) -> u32 {
+        // SAFETY: `reg` only returns pointers into the live mapping
+        unsafe { self.reg(offset).read_volatile() }
+    }
+
+    fn write32(&mut self, offset: u32, value: u32) {
+        // SAFETY: `reg` only returns pointers into the live mapping
+        unsafe { self.reg(offset).write_volatile(value) }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for MmioRegion {
+    fn drop(&mut self) {
+        // SAFETY: unmapping exactly what `map` mapped
+        unsafe { sys::munmap(self.mapping as *mut _, self.mapping_len) };
+    }
+}
+
+/// A PIO block driven through its registers in a [`MemoryRegion`]
+pub struct MmioPio<R: MemoryRegion> {
+    regs: R,
+    instr_mem: PioInstrMem,
+    claimed: u32,
+}
+
+#[cfg(target_os = "linux")]
+impl MmioPio<MmioRegion> {
+    /// Map the RP1 PIO block through `/dev/mem`
+    pub fn open_rp1() -> PioResult<Self> {
+        MmioRegion::open_rp1()
+            .map(MmioPio::with_region)
+            .map_err(|err| PioError::Io(err.raw_os_error().unwrap_or(0)))
+    }
+}
+
+impl<R: MemoryRegion> MmioPio<R> {
+    pub fn with_region(regs: R) -> Self {
+        MmioPio {
+            regs,
+            instr_mem: PioInstrMem::new(),
+            claimed: 0,
+        }
+    }
+
+    pub fn region(&self) -> &R {
+        &self.regs
+    }
+
+    pub fn region_mut(&mut self) -> &mut R {
+        &mut self.regs
+    }
+
+    fn check_sm_mask(mask: u32) -> PioResult<u32> {
+        if mask < 1 << PROC_PIO_SM_COUNT {
+            Ok(mask)
+        } else {
+            Err(PioError::InvalidParam { which: "mask" })
+        }
+    }
+
+    fn ctrl(&mut self) -> Ctrl {
+        Ctrl(self.regs.read32(Ctrl::OFFSET))
+    }
+
+    /// Write the SM enables to CTRL along with the self-clearing restart bits in `set`
+    fn set_ctrl(&mut self, enables: u32, set: u32) {
+        self.regs.write32(Ctrl::OFFSET, enables | set);
+    }
+
+    fn fstat(&mut self) -> Fstat {
+        Fstat(self.regs.read32(Fstat::OFFSET))
+    }
+
+    fn exec_stalled(&mut self, sm: u32) -> bool {
+        SmExecCtrl(self.regs.read32(SmExecCtrl::offset(sm))).exec_stalled()
+    }
+
+    /// Execute `SET dest` with SET_BASE moved to each pin in `mask` in turn, like the SDK does
+    fn set_pins_by_exec(
+        &mut self,
+        sm: u32,
+        dest: pio_src_dest,
+        values: u32,
+        mask: u32,
+    ) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let pinctrl_saved = self.regs.read32(SmPinCtrl::offset(sm));
+        let execctrl_saved = self.regs.read32(SmExecCtrl::offset(sm));
+        let mut execctrl = SmExecCtrl(execctrl_saved);
+        execctrl.set_out_sticky(false);
+        self.regs.write32(SmExecCtrl::offset(sm), execctrl.bits());
+        for pin in (0..32).filter(|pin| mask & (1 << pin) != 0) {
+            let mut pinctrl = SmPinCtrl::default();
+            pinctrl.set_set_base(pin as u8).set_set_count(1);
+            self.regs.write32(SmPinCtrl::offset(sm), pinctrl.bits());
+            let instr = pio_encode_set(pio_src_dest(dest.0), (values >> pin) & 1);
+            self.sm_exec(sm, instr as u16, false)?;
+        }
+        self.regs.write32(SmPinCtrl::offset(sm), pinctrl_saved);
+        self.regs.write32(SmExecCtrl::offset(sm), execctrl_saved);
+        Ok(())
+    }
+
+    fn fifo_level(&mut self, sm: u32, tx: bool) -> PioResult<u32> {
+        self.check_sm(sm)?;
+        let flevel = Flevel(self.regs.read32(Flevel::OFFSET));
+        let flevel2 = Flevel2(self.regs.read32(Flevel2::OFFSET));
+        Ok(fifo_level(flevel, flevel2, sm, tx))
+    }
+}
+
+impl<R: MemoryRegion> PioChip for MmioPio<R> {
+    fn info(&self) -> &PioChipInfo {
+        &MMIO_PIO_CHIP_INFO
+    }
+
+    /// There is no DMA here, so transfers are always done word by word
+    fn sm_config_xfer(
+        &mut self,
+        sm: u32,
+        dir: pio_xfer_dir,
+        _buf_size: u32,
+        _buf_count: u32,
+    ) -> PioResult<()> {
+        self.check_sm(sm)?;
+        match dir {
+            pio_xfer_dir::PIO_DIR_COUNT => Err(PioError::InvalidParam { which: "dir" }),
+            _ => Ok(()),
+        }
+    }
+
+    fn sm_xfer_data(&mut self, sm: u32, dir: pio_xfer_dir, data: &mut [u8]) -> PioResult<()> {
+        self.check_sm(sm)?;
+        if !data.len().is_multiple_of(4) {
+            return Err(PioError::InvalidParam { which: "data" });
+        }
+        for word in data.chunks_exact_mut(4) {
+            match dir {
+                pio_xfer_dir::PIO_DIR_TO_SM => {
+                    self.sm_put(sm, u32::from_le_bytes(word.try_into().unwrap()), true)?
+                }
+                pio_xfer_dir::PIO_DIR_FROM_SM => {
+                    word.copy_from_slice(&self.sm_get(sm, true)?.to_le_bytes())
+                }
+                pio_xfer_dir::PIO_DIR_COUNT => return Err(PioError::InvalidParam { which: "dir" }),
+            }
+        }
+        Ok(())
+    }
+
+    fn can_add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<bool> {
+        Ok(self.instr_mem.can_add_program_at_offset(program, offset))
+    }
+
+    fn add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<u8> {
+        program.check(offset)?;
+        let mut instr_mem = self.instr_mem;
+        let offset = instr_mem
+            .add_program_at_offset(program, offset)
+            .ok_or(PioError::NoProgramSpace)?;
+        let image = program.relocated(offset)?;
+        for (addr, instr) in (offset as u32..).zip(image) {
+            let mut reg = InstrMem::default();
+            reg.set_instr(instr);
+            self.regs.write32(InstrMem::offset(addr), reg.bits());
+        }
+        self.instr_mem = instr_mem;
+        Ok(offset)
+    }
+
+    fn remove_program(&mut self, program: &pio_program, loaded_offset: u8) -> PioResult<()> {
+        if self.instr_mem.remove_program(program, loaded_offset) {
+            Ok(())
+        } else {
+            Err(PioError::InvalidParam {
+                which: "loaded_offset",
+            })
+        }
+    }
+
+    fn clear_instruction_memory(&mut self) -> PioResult<()> {
+        self.instr_mem.clear_instruction_memory();
+        Ok(())
+    }
+
+    fn sm_claim_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        if self.claimed & mask != 0 {
+            return Err(PioError::SmAlreadyClaimed);
+        }
+        self.claimed |= mask;
+        Ok(())
+    }
+
+    fn sm_unclaim(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.claimed &= !(1 << sm);
+        Ok(())
+    }
+
+    fn sm_is_claimed(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.claimed & (1 << sm) != 0)
+    }
+
+    fn sm_init(&mut self, sm: u32, initial_pc: u32, config: &pio_sm_config) -> PioResult<()> {
+        self.check_sm(sm)?;
+        if initial_pc >= InstrMem::COUNT {
+            return Err(PioError::InvalidParam {
+                which: "initial_pc",
+            });
+        }
+        self.sm_set_enabled(sm, false)?;
+        self.sm_set_config(sm, config)?;
+        self.sm_clear_fifos(sm)?;
+        let mut fdebug = Fdebug::default();
+        fdebug
+            .clear_tx_stall(sm)
+            .clear_tx_over(sm)
+            .clear_rx_under(sm)
+            .clear_rx_stall(sm);
+        self.regs.write32(Fdebug::OFFSET, fdebug.bits());
+        self.sm_restart(sm)?;
+        self.sm_clkdiv_restart(sm)?;
+        self.sm_exec(sm, pio_encode_jmp(initial_pc) as u16, false)
+    }
+
+    fn sm_set_config(&mut self, sm: u32, config: &pio_sm_config) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.regs.write32(SmClkdiv::offset(sm), config.clkdiv());
+        self.regs.write32(SmExecCtrl::offset(sm), config.execctrl());
+        self.regs
+            .write32(SmShiftCtrl::offset(sm), config.shiftctrl());
+        self.regs.write32(SmPinCtrl::offset(sm), config.pinctrl());
+        Ok(())
+    }
+
+    fn sm_exec(&mut self, sm: u32, instr: u16, blocking: bool) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut reg = SmInstr::default();
+        reg.set_instr(instr);
+        self.regs.write32(SmInstr::offset(sm), reg.bits());
+        while blocking && self.exec_stalled(sm) {
+            std::hint::spin_loop();
+        }
+        Ok(())
+    }
+
+    /// Toggling FJOIN_RX twice empties both FIFOs
+    fn sm_clear_fifos(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let shiftctrl = self.regs.read32(SmShiftCtrl::offset(sm));
+        let toggled = shiftctrl ^ PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS;
+        self.regs.write32(SmShiftCtrl::offset(sm), toggled);
+        self.regs.write32(SmShiftCtrl::offset(sm), shiftctrl);
+        Ok(())
+    }
+
+    fn sm_set_clkdiv_int_frac(&mut self, sm: u32, div_int: u16, div_frac: u8) -> PioResult<()> {
+        self.check_sm(sm)?;
+        if div_int == 0 && div_frac != 0 {
+            return Err(PioError::InvalidParam { which: "div_frac" });
+        }
+        let mut clkdiv = SmClkdiv::default();
+        clkdiv.set_int(div_int).set_frac(div_frac);
+        self.regs.write32(SmClkdiv::offset(sm), clkdiv.bits());
+        Ok(())
+    }
+
+    fn sm_set_pins_with_mask(&mut self, sm: u32, pin_values: u32, pin_mask: u32) -> PioResult<()> {
+        self.set_pins_by_exec(sm, pio_src_dest::pio_pins, pin_values, pin_mask)
+    }
+
+    fn sm_set_pindirs_with_mask(&mut self, sm: u32, pin_dirs: u32, pin_mask: u32) -> PioResult<()> {
+        self.set_pins_by_exec(sm, pio_src_dest::pio_pindirs, pin_dirs, pin_mask)
+    }
+
+    fn sm_set_enabled_mask(&mut self, mask: u32, enabled: bool) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        let enables = self.ctrl().bits() & PROC_PIO_CTRL_SM_ENABLE_BITS;
+        let enables = if enabled {
+            enables | mask
+        } else {
+            enables & !mask
+        };
+        self.set_ctrl(enables, 0);
+        Ok(())
+    }
+
+    fn sm_restart_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        let enables = self.ctrl().bits() & PROC_PIO_CTRL_SM_ENABLE_BITS;
+        self.set_ctrl(enables, mask << PROC_PIO_CTRL_SM_RESTART_LSB);
+        Ok(())
+    }
+
+    fn sm_clkdiv_restart_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        let enables = self.ctrl().bits() & PROC_PIO_CTRL_SM_ENABLE_BITS;
+        self.set_ctrl(enables, mask << PROC_PIO_CTRL_CLKDIV_RESTART_LSB);
+        Ok(())
+    }
+
+    fn sm_enable_sync(&mut self, mask: u32) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        let enables = self.ctrl().bits() & PROC_PIO_CTRL_SM_ENABLE_BITS;
+        self.set_ctrl(enables | mask, mask << PROC_PIO_CTRL_CLKDIV_RESTART_LSB);
+        Ok(())
+    }
+
+    /// Like the SDK, a non-blocking put to a full FIFO is dropped and flagged in FDEBUG
+    fn sm_put(&mut self, sm: u32, data: u32, blocking: bool) -> PioResult<()> {
+        self.check_sm(sm)?;
+        while blocking && self.fstat().tx_full(sm) {
+            std::hint::spin_loop();
+        }
+        self.regs.write32(PROC_PIO_TXF0_OFFSET + 4 * sm, data);
+        Ok(())
+    }
+
+    /// Like the SDK, a non-blocking get from an empty FIFO returns junk and is flagged in FDEBUG
+    fn sm_get(&mut self, sm: u32, blocking: bool) -> PioResult<u32> {
+        self.check_sm(sm)?;
+        while blocking && self.fstat().rx_empty(sm) {
+            std::hint::spin_loop();
+        }
+        Ok(self.regs.read32(PROC_PIO_RXF0_OFFSET + 4 * sm))
+    }
+
+    fn sm_set_dmactrl(&mut self, sm: u32, is_tx: bool, ctrl: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let offset = if is_tx {
+            SmDmaCtrlTx::offset(sm)
+        } else {
+            SmDmaCtrlRx::offset(sm)
+        };
+        self.regs.write32(offset, ctrl);
+        Ok(())
+    }
+
+    fn sm_is_rx_fifo_empty(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.fstat().rx_empty(sm))
+    }
+
+    fn sm_is_rx_fifo_full(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.fstat().rx_full(sm))
+    }
+
+    fn sm_get_rx_fifo_level(&mut self, sm: u32) -> PioResult<u32> {
+        self.fifo_level(sm, false)
+    }
+
+    fn sm_is_tx_fifo_empty(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.fstat().tx_empty(sm))
+    }
+
+    fn sm_is_tx_fifo_full(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.fstat().tx_full(sm))
+    }
+
+    fn sm_get_tx_fifo_level(&mut self, sm: u32) -> PioResult<u32> {
+        self.fifo_level(sm, true)
+    }
+
+    fn sm_drain_tx_fifo(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let instr = if SmShiftCtrl(self.regs.read32(SmShiftCtrl::offset(sm))).autopull() {
+            pio_encode_out(pio_src_dest::pio_null, 32)
+        } else {
+            pio_encode_pull(false, false)
+        };
+        while !self.fstat().tx_empty(sm) {
+            self.sm_exec(sm, instr as u16, false)?;
+        }
+        Ok(())
+    }
+
+    fn clock_get_hz(&mut self, clk_index: clock_index) -> PioResult<u32> {
+        match clk_index {
+            clock_index::clk_sys => Ok(200_000_000),
+            _ => Err(PioError::InvalidParam { which: "clk_index" }),
+        }
+    }
+
+    fn pio_gpio_init(&mut self, _pin: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_init(&mut self, _gpio: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_set_function(&mut self, _gpio: u32, _func: gpio_function) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_set_pulls(&mut self, _gpio: u32, _up: bool, _down: bool) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_set_outover(&mut self, _gpio: u32, _value: gpio_override) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_set_inover(&mut self, _gpio: u32, _value: gpio_override) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_set_oeover(&mut self, _gpio: u32, _value: gpio_override) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_set_input_enabled(&mut self, _gpio: u32, _enabled: bool) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+
+    fn gpio_set_drive_strength(
+        &mut self,
+        _gpio: u32,
+        _drive: gpio_drive_strength,
+    ) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+}
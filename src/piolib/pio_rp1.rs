@@ -0,0 +1,542 @@
+//! Raspberry Pi 5 backend talking to the rp1-pio kernel driver through `/dev/pioN`
+
+use std::io;
+
+use crate::include::hardware::clocks::clock_index;
+use crate::include::hardware::gpio::{gpio_drive_strength, gpio_function, gpio_override};
+use crate::include::piolib::{pio_program, pio_sm_config, pio_xfer_dir};
+use crate::include::rp1_pio_if::*;
+
+use super::chip::{PioChip, PioChipInfo};
+use super::error::{PioError, PioResult};
+
+pub const RP1_PIO_CHIP_INFO: PioChipInfo = PioChipInfo {
+    name: "rp1",
+    compatible: "raspberrypi,rp1-pio",
+    instr_count: RP1_PIO_INSTRUCTION_COUNT as u16,
+    sm_count: RP1_PIO_SM_COUNT as u16,
+    fifo_depth: 8,
+};
+
+const EBUSY: i32 = 16;
+const ENOMEM: i32 = 12;
+const ENOSPC: i32 = 28;
+const ENOTTY: i32 = 25;
+
+/// Channel the rp1-pio ioctls are issued on
+///
+/// [`Rp1PioFile`] is the real character device; tests can stand in a fake that decodes the
+/// argument structs with [`Rp1PioArgs::from_bytes`].
+pub trait Rp1PioDevice: Send {
+    /// Issue `request` with `arg` holding the argument struct, which the driver may update in
+    /// place; returns the non-negative ioctl result
+    fn ioctl(&mut self, request: u32, arg: &mut [u8]) -> io::Result<i32>;
+}
+
+/// An open `/dev/pioN`
+#[cfg(target_os = "linux")]
+pub struct Rp1PioFile {
+    file: std::fs::File,
+}
+
+#[cfg(target_os = "linux")]
+impl Rp1PioFile {
+    pub fn open(index: u32) -> io::Result<Self> {
+        Self::open_path(format!("/dev/pio{index}"))
+    }
+
+    pub fn open_path(path: impl AsRef<std::path::Path>) -> io::Result<Self> {
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(path)?;
+        Ok(Rp1PioFile { file })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Rp1PioDevice for Rp1PioFile {
+    fn ioctl(&mut self, request: u32, arg: &mut [u8]) -> io::Result<i32> {
+        use std::os::fd::AsRawFd;
+        extern "C" {
+            #[cfg(not(target_env = "musl"))]
+            fn ioctl(fd: i32, request: std::ffi::c_ulong, ...) -> i32;
+            #[cfg(target_env = "musl")]
+            fn ioctl(fd: i32, request: i32, ...) -> i32;
+        }
+        // SAFETY: `arg` is the argument struct `request` was defined with, and stays borrowed
+        // for the duration of the call
+        let ret = unsafe { ioctl(self.file.as_raw_fd(), request as _, arg.as_mut_ptr()) };
+        if ret < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ret)
+        }
+    }
+}
+
+/// A PIO block on RP1, driven through the rp1-pio ioctls
+#[cfg(target_os = "linux")]
+pub struct Rp1Pio<D: Rp1PioDevice = Rp1PioFile> {
+    device: D,
+}
+
+#[cfg(not(target_os = "linux"))]
+pub struct Rp1Pio<D: Rp1PioDevice> {
+    device: D,
+}
+
+#[cfg(target_os = "linux")]
+impl Rp1Pio<Rp1PioFile> {
+    /// Open `/dev/pio<index>`
+    pub fn open(index: u32) -> PioResult<Self> {
+        Rp1PioFile::open(index)
+            .map(Rp1Pio::with_device)
+            .map_err(io_error)
+    }
+}
+
+impl<D: Rp1PioDevice> Rp1Pio<D> {
+    pub fn with_device(device: D) -> Self {
+        Rp1Pio { device }
+    }
+
+    pub fn device(&self) -> &D {
+        &self.device
+    }
+
+    pub fn device_mut(&mut self) -> &mut D {
+        &mut self.device
+    }
+
+    fn call<T: Rp1PioArgs>(&mut self, request: u32, args: &mut T) -> PioResult<i32> {
+        self.device
+            .ioctl(request, args.as_bytes_mut())
+            .map_err(io_error)
+    }
+
+    fn check_gpio(gpio: u32) -> PioResult<u16> {
+        if gpio < RP1_PIO_GPIO_COUNT {
+            Ok(gpio as u16)
+        } else {
+            Err(PioError::InvalidParam { which: "gpio" })
+        }
+    }
+
+    fn check_sm_mask(mask: u32) -> PioResult<u16> {
+        if mask < 1 << RP1_PIO_SM_COUNT {
+            Ok(mask as u16)
+        } else {
+            Err(PioError::InvalidParam { which: "mask" })
+        }
+    }
+
+    fn check_dir(dir: pio_xfer_dir) -> PioResult<u16> {
+        match dir {
+            pio_xfer_dir::PIO_DIR_TO_SM => Ok(RP1_PIO_DIR_TO_SM),
+            pio_xfer_dir::PIO_DIR_FROM_SM => Ok(RP1_PIO_DIR_FROM_SM),
+            pio_xfer_dir::PIO_DIR_COUNT => Err(PioError::InvalidParam { which: "dir" }),
+        }
+    }
+
+    fn program_args(
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<rp1_pio_add_program_args> {
+        program.check(offset)?;
+        let offset = offset.or(program.origin());
+        if offset.is_some_and(|offset| {
+            offset as usize + program.instructions().len() > RP1_PIO_INSTRUCTION_COUNT
+        }) {
+            return Err(PioError::InvalidParam { which: "offset" });
+        }
+        let mut args = rp1_pio_add_program_args {
+            num_instrs: program.length() as u16,
+            origin: offset.map_or(RP1_PIO_ORIGIN_ANY, u16::from),
+            ..Default::default()
+        };
+        args.instrs[..program.instructions().len()].copy_from_slice(program.instructions());
+        Ok(args)
+    }
+
+    fn fifo_state(&mut self, sm: u32, tx: bool) -> PioResult<rp1_pio_sm_fifo_state_args> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_fifo_state_args {
+            sm: sm as u16,
+            tx: tx as u8,
+            ..Default::default()
+        };
+        self.call(PIO_IOC_SM_FIFO_STATE, &mut args)?;
+        Ok(args)
+    }
+
+    fn gpio_set(&mut self, request: u32, gpio: u32, value: u16) -> PioResult<()> {
+        let mut args = rp1_gpio_set_args {
+            gpio: Self::check_gpio(gpio)?,
+            value,
+        };
+        self.call(request, &mut args).map(drop)
+    }
+}
+
+/// ENOTTY means the driver has no such ioctl, i.e. it is a different version from this library
+fn io_error(err: io::Error) -> PioError {
+    match err.raw_os_error() {
+        Some(ENOTTY) => PioError::DriverVersion,
+        errno => PioError::Io(errno.unwrap_or(0)),
+    }
+}
+
+impl<D: Rp1PioDevice> PioChip for Rp1Pio<D> {
+    fn info(&self) -> &PioChipInfo {
+        &RP1_PIO_CHIP_INFO
+    }
+
+    fn sm_config_xfer(
+        &mut self,
+        sm: u32,
+        dir: pio_xfer_dir,
+        buf_size: u32,
+        buf_count: u32,
+    ) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let dir = Self::check_dir(dir)?;
+        if buf_size > 0xffff || buf_count > 0xffff {
+            let mut args = rp1_pio_sm_config_xfer32_args {
+                sm,
+                dir: dir as u32,
+                buf_size,
+                buf_count,
+            };
+            self.call(PIO_IOC_SM_CONFIG_XFER32, &mut args)
+        } else {
+            let mut args = rp1_pio_sm_config_xfer_args {
+                sm: sm as u16,
+                dir,
+                buf_size: buf_size as u16,
+                buf_count: buf_count as u16,
+            };
+            self.call(PIO_IOC_SM_CONFIG_XFER, &mut args)
+        }
+        .map(drop)
+    }
+
+    fn sm_xfer_data(&mut self, sm: u32, dir: pio_xfer_dir, data: &mut [u8]) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let dir = Self::check_dir(dir)?;
+        let data_bytes =
+            u32::try_from(data.len()).map_err(|_| PioError::InvalidParam { which: "data" })?;
+        let data = data.as_mut_ptr() as usize;
+        if data_bytes > 0xffff {
+            let mut args = rp1_pio_sm_xfer_data32_args {
+                sm: sm as u16,
+                dir,
+                data_bytes,
+                data,
+            };
+            self.call(PIO_IOC_SM_XFER_DATA32, &mut args)
+        } else {
+            let mut args = rp1_pio_sm_xfer_data_args {
+                sm: sm as u16,
+                dir,
+                data_bytes: data_bytes as u16,
+                rsvd: 0,
+                data,
+            };
+            self.call(PIO_IOC_SM_XFER_DATA, &mut args)
+        }
+        .map(drop)
+    }
+
+    fn can_add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<bool> {
+        let mut args = match Self::program_args(program, offset) {
+            Ok(args) => args,
+            Err(_) => return Ok(false),
+        };
+        self.call(PIO_IOC_CAN_ADD_PROGRAM, &mut args)
+            .map(|ret| ret > 0)
+    }
+
+    fn add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<u8> {
+        let mut args = Self::program_args(program, offset)?;
+        match self.call(PIO_IOC_ADD_PROGRAM, &mut args) {
+            Ok(offset) => Ok(offset as u8),
+            Err(PioError::Io(EBUSY | ENOMEM | ENOSPC)) => Err(PioError::NoProgramSpace),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn remove_program(&mut self, program: &pio_program, loaded_offset: u8) -> PioResult<()> {
+        let mut args = rp1_pio_remove_program_args {
+            num_instrs: program.length() as u16,
+            origin: loaded_offset as u16,
+        };
+        self.call(PIO_IOC_REMOVE_PROGRAM, &mut args).map(drop)
+    }
+
+    fn clear_instruction_memory(&mut self) -> PioResult<()> {
+        self.device
+            .ioctl(PIO_IOC_CLEAR_INSTR_MEM, &mut [])
+            .map(drop)
+            .map_err(io_error)
+    }
+
+    fn sm_claim_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mut args = rp1_pio_sm_claim_args {
+            mask: Self::check_sm_mask(mask)?,
+        };
+        match self.call(PIO_IOC_SM_CLAIM, &mut args) {
+            Err(PioError::Io(EBUSY)) => Err(PioError::SmAlreadyClaimed),
+            result => result.map(drop),
+        }
+    }
+
+    fn sm_unclaim(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_claim_args { mask: 1 << sm };
+        self.call(PIO_IOC_SM_UNCLAIM, &mut args).map(drop)
+    }
+
+    fn sm_is_claimed(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_claim_args { mask: 1 << sm };
+        self.call(PIO_IOC_SM_IS_CLAIMED, &mut args)
+            .map(|ret| ret > 0)
+    }
+
+    fn sm_init(&mut self, sm: u32, initial_pc: u32, config: &pio_sm_config) -> PioResult<()> {
+        self.check_sm(sm)?;
+        if initial_pc >= RP1_PIO_INSTRUCTION_COUNT as u32 {
+            return Err(PioError::InvalidParam {
+                which: "initial_pc",
+            });
+        }
+        let mut args = rp1_pio_sm_init_args {
+            sm: sm as u16,
+            initial_pc: initial_pc as u16,
+            config: config.into(),
+        };
+        self.call(PIO_IOC_SM_INIT, &mut args).map(drop)
+    }
+
+    fn sm_set_config(&mut self, sm: u32, config: &pio_sm_config) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_set_config_args {
+            sm: sm as u16,
+            rsvd: 0,
+            config: config.into(),
+        };
+        self.call(PIO_IOC_SM_SET_CONFIG, &mut args).map(drop)
+    }
+
+    fn sm_exec(&mut self, sm: u32, instr: u16, blocking: bool) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_exec_args {
+            sm: sm as u16,
+            instr,
+            blocking: blocking as u8,
+            rsvd: 0,
+        };
+        self.call(PIO_IOC_SM_EXEC, &mut args).map(drop)
+    }
+
+    fn sm_clear_fifos(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_clear_fifos_args { sm: sm as u16 };
+        self.call(PIO_IOC_SM_CLEAR_FIFOS, &mut args).map(drop)
+    }
+
+    fn sm_set_clkdiv_int_frac(&mut self, sm: u32, div_int: u16, div_frac: u8) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_set_clkdiv_args {
+            sm: sm as u16,
+            div_int,
+            div_frac,
+            rsvd: 0,
+        };
+        self.call(PIO_IOC_SM_SET_CLKDIV, &mut args).map(drop)
+    }
+
+    fn sm_set_pins_with_mask(&mut self, sm: u32, pin_values: u32, pin_mask: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_set_pins_args {
+            sm: sm as u16,
+            rsvd: 0,
+            values: pin_values,
+            mask: pin_mask,
+        };
+        self.call(PIO_IOC_SM_SET_PINS, &mut args).map(drop)
+    }
+
+    fn sm_set_pindirs_with_mask(&mut self, sm: u32, pin_dirs: u32, pin_mask: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_set_pindirs_args {
+            sm: sm as u16,
+            rsvd: 0,
+            dirs: pin_dirs,
+            mask: pin_mask,
+        };
+        self.call(PIO_IOC_SM_SET_PINDIRS, &mut args).map(drop)
+    }
+
+    fn sm_set_enabled_mask(&mut self, mask: u32, enabled: bool) -> PioResult<()> {
+        let mut args = rp1_pio_sm_set_enabled_args {
+            mask: Self::check_sm_mask(mask)?,
+            enable: enabled as u8,
+            rsvd: 0,
+        };
+        self.call(PIO_IOC_SM_SET_ENABLED, &mut args).map(drop)
+    }
+
+    fn sm_restart_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mut args = rp1_pio_sm_restart_args {
+            mask: Self::check_sm_mask(mask)?,
+        };
+        self.call(PIO_IOC_SM_RESTART, &mut args).map(drop)
+    }
+
+    fn sm_clkdiv_restart_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mut args = rp1_pio_sm_restart_args {
+            mask: Self::check_sm_mask(mask)?,
+        };
+        self.call(PIO_IOC_SM_CLKDIV_RESTART, &mut args).map(drop)
+    }
+
+    fn sm_enable_sync(&mut self, mask: u32) -> PioResult<()> {
+        let mut args = rp1_pio_sm_enable_sync_args {
+            mask: Self::check_sm_mask(mask)?,
+        };
+        self.call(PIO_IOC_SM_ENABLE_SYNC, &mut args).map(drop)
+    }
+
+    fn sm_put(&mut self, sm: u32, data: u32, blocking: bool) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_put_args {
+            sm: sm as u16,
+            blocking: blocking as u8,
+            rsvd: 0,
+            data,
+        };
+        self.call(PIO_IOC_SM_PUT, &mut args).map(drop)
+    }
+
+    fn sm_get(&mut self, sm: u32, blocking: bool) -> PioResult<u32> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_get_args {
+            sm: sm as u16,
+            blocking: blocking as u8,
+            rsvd: 0,
+            data: 0,
+        };
+        self.call(PIO_IOC_SM_GET, &mut args)?;
+        Ok(args.data)
+    }
+
+    fn sm_set_dmactrl(&mut self, sm: u32, is_tx: bool, ctrl: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_set_dmactrl_args {
+            sm: sm as u16,
+            is_tx: is_tx as u8,
+            rsvd: 0,
+            ctrl,
+        };
+        self.call(PIO_IOC_SM_SET_DMACTRL, &mut args).map(drop)
+    }
+
+    fn sm_is_rx_fifo_empty(&mut self, sm: u32) -> PioResult<bool> {
+        self.fifo_state(sm, false).map(|state| state.empty != 0)
+    }
+
+    fn sm_is_rx_fifo_full(&mut self, sm: u32) -> PioResult<bool> {
+        self.fifo_state(sm, false).map(|state| state.full != 0)
+    }
+
+    fn sm_get_rx_fifo_level(&mut self, sm: u32) -> PioResult<u32> {
+        self.fifo_state(sm, false).map(|state| state.level as u32)
+    }
+
+    fn sm_is_tx_fifo_empty(&mut self, sm: u32) -> PioResult<bool> {
+        self.fifo_state(sm, true).map(|state| state.empty != 0)
+    }
+
+    fn sm_is_tx_fifo_full(&mut self, sm: u32) -> PioResult<bool> {
+        self.fifo_state(sm, true).map(|state| state.full != 0)
+    }
+
+    fn sm_get_tx_fifo_level(&mut self, sm: u32) -> PioResult<u32> {
+        self.fifo_state(sm, true).map(|state| state.level as u32)
+    }
+
+    fn sm_drain_tx_fifo(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let mut args = rp1_pio_sm_clear_fifos_args { sm: sm as u16 };
+        self.call(PIO_IOC_SM_DRAIN_TX, &mut args).map(drop)
+    }
+
+    fn clock_get_hz(&mut self, clk_index: clock_index) -> PioResult<u32> {
+        match clk_index {
+            clock_index::clk_sys => Ok(200_000_000),
+            _ => Err(PioError::InvalidParam { which: "clk_index" }),
+        }
+    }
+
+    fn pio_gpio_init(&mut self, pin: u32) -> PioResult<()> {
+        let mut args = rp1_gpio_set_function_args {
+            gpio: Self::check_gpio(pin)?,
+            func: RP1_GPIO_FUNC_PIO,
+        };
+        self.call(PIO_IOC_GPIO_SET_FUNCTION, &mut args).map(drop)
+    }
+
+    fn gpio_init(&mut self, gpio: u32) -> PioResult<()> {
+        let mut args = rp1_gpio_init_args {
+            gpio: Self::check_gpio(gpio)?,
+        };
+        self.call(PIO_IOC_GPIO_INIT, &mut args).map(drop)
+    }
+
+    fn gpio_set_function(&mut self, gpio: u32, func: gpio_function) -> PioResult<()> {
+        let mut args = rp1_gpio_set_function_args {
+            gpio: Self::check_gpio(gpio)?,
+            func: func as u16,
+        };
+        self.call(PIO_IOC_GPIO_SET_FUNCTION, &mut args).map(drop)
+    }
+
+    fn gpio_set_pulls(&mut self, gpio: u32, up: bool, down: bool) -> PioResult<()> {
+        let mut args = rp1_gpio_set_pulls_args {
+            gpio: Self::check_gpio(gpio)?,
+            up: up as u8,
+            down: down as u8,
+        };
+        self.call(PIO_IOC_GPIO_SET_PULLS, &mut args).map(drop)
+    }
+
+    fn gpio_set_outover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()> {
+        self.gpio_set(PIO_IOC_GPIO_SET_OUTOVER, gpio, value as u16)
+    }
+
+    fn gpio_set_inover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()> {
+        self.gpio_set(PIO_IOC_GPIO_SET_INOVER, gpio, value as u16)
+    }
+
+    fn gpio_set_oeover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()> {
+        self.gpio_set(PIO_IOC_GPIO_SET_OEOVER, gpio, value as u16)
+    }
+
+    fn gpio_set_input_enabled(&mut self, gpio: u32, enabled: bool) -> PioResult<()> {
+        self.gpio_set(PIO_IOC_GPIO_SET_INPUT_ENABLED, gpio, enabled as u16)
+    }
+
+    fn gpio_set_drive_strength(&mut self, gpio: u32, drive: gpio_drive_strength) -> PioResult<()> {
+        self.gpio_set(PIO_IOC_GPIO_SET_DRIVE_STRENGTH, gpio, drive as u16)
+    }
+}
@@ -0,0 +1,1323 @@
+//! Software model of a PIO block, for running PIO programs without the hardware
+//!
+//! Every call to [`PioSim::step`] is one `clk_sys` cycle: each enabled state machine whose clock
+//! divider fires that cycle, in order SM0 to SM3, finishes a delay cycle or issues the
+//! instruction at its PC (or one forced with
+//! `sm_exec`, `OUT EXEC` or `MOV EXEC`). Instructions that cannot complete stall and are issued
+//! again the next cycle; side-set is applied when an instruction is first issued, and takes
+//! priority over `OUT`/`SET` to the same pins. A state machine numbered higher wins when two
+//! drive the same pin. All of them read the pins and IRQ flags as they were at the start of the
+//! cycle, so writes to either are seen by every state machine on the next cycle.
+//!
+//! The dividers work as on hardware: a state machine runs every INT cycles, plus one more
+//! whenever adding FRAC/256 to its fractional accumulator carries, so a divider of 2.5 runs it
+//! after 2, 3, 2, 3... cycles. An INT of 0 divides by 65536. CLKDIV_RESTART
+//! (`sm_clkdiv_restart`, `sm_enable_sync`) clears the accumulators so that state machines
+//! restarted together run in step.
+//!
+//! SHIFTCTRL is honoured: the shift directions, and autopush/autopull at PUSH_THRESH and
+//! PULL_THRESH (0 meaning 32). An `IN` that fills the ISR stalls while the RX FIFO is full, and
+//! an `OUT` from an empty OSR stalls until it can be refilled from the TX FIFO; the OSR is
+//! otherwise refilled after the `OUT` that empties it. A stall on a full or empty FIFO sets the
+//! matching FDEBUG bit. FJOIN gives one FIFO both FIFOs' entries, so on the RP1's depth of 8 a
+//! joined FIFO holds 16 and its level needs the FLEVEL2 bit; changing FJOIN empties the FIFOs.
+//!
+//! PIO drives and reads the pins through the pad model in [`super::pio_sim_pads`], which applies
+//! the `gpio_*` settings and takes levels driven from outside, by [`PioSim::set_input_pins`] or a
+//! per-pin [`Stimulus`](super::pio_sim_pads::Stimulus). Pin bases wrap round after pin 31, so an
+//! `OUT` of 4 pins from OUT_BASE 30 drives pins 30, 31, 0 and 1. The input synchronisers are not
+//! modelled.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::include::hardware::clocks::clock_index;
+use crate::include::hardware::gpio::{gpio_drive_strength, gpio_function, gpio_override};
+use crate::include::hardware::pio_instructions::*;
+use crate::include::hardware::regs::proc_pio::*;
+use crate::include::piolib::{pio_program, pio_sm_config, pio_xfer_dir};
+
+use super::chip::{PioChip, PioChipInfo};
+use super::error::{PioError, PioResult};
+use super::instr_mem::PioInstrMem;
+use super::pio_rp1::RP1_PIO_CHIP_INFO;
+use super::pio_sim_pads::SimPads;
+use super::regs::*;
+
+pub const SIM_PIO_CHIP_INFO: PioChipInfo = PioChipInfo {
+    name: "sim",
+    compatible: "piolib-rs,pio-sim",
+    ..RP1_PIO_CHIP_INFO
+};
+
+/// Default frequency of the modelled `clk_sys`, matching what the RP1 backend reports
+pub const SIM_CLK_SYS_HZ: u32 = 200_000_000;
+
+/// Deepest FIFO [`PioSim::with_fifo_depth`] takes, so that a joined FIFO's level fits the 5 bits
+/// of FLEVEL and FLEVEL2
+pub const SIM_MAX_FIFO_DEPTH: usize = 15;
+
+/// Cycles a blocking operation runs the simulation for before giving up with `ETIMEDOUT`
+pub const SIM_BLOCKING_TIMEOUT_CYCLES: u64 = 1 << 20;
+
+const ETIMEDOUT: i32 = 110;
+
+const SM_COUNT: usize = PROC_PIO_SM_COUNT as usize;
+const INSTR_COUNT: usize = InstrMem::COUNT as usize;
+
+/// State of one simulated state machine
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PioSimSm {
+    clkdiv: SmClkdiv,
+    execctrl: SmExecCtrl,
+    shiftctrl: SmShiftCtrl,
+    pinctrl: SmPinCtrl,
+    dmactrl_tx: SmDmaCtrlTx,
+    dmactrl_rx: SmDmaCtrlRx,
+    pc: u8,
+    x: u32,
+    y: u32,
+    isr: u32,
+    isr_count: u8,
+    osr: u32,
+    osr_count: u8,
+    delay: u8,
+    /// instruction to issue instead of the one at PC
+    exec: Option<u16>,
+    /// the last issued instruction stalled and is issued again
+    stalled: bool,
+    /// an `IRQ WAIT` set this flag and is waiting for it to clear
+    irq_wait: Option<u8>,
+    /// `clk_sys` cycles until the clock divider next fires
+    clkdiv_wait: u32,
+    clkdiv_frac_acc: u8,
+    tx_fifo: VecDeque<u32>,
+    rx_fifo: VecDeque<u32>,
+}
+
+impl PioSimSm {
+    fn new() -> Self {
+        PioSimSm {
+            clkdiv: SmClkdiv::RESET,
+            execctrl: SmExecCtrl::RESET,
+            shiftctrl: SmShiftCtrl::RESET,
+            pinctrl: SmPinCtrl::RESET,
+            dmactrl_tx: SmDmaCtrlTx::RESET,
+            dmactrl_rx: SmDmaCtrlRx::RESET,
+            osr_count: 32,
+            ..Default::default()
+        }
+    }
+
+    pub fn pc(&self) -> u8 {
+        self.pc
+    }
+
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+
+    pub fn isr(&self) -> u32 {
+        self.isr
+    }
+
+    /// Bits shifted into the ISR since it was last emptied
+    pub fn isr_shift_count(&self) -> u8 {
+        self.isr_count
+    }
+
+    pub fn osr(&self) -> u32 {
+        self.osr
+    }
+
+    /// Bits shifted out of the OSR since it was last filled; 32 when empty
+    pub fn osr_shift_count(&self) -> u8 {
+        self.osr_count
+    }
+
+    /// Whether the current instruction has stalled, the EXECCTRL_EXEC_STALLED status
+    pub fn is_stalled(&self) -> bool {
+        self.stalled
+    }
+
+    pub fn tx_fifo(&self) -> &VecDeque<u32> {
+        &self.tx_fifo
+    }
+
+    pub fn rx_fifo(&self) -> &VecDeque<u32> {
+        &self.rx_fifo
+    }
+
+    pub fn clkdiv(&self) -> SmClkdiv {
+        self.clkdiv
+    }
+
+    /// EXECCTRL as read back, including EXEC_STALLED
+    pub fn execctrl(&self) -> SmExecCtrl {
+        let stalled = (self.stalled as u32) << PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_LSB;
+        SmExecCtrl(self.execctrl.bits() | stalled)
+    }
+
+    pub fn shiftctrl(&self) -> SmShiftCtrl {
+        self.shiftctrl
+    }
+
+    pub fn pinctrl(&self) -> SmPinCtrl {
+        self.pinctrl
+    }
+
+    pub fn dmactrl(&self, is_tx: bool) -> u32 {
+        if is_tx {
+            self.dmactrl_tx.bits()
+        } else {
+            self.dmactrl_rx.bits()
+        }
+    }
+
+    fn side_set(&self) -> PioSideSet {
+        let optional = self.execctrl.side_en();
+        PioSideSet {
+            bit_count: self.pinctrl.sideset_count().saturating_sub(optional as u8),
+            optional,
+            pindirs: self.execctrl.side_pindir(),
+        }
+    }
+
+    /// PUSH_THRESH, where 0 means 32
+    fn push_threshold(&self) -> u8 {
+        match self.shiftctrl.push_thresh() {
+            0 => 32,
+            thresh => thresh,
+        }
+    }
+
+    /// PULL_THRESH, where 0 means 32
+    fn pull_threshold(&self) -> u8 {
+        match self.shiftctrl.pull_thresh() {
+            0 => 32,
+            thresh => thresh,
+        }
+    }
+
+    /// TX and RX FIFO depths, a FIFO joined with FJOIN taking the other's entries
+    fn fifo_depths(&self, depth: usize) -> (usize, usize) {
+        if self.shiftctrl.fjoin_tx() {
+            (2 * depth, 0)
+        } else if self.shiftctrl.fjoin_rx() {
+            (0, 2 * depth)
+        } else {
+            (depth, depth)
+        }
+    }
+
+    /// Clear the internal state SM_RESTART clears, leaving PC, X, Y and the FIFOs
+    fn restart(&mut self) {
+        self.isr = 0;
+        self.isr_count = 0;
+        self.osr_count = 32;
+        self.delay = 0;
+        self.exec = None;
+        self.stalled = false;
+        self.irq_wait = None;
+    }
+
+    /// Advance the clock divider by one `clk_sys` cycle, returning whether the SM runs in it
+    fn clock_tick(&mut self) -> bool {
+        if self.clkdiv_wait > 0 {
+            self.clkdiv_wait -= 1;
+            return false;
+        }
+        let div_int = match self.clkdiv.int() {
+            0 => 0x10000,
+            div_int => div_int as u32,
+        };
+        let (acc, carry) = self.clkdiv_frac_acc.overflowing_add(self.clkdiv.frac());
+        self.clkdiv_frac_acc = acc;
+        self.clkdiv_wait = div_int - 1 + carry as u32;
+        true
+    }
+
+    fn clkdiv_restart(&mut self) {
+        self.clkdiv_wait = 0;
+        self.clkdiv_frac_acc = 0;
+    }
+
+    fn advance_pc(&mut self) {
+        self.pc = if self.pc == self.execctrl.wrap_top() {
+            self.execctrl.wrap_bottom()
+        } else {
+            (self.pc + 1) % INSTR_COUNT as u8
+        };
+    }
+
+    fn shift_in(&mut self, data: u32, bit_count: u8) {
+        let mask = low_bits(bit_count);
+        self.isr = if self.shiftctrl.in_shiftdir() {
+            ((self.isr as u64 >> bit_count) | ((data & mask) as u64) << (32 - bit_count)) as u32
+        } else {
+            ((self.isr as u64) << bit_count) as u32 | (data & mask)
+        };
+        self.isr_count = (self.isr_count + bit_count).min(32);
+    }
+
+    fn shift_out(&mut self, bit_count: u8) -> u32 {
+        let data;
+        if self.shiftctrl.out_shiftdir() {
+            data = self.osr & low_bits(bit_count);
+            self.osr = (self.osr as u64 >> bit_count) as u32;
+        } else {
+            data = (self.osr as u64 >> (32 - bit_count)) as u32;
+            self.osr = ((self.osr as u64) << bit_count) as u32;
+        }
+        self.osr_count = (self.osr_count + bit_count).min(32);
+        data
+    }
+}
+
+fn low_bits(count: u8) -> u32 {
+    ((1u64 << count) - 1) as u32
+}
+
+/// Mask of `count` pins from `base`, wrapping round after pin 31
+fn pin_mask(base: u8, count: u8) -> u32 {
+    low_bits(count.min(32)).rotate_left(base as u32)
+}
+
+/// Result of issuing an instruction
+enum Issue {
+    /// completed, moving on to the next instruction
+    Next,
+    /// completed having set the PC itself
+    Jumped,
+    /// completed having forced the next instruction, so its delay is ignored
+    Forced,
+    /// could not complete, issue again next cycle
+    Stall,
+}
+
+/// A simulated PIO block, usable wherever a hardware backend is
+pub struct PioSim {
+    info: PioChipInfo,
+    fifo_depth: usize,
+    instr_mem: [u16; INSTR_COUNT],
+    allocation: PioInstrMem,
+    claimed: u32,
+    enabled: u32,
+    sms: [PioSimSm; SM_COUNT],
+    irq: u8,
+    fdebug: Fdebug,
+    pad_out: u32,
+    pad_oe: u32,
+    pads: SimPads,
+    /// pin levels and IRQ flags as every SM sees them this cycle, whatever the others write
+    sampled_levels: u32,
+    sampled_irq: u8,
+    cycle: u64,
+    clk_sys_hz: u32,
+}
+
+impl Default for PioSim {
+    fn default() -> Self {
+        PioSim::new()
+    }
+}
+
+impl PioSim {
+    /// A block with the RP1's FIFO depth
+    pub fn new() -> Self {
+        PioSim::with_fifo_depth(RP1_PIO_CHIP_INFO.fifo_depth as usize)
+            .expect("RP1 FIFO depth is in range")
+    }
+
+    /// A block whose FIFOs hold `fifo_depth` entries, from 1 to [`SIM_MAX_FIFO_DEPTH`]
+    pub fn with_fifo_depth(fifo_depth: usize) -> PioResult<Self> {
+        if !(1..=SIM_MAX_FIFO_DEPTH).contains(&fifo_depth) {
+            return Err(PioError::InvalidParam {
+                which: "fifo_depth",
+            });
+        }
+        Ok(PioSim {
+            info: PioChipInfo {
+                fifo_depth: fifo_depth as u16,
+                ..SIM_PIO_CHIP_INFO
+            },
+            fifo_depth,
+            instr_mem: [0; INSTR_COUNT],
+            allocation: PioInstrMem::new(),
+            claimed: 0,
+            enabled: 0,
+            sms: std::array::from_fn(|_| PioSimSm::new()),
+            irq: 0,
+            fdebug: Fdebug::default(),
+            pad_out: 0,
+            pad_oe: 0,
+            pads: SimPads::new(),
+            sampled_levels: 0,
+            sampled_irq: 0,
+            cycle: 0,
+            clk_sys_hz: SIM_CLK_SYS_HZ,
+        })
+    }
+
+    /// Cycles run since the block was created
+    pub fn cycle(&self) -> u64 {
+        self.cycle
+    }
+
+    /// Frequency of the modelled `clk_sys`, which `clock_get_hz` reports
+    pub fn clk_sys_hz(&self) -> u32 {
+        self.clk_sys_hz
+    }
+
+    /// Set the modelled `clk_sys` frequency, which must not be 0
+    pub fn set_clk_sys_hz(&mut self, hz: u32) -> PioResult<()> {
+        if hz == 0 {
+            return Err(PioError::InvalidParam { which: "hz" });
+        }
+        self.clk_sys_hz = hz;
+        Ok(())
+    }
+
+    /// Time the cycles run so far take at the modelled `clk_sys`
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos((self.cycle as u128 * 1_000_000_000 / self.clk_sys_hz as u128) as u64)
+    }
+
+    pub fn sm(&self, sm: u32) -> &PioSimSm {
+        &self.sms[sm as usize]
+    }
+
+    pub fn instr_mem(&self) -> &[u16; INSTR_COUNT] {
+        &self.instr_mem
+    }
+
+    pub fn irq_flags(&self) -> u8 {
+        self.irq
+    }
+
+    /// Set or clear IRQ flags from the system side, like IRQ_FORCE and IRQ
+    pub fn set_irq_flags(&mut self, flags: u8, set: bool) {
+        if set {
+            self.irq |= flags;
+        } else {
+            self.irq &= !flags;
+        }
+    }
+
+    /// FSTAT as read back, with the FIFO depths FJOIN gives
+    pub fn fstat(&self) -> Fstat {
+        let mut fstat = 0;
+        for (sm, state) in self.sms.iter().enumerate() {
+            let flag = |set: bool, lsb: u32| (set as u32) << (lsb + sm as u32);
+            fstat |= flag(state.tx_fifo.is_empty(), PROC_PIO_FSTAT_TXEMPTY_LSB)
+                | flag(
+                    state.tx_fifo.len() >= self.tx_depth(sm),
+                    PROC_PIO_FSTAT_TXFULL_LSB,
+                )
+                | flag(state.rx_fifo.is_empty(), PROC_PIO_FSTAT_RXEMPTY_LSB)
+                | flag(
+                    state.rx_fifo.len() >= self.rx_depth(sm),
+                    PROC_PIO_FSTAT_RXFULL_LSB,
+                );
+        }
+        Fstat(fstat)
+    }
+
+    /// FLEVEL as read back, the low four bits of each FIFO level
+    pub fn flevel(&self) -> Flevel {
+        Flevel(self.level_bits(0, 0xf))
+    }
+
+    /// FLEVEL2 as read back, bit 4 of each FIFO level
+    pub fn flevel2(&self) -> Flevel2 {
+        Flevel2(self.level_bits(4, 0x1))
+    }
+
+    /// Bits `shift..` of the FIFO levels, masked with `mask`, packed in FLEVEL's layout
+    fn level_bits(&self, shift: u32, mask: u32) -> u32 {
+        let stride = PROC_PIO_FLEVEL_TX1_LSB - PROC_PIO_FLEVEL_TX0_LSB;
+        let mut bits = 0;
+        for (sm, state) in self.sms.iter().enumerate() {
+            let level =
+                |len: usize, lsb: u32| ((len as u32 >> shift) & mask) << (lsb + stride * sm as u32);
+            bits |= level(state.tx_fifo.len(), PROC_PIO_FLEVEL_TX0_LSB)
+                | level(state.rx_fifo.len(), PROC_PIO_FLEVEL_RX0_LSB);
+        }
+        bits
+    }
+
+    /// FDEBUG as read back
+    pub fn fdebug(&self) -> Fdebug {
+        self.fdebug
+    }
+
+    pub fn clear_fdebug(&mut self, fdebug: Fdebug) {
+        self.fdebug.0 &= !fdebug.bits();
+    }
+
+    /// Values PIO drives on the pins, the DBG_PADOUT register
+    pub fn pad_out(&self) -> u32 {
+        self.pad_out
+    }
+
+    /// Pins PIO drives, the DBG_PADOE register
+    pub fn pad_oe(&self) -> u32 {
+        self.pad_oe
+    }
+
+    /// The GPIO pads, for setting them up and driving their pins from outside
+    pub fn pads(&self) -> &SimPads {
+        &self.pads
+    }
+
+    pub fn pads_mut(&mut self) -> &mut SimPads {
+        &mut self.pads
+    }
+
+    /// Drive every pin from outside with `values`, seen where the pads do not drive
+    pub fn set_input_pins(&mut self, values: u32) {
+        self.pads.drive_all(values);
+    }
+
+    /// Levels on the pins, as seen from outside
+    pub fn pad_levels(&self) -> u32 {
+        self.pads.levels(self.pad_out, self.pad_oe)
+    }
+
+    /// Levels on the pins as PIO reads them
+    pub fn pin_levels(&self) -> u32 {
+        self.pads.inputs(self.pad_out, self.pad_oe)
+    }
+
+    /// Run one cycle, first driving the pins with their stimuli's levels for it
+    ///
+    /// Every SM sees the pins and IRQ flags as they were at the start of the cycle, so what one
+    /// writes is seen by the others on the next cycle whichever SM numbers they have.
+    pub fn step(&mut self) {
+        self.pads.apply_stimuli(self.cycle);
+        self.sample();
+        for sm in 0..SM_COUNT {
+            if self.enabled & (1 << sm) != 0 && self.sms[sm].clock_tick() {
+                self.step_sm(sm);
+            }
+        }
+        self.pads.latch(self.pad_out, self.pad_oe);
+        self.cycle += 1;
+    }
+
+    pub fn run(&mut self, cycles: u64) {
+        for _ in 0..cycles {
+            self.step();
+        }
+    }
+
+    /// Run until `done` holds, for at most `max_cycles`; returns whether it did
+    pub fn run_until(&mut self, max_cycles: u64, mut done: impl FnMut(&PioSim) -> bool) -> bool {
+        for _ in 0..max_cycles {
+            if done(self) {
+                return true;
+            }
+            self.step();
+        }
+        done(self)
+    }
+
+    fn run_blocking(&mut self, mut done: impl FnMut(&PioSim) -> bool) -> PioResult<()> {
+        if self.run_until(SIM_BLOCKING_TIMEOUT_CYCLES, &mut done) {
+            Ok(())
+        } else {
+            Err(PioError::Io(ETIMEDOUT))
+        }
+    }
+
+    fn sample(&mut self) {
+        self.sampled_levels = self.pin_levels();
+        self.sampled_irq = self.irq;
+    }
+
+    fn step_sm(&mut self, sm: usize) {
+        let state = &mut self.sms[sm];
+        if state.delay > 0 {
+            state.delay -= 1;
+            return;
+        }
+        self.issue(sm);
+    }
+
+    fn issue(&mut self, sm: usize) {
+        let exec = self.sms[sm].exec.take();
+        let instr = exec.unwrap_or(self.instr_mem[self.sms[sm].pc as usize]);
+        let forced = exec.is_some();
+        let state = &self.sms[sm];
+        let Some(word) = PioInstructionWord::decode(instr) else {
+            // reserved encodings do nothing
+            self.complete(sm, forced, Issue::Next, 0);
+            return;
+        };
+        let side_set = state.side_set();
+        let first_issue = !state.stalled;
+        let result = self.execute(sm, word.instruction);
+        if first_issue {
+            if let Some(value) = word.side_set(&side_set) {
+                let base = self.sms[sm].pinctrl.sideset_base();
+                self.write_pins(base, side_set.bit_count, value as u32, side_set.pindirs);
+            }
+        }
+        match result {
+            Issue::Stall => {
+                let state = &mut self.sms[sm];
+                state.stalled = true;
+                state.exec = exec.or(state.exec);
+            }
+            result => self.complete(sm, forced, result, word.delay(&side_set)),
+        }
+    }
+
+    fn complete(&mut self, sm: usize, forced: bool, result: Issue, delay: u8) {
+        let state = &mut self.sms[sm];
+        state.stalled = false;
+        match result {
+            Issue::Next => {
+                state.delay = delay;
+                if !forced {
+                    state.advance_pc();
+                }
+            }
+            Issue::Jumped => state.delay = delay,
+            Issue::Forced => {
+                if !forced {
+                    state.advance_pc();
+                }
+            }
+            Issue::Stall => unreachable!(),
+        }
+    }
+
+    /// Index of the IRQ flag an `IRQ` or `WAIT IRQ` index field refers to for `sm`
+    fn irq_index(sm: usize, index: u8) -> u8 {
+        if index & 0x10 != 0 {
+            (index & 0x4) | ((index + sm as u8) & 0x3)
+        } else {
+            index & 0x7
+        }
+    }
+
+    fn write_pins(&mut self, base: u8, count: u8, value: u32, pindirs: bool) {
+        let mask = pin_mask(base, count);
+        let value = value.rotate_left(base as u32) & mask;
+        let pins = if pindirs {
+            &mut self.pad_oe
+        } else {
+            &mut self.pad_out
+        };
+        *pins = (*pins & !mask) | value;
+    }
+
+    fn read_in_pins(&self, sm: usize) -> u32 {
+        self.sampled_levels
+            .rotate_right(self.sms[sm].pinctrl.in_base() as u32)
+    }
+
+    /// Push the ISR after an IN if autopush is on and the threshold is reached
+    fn autopush(&mut self, sm: usize) -> Issue {
+        let depth = self.rx_depth(sm);
+        let state = &mut self.sms[sm];
+        if !state.shiftctrl.autopush() || state.isr_count < state.push_threshold() {
+            return Issue::Next;
+        }
+        if state.rx_fifo.len() >= depth {
+            self.fdebug.0 |= (1 << PROC_PIO_FDEBUG_RXSTALL_LSB) << sm;
+            return Issue::Stall;
+        }
+        state.rx_fifo.push_back(state.isr);
+        state.isr = 0;
+        state.isr_count = 0;
+        Issue::Next
+    }
+
+    fn tx_depth(&self, sm: usize) -> usize {
+        self.sms[sm].fifo_depths(self.fifo_depth).0
+    }
+
+    fn rx_depth(&self, sm: usize) -> usize {
+        self.sms[sm].fifo_depths(self.fifo_depth).1
+    }
+
+    fn mov_status(&self, sm: usize) -> u32 {
+        let state = &self.sms[sm];
+        let level = if state.execctrl.status_sel() {
+            state.rx_fifo.len()
+        } else {
+            state.tx_fifo.len()
+        };
+        if level < state.execctrl.status_n() as usize {
+            !0
+        } else {
+            0
+        }
+    }
+
+    fn execute(&mut self, sm: usize, instruction: PioInstruction) -> Issue {
+        match instruction {
+            PioInstruction::Jmp { condition, address } => {
+                let levels = self.sampled_levels;
+                let state = &mut self.sms[sm];
+                let taken = match condition {
+                    PioJmpCondition::Always => true,
+                    PioJmpCondition::NotX => state.x == 0,
+                    PioJmpCondition::XDec => {
+                        let taken = state.x != 0;
+                        state.x = state.x.wrapping_sub(1);
+                        taken
+                    }
+                    PioJmpCondition::NotY => state.y == 0,
+                    PioJmpCondition::YDec => {
+                        let taken = state.y != 0;
+                        state.y = state.y.wrapping_sub(1);
+                        taken
+                    }
+                    PioJmpCondition::XNeY => state.x != state.y,
+                    PioJmpCondition::Pin => levels & (1 << state.execctrl.jmp_pin()) != 0,
+                    PioJmpCondition::NotOsre => state.osr_count < state.pull_threshold(),
+                };
+                if taken {
+                    state.pc = address;
+                    Issue::Jumped
+                } else {
+                    Issue::Next
+                }
+            }
+            PioInstruction::Wait {
+                polarity,
+                source,
+                index,
+            } => {
+                let met = match source {
+                    PioWaitSource::Gpio => self.sampled_levels & (1 << (index & 0x1f)) != 0,
+                    PioWaitSource::Pin => self.read_in_pins(sm) & (1 << (index & 0x1f)) != 0,
+                    PioWaitSource::Irq => {
+                        let flag = 1 << Self::irq_index(sm, index);
+                        let set = self.sampled_irq & flag != 0;
+                        if polarity && set {
+                            self.irq &= !flag;
+                        }
+                        set
+                    }
+                } == polarity;
+                if met {
+                    Issue::Next
+                } else {
+                    Issue::Stall
+                }
+            }
+            PioInstruction::In { source, bit_count } => {
+                // an IN stalled on autopush has shifted already and only retries the push
+                if !self.sms[sm].stalled {
+                    let data = match source {
+                        PioInSource::Pins => self.read_in_pins(sm),
+                        PioInSource::X => self.sms[sm].x,
+                        PioInSource::Y => self.sms[sm].y,
+                        PioInSource::Null => 0,
+                        PioInSource::Isr => self.sms[sm].isr,
+                        PioInSource::Osr => self.sms[sm].osr,
+                    };
+                    self.sms[sm].shift_in(data, bit_count);
+                }
+                self.autopush(sm)
+            }
+            PioInstruction::Out {
+                destination,
+                bit_count,
+            } => {
+                let state = &mut self.sms[sm];
+                if state.shiftctrl.autopull() && state.osr_count >= state.pull_threshold() {
+                    // an empty OSR is refilled while the OUT stalls, costing it one cycle
+                    match state.tx_fifo.pop_front() {
+                        Some(data) => {
+                            state.osr = data;
+                            state.osr_count = 0;
+                        }
+                        None => self.fdebug.0 |= (1 << PROC_PIO_FDEBUG_TXSTALL_LSB) << sm,
+                    }
+                    return Issue::Stall;
+                }
+                let data = state.shift_out(bit_count);
+                let pinctrl = state.pinctrl;
+                let result = match destination {
+                    PioOutDestination::Pins => {
+                        // zero-extended to all OUT_COUNT pins
+                        self.write_pins(pinctrl.out_base(), pinctrl.out_count(), data, false);
+                        Issue::Next
+                    }
+                    PioOutDestination::X => {
+                        state.x = data;
+                        Issue::Next
+                    }
+                    PioOutDestination::Y => {
+                        state.y = data;
+                        Issue::Next
+                    }
+                    PioOutDestination::Null => Issue::Next,
+                    PioOutDestination::Pindirs => {
+                        self.write_pins(pinctrl.out_base(), pinctrl.out_count(), data, true);
+                        Issue::Next
+                    }
+                    PioOutDestination::Pc => {
+                        state.pc = data as u8 % INSTR_COUNT as u8;
+                        Issue::Jumped
+                    }
+                    PioOutDestination::Isr => {
+                        state.isr = data;
+                        state.isr_count = bit_count;
+                        Issue::Next
+                    }
+                    PioOutDestination::Exec => {
+                        state.exec = Some(data as u16);
+                        Issue::Forced
+                    }
+                };
+                // the OUT that empties the OSR refills it in the same cycle if it can
+                let state = &mut self.sms[sm];
+                if state.shiftctrl.autopull() && state.osr_count >= state.pull_threshold() {
+                    if let Some(data) = state.tx_fifo.pop_front() {
+                        state.osr = data;
+                        state.osr_count = 0;
+                    }
+                }
+                result
+            }
+            PioInstruction::Push { if_full, block } => {
+                let depth = self.rx_depth(sm);
+                let state = &mut self.sms[sm];
+                if if_full && state.isr_count < state.push_threshold() {
+                    return Issue::Next;
+                }
+                if state.rx_fifo.len() >= depth {
+                    if block {
+                        self.fdebug.0 |= (1 << PROC_PIO_FDEBUG_RXSTALL_LSB) << sm;
+                        return Issue::Stall;
+                    }
+                } else {
+                    state.rx_fifo.push_back(state.isr);
+                }
+                state.isr = 0;
+                state.isr_count = 0;
+                Issue::Next
+            }
+            PioInstruction::Pull { if_empty, block } => {
+                let state = &mut self.sms[sm];
+                if if_empty && state.osr_count < state.pull_threshold() {
+                    return Issue::Next;
+                }
+                // with autopull on, a PULL of a full OSR does nothing
+                if state.shiftctrl.autopull() && state.osr_count == 0 {
+                    return Issue::Next;
+                }
+                match state.tx_fifo.pop_front() {
+                    Some(data) => state.osr = data,
+                    None if block => {
+                        self.fdebug.0 |= (1 << PROC_PIO_FDEBUG_TXSTALL_LSB) << sm;
+                        return Issue::Stall;
+                    }
+                    // a non-blocking PULL from an empty FIFO copies X instead
+                    None => state.osr = state.x,
+                }
+                state.osr_count = 0;
+                Issue::Next
+            }
+            PioInstruction::Mov {
+                destination,
+                op,
+                source,
+            } => {
+                let data = match source {
+                    PioMovSource::Pins => self.read_in_pins(sm),
+                    PioMovSource::X => self.sms[sm].x,
+                    PioMovSource::Y => self.sms[sm].y,
+                    PioMovSource::Null => 0,
+                    PioMovSource::Status => self.mov_status(sm),
+                    PioMovSource::Isr => self.sms[sm].isr,
+                    PioMovSource::Osr => self.sms[sm].osr,
+                };
+                let data = match op {
+                    PioMovOp::None => data,
+                    PioMovOp::Invert => !data,
+                    PioMovOp::BitReverse => data.reverse_bits(),
+                };
+                let pinctrl = self.sms[sm].pinctrl;
+                let state = &mut self.sms[sm];
+                match destination {
+                    PioMovDestination::Pins => {
+                        self.write_pins(pinctrl.out_base(), pinctrl.out_count(), data, false)
+                    }
+                    PioMovDestination::X => state.x = data,
+                    PioMovDestination::Y => state.y = data,
+                    PioMovDestination::Exec => {
+                        state.exec = Some(data as u16);
+                        return Issue::Forced;
+                    }
+                    PioMovDestination::Pc => {
+                        state.pc = data as u8 % INSTR_COUNT as u8;
+                        return Issue::Jumped;
+                    }
+                    PioMovDestination::Isr => {
+                        state.isr = data;
+                        state.isr_count = 0;
+                    }
+                    PioMovDestination::Osr => {
+                        state.osr = data;
+                        state.osr_count = 0;
+                    }
+                }
+                Issue::Next
+            }
+            PioInstruction::Irq { clear, wait, index } => {
+                let index = Self::irq_index(sm, index);
+                let flag = 1 << index;
+                let state = &mut self.sms[sm];
+                if state.irq_wait == Some(index) {
+                    if self.sampled_irq & flag != 0 {
+                        return Issue::Stall;
+                    }
+                    state.irq_wait = None;
+                    return Issue::Next;
+                }
+                if clear {
+                    self.irq &= !flag;
+                } else {
+                    self.irq |= flag;
+                    if wait {
+                        state.irq_wait = Some(index);
+                        return Issue::Stall;
+                    }
+                }
+                Issue::Next
+            }
+            PioInstruction::Set { destination, data } => {
+                let pinctrl = self.sms[sm].pinctrl;
+                let state = &mut self.sms[sm];
+                let data = data as u32;
+                match destination {
+                    PioSetDestination::Pins => {
+                        self.write_pins(pinctrl.set_base(), pinctrl.set_count(), data, false)
+                    }
+                    PioSetDestination::X => state.x = data,
+                    PioSetDestination::Y => state.y = data,
+                    PioSetDestination::Pindirs => {
+                        self.write_pins(pinctrl.set_base(), pinctrl.set_count(), data, true)
+                    }
+                }
+                Issue::Next
+            }
+        }
+    }
+
+    fn check_sm_mask(mask: u32) -> PioResult<u32> {
+        if mask < 1 << SM_COUNT {
+            Ok(mask)
+        } else {
+            Err(PioError::InvalidParam { which: "mask" })
+        }
+    }
+
+    fn check_gpio(gpio: u32) -> PioResult<()> {
+        if gpio < 32 {
+            Ok(())
+        } else {
+            Err(PioError::InvalidParam { which: "gpio" })
+        }
+    }
+}
+
+impl PioChip for PioSim {
+    fn info(&self) -> &PioChipInfo {
+        &self.info
+    }
+
+    /// There is no DMA here, so transfers are always done word by word
+    fn sm_config_xfer(
+        &mut self,
+        sm: u32,
+        dir: pio_xfer_dir,
+        _buf_size: u32,
+        _buf_count: u32,
+    ) -> PioResult<()> {
+        self.check_sm(sm)?;
+        match dir {
+            pio_xfer_dir::PIO_DIR_COUNT => Err(PioError::InvalidParam { which: "dir" }),
+            _ => Ok(()),
+        }
+    }
+
+    fn sm_xfer_data(&mut self, sm: u32, dir: pio_xfer_dir, data: &mut [u8]) -> PioResult<()> {
+        self.check_sm(sm)?;
+        if !data.len().is_multiple_of(4) {
+            return Err(PioError::InvalidParam { which: "data" });
+        }
+        for word in data.chunks_exact_mut(4) {
+            match dir {
+                pio_xfer_dir::PIO_DIR_TO_SM => {
+                    self.sm_put(sm, u32::from_le_bytes(word.try_into().unwrap()), true)?
+                }
+                pio_xfer_dir::PIO_DIR_FROM_SM => {
+                    word.copy_from_slice(&self.sm_get(sm, true)?.to_le_bytes())
+                }
+                pio_xfer_dir::PIO_DIR_COUNT => return Err(PioError::InvalidParam { which: "dir" }),
+            }
+        }
+        Ok(())
+    }
+
+    fn can_add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<bool> {
+        Ok(self.allocation.can_add_program_at_offset(program, offset))
+    }
+
+    fn add_program_at_offset(
+        &mut self,
+        program: &pio_program,
+        offset: Option<u8>,
+    ) -> PioResult<u8> {
+        program.check(offset)?;
+        let mut allocation = self.allocation;
+        let offset = allocation
+            .add_program_at_offset(program, offset)
+            .ok_or(PioError::NoProgramSpace)?;
+        let image = program.relocated(offset)?;
+        self.instr_mem[offset as usize..][..image.len()].copy_from_slice(&image);
+        self.allocation = allocation;
+        Ok(offset)
+    }
+
+    fn remove_program(&mut self, program: &pio_program, loaded_offset: u8) -> PioResult<()> {
+        if self.allocation.remove_program(program, loaded_offset) {
+            Ok(())
+        } else {
+            Err(PioError::InvalidParam {
+                which: "loaded_offset",
+            })
+        }
+    }
+
+    fn clear_instruction_memory(&mut self) -> PioResult<()> {
+        self.allocation.clear_instruction_memory();
+        Ok(())
+    }
+
+    fn sm_claim_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        if self.claimed & mask != 0 {
+            return Err(PioError::SmAlreadyClaimed);
+        }
+        self.claimed |= mask;
+        Ok(())
+    }
+
+    fn sm_unclaim(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.claimed &= !(1 << sm);
+        Ok(())
+    }
+
+    fn sm_is_claimed(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.claimed & (1 << sm) != 0)
+    }
+
+    fn sm_init(&mut self, sm: u32, initial_pc: u32, config: &pio_sm_config) -> PioResult<()> {
+        self.check_sm(sm)?;
+        if initial_pc >= INSTR_COUNT as u32 {
+            return Err(PioError::InvalidParam {
+                which: "initial_pc",
+            });
+        }
+        self.sm_set_enabled(sm, false)?;
+        self.sm_set_config(sm, config)?;
+        self.sm_clear_fifos(sm)?;
+        let mut fdebug = Fdebug::default();
+        fdebug
+            .clear_tx_stall(sm)
+            .clear_tx_over(sm)
+            .clear_rx_under(sm)
+            .clear_rx_stall(sm);
+        self.clear_fdebug(fdebug);
+        self.sm_restart(sm)?;
+        self.sm_clkdiv_restart(sm)?;
+        self.sm_exec(sm, pio_encode_jmp(initial_pc) as u16, false)
+    }
+
+    /// SIDESET_COUNT must be at most 5, and cover the enable bit when SIDE_EN is set
+    fn sm_set_config(&mut self, sm: u32, config: &pio_sm_config) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let sideset_count = SmPinCtrl(config.pinctrl()).sideset_count();
+        let side_en = SmExecCtrl(config.execctrl()).side_en();
+        if sideset_count > 5 || (side_en && sideset_count == 0) {
+            return Err(PioError::InvalidParam { which: "config" });
+        }
+        let state = &mut self.sms[sm as usize];
+        let shiftctrl = SmShiftCtrl(config.shiftctrl());
+        // changing FJOIN empties both FIFOs
+        let fjoin = |ctrl: SmShiftCtrl| (ctrl.fjoin_tx(), ctrl.fjoin_rx());
+        if fjoin(shiftctrl) != fjoin(state.shiftctrl) {
+            state.tx_fifo.clear();
+            state.rx_fifo.clear();
+        }
+        state.clkdiv = SmClkdiv(config.clkdiv());
+        state.execctrl = SmExecCtrl(config.execctrl() & !PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_BITS);
+        state.shiftctrl = shiftctrl;
+        state.pinctrl = SmPinCtrl(config.pinctrl());
+        Ok(())
+    }
+
+    /// The instruction is issued on the next cycle, or straight away if the SM is disabled; a
+    /// blocking exec on a disabled SM issues it again until it completes, without running the
+    /// other SMs
+    fn sm_exec(&mut self, sm: u32, instr: u16, blocking: bool) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let index = sm as usize;
+        self.sms[index].exec = Some(instr);
+        self.sms[index].stalled = false;
+        if self.enabled & (1 << sm) == 0 {
+            let tries = if blocking {
+                SIM_BLOCKING_TIMEOUT_CYCLES
+            } else {
+                1
+            };
+            for _ in 0..tries {
+                self.sample();
+                self.issue(index);
+                if self.sms[index].exec.is_none() {
+                    return Ok(());
+                }
+            }
+            return if blocking {
+                Err(PioError::Io(ETIMEDOUT))
+            } else {
+                Ok(())
+            };
+        }
+        if blocking {
+            self.run_blocking(|sim| sim.sms[index].exec.is_none())?;
+        }
+        Ok(())
+    }
+
+    fn sm_clear_fifos(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let state = &mut self.sms[sm as usize];
+        state.tx_fifo.clear();
+        state.rx_fifo.clear();
+        Ok(())
+    }
+
+    fn sm_set_clkdiv_int_frac(&mut self, sm: u32, div_int: u16, div_frac: u8) -> PioResult<()> {
+        self.check_sm(sm)?;
+        if div_int == 0 && div_frac != 0 {
+            return Err(PioError::InvalidParam { which: "div_frac" });
+        }
+        let mut clkdiv = SmClkdiv::default();
+        clkdiv.set_int(div_int).set_frac(div_frac);
+        self.sms[sm as usize].clkdiv = clkdiv;
+        Ok(())
+    }
+
+    fn sm_set_pins_with_mask(&mut self, sm: u32, pin_values: u32, pin_mask: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.pad_out = (self.pad_out & !pin_mask) | (pin_values & pin_mask);
+        Ok(())
+    }
+
+    fn sm_set_pindirs_with_mask(&mut self, sm: u32, pin_dirs: u32, pin_mask: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        self.pad_oe = (self.pad_oe & !pin_mask) | (pin_dirs & pin_mask);
+        Ok(())
+    }
+
+    fn sm_set_enabled_mask(&mut self, mask: u32, enabled: bool) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        if enabled {
+            self.enabled |= mask;
+        } else {
+            self.enabled &= !mask;
+        }
+        Ok(())
+    }
+
+    fn sm_restart_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        for sm in (0..SM_COUNT).filter(|sm| mask & (1 << sm) != 0) {
+            self.sms[sm].restart();
+        }
+        Ok(())
+    }
+
+    fn sm_clkdiv_restart_mask(&mut self, mask: u32) -> PioResult<()> {
+        let mask = Self::check_sm_mask(mask)?;
+        for sm in (0..SM_COUNT).filter(|sm| mask & (1 << sm) != 0) {
+            self.sms[sm].clkdiv_restart();
+        }
+        Ok(())
+    }
+
+    fn sm_enable_sync(&mut self, mask: u32) -> PioResult<()> {
+        self.sm_clkdiv_restart_mask(mask)?;
+        self.sm_set_enabled_mask(mask, true)
+    }
+
+    /// A blocking put runs the simulation until there is room; a non-blocking put to a full
+    /// FIFO is dropped and flagged in FDEBUG, as on hardware
+    fn sm_put(&mut self, sm: u32, data: u32, blocking: bool) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let index = sm as usize;
+        if blocking {
+            self.run_blocking(|sim| sim.sms[index].tx_fifo.len() < sim.tx_depth(index))?;
+        }
+        if self.sms[index].tx_fifo.len() < self.tx_depth(index) {
+            self.sms[index].tx_fifo.push_back(data);
+        } else {
+            self.fdebug.0 |= (1 << PROC_PIO_FDEBUG_TXOVER_LSB) << sm;
+        }
+        Ok(())
+    }
+
+    /// A blocking get runs the simulation until there is data; a non-blocking get from an empty
+    /// FIFO returns 0 and is flagged in FDEBUG, as on hardware
+    fn sm_get(&mut self, sm: u32, blocking: bool) -> PioResult<u32> {
+        self.check_sm(sm)?;
+        let index = sm as usize;
+        if blocking {
+            self.run_blocking(|sim| !sim.sms[index].rx_fifo.is_empty())?;
+        }
+        Ok(self.sms[index].rx_fifo.pop_front().unwrap_or_else(|| {
+            self.fdebug.0 |= (1 << PROC_PIO_FDEBUG_RXUNDER_LSB) << sm;
+            0
+        }))
+    }
+
+    fn sm_set_dmactrl(&mut self, sm: u32, is_tx: bool, ctrl: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let state = &mut self.sms[sm as usize];
+        if is_tx {
+            state.dmactrl_tx = SmDmaCtrlTx(ctrl);
+        } else {
+            state.dmactrl_rx = SmDmaCtrlRx(ctrl);
+        }
+        Ok(())
+    }
+
+    fn sm_is_rx_fifo_empty(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.sms[sm as usize].rx_fifo.is_empty())
+    }
+
+    fn sm_is_rx_fifo_full(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.sms[sm as usize].rx_fifo.len() >= self.rx_depth(sm as usize))
+    }
+
+    fn sm_get_rx_fifo_level(&mut self, sm: u32) -> PioResult<u32> {
+        self.check_sm(sm)?;
+        Ok(self.sms[sm as usize].rx_fifo.len() as u32)
+    }
+
+    fn sm_is_tx_fifo_empty(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.sms[sm as usize].tx_fifo.is_empty())
+    }
+
+    fn sm_is_tx_fifo_full(&mut self, sm: u32) -> PioResult<bool> {
+        self.check_sm(sm)?;
+        Ok(self.sms[sm as usize].tx_fifo.len() >= self.tx_depth(sm as usize))
+    }
+
+    fn sm_get_tx_fifo_level(&mut self, sm: u32) -> PioResult<u32> {
+        self.check_sm(sm)?;
+        Ok(self.sms[sm as usize].tx_fifo.len() as u32)
+    }
+
+    fn sm_drain_tx_fifo(&mut self, sm: u32) -> PioResult<()> {
+        self.check_sm(sm)?;
+        let instr = if self.sms[sm as usize].shiftctrl.autopull() {
+            pio_encode_out(pio_src_dest::pio_null, 32)
+        } else {
+            pio_encode_pull(false, false)
+        };
+        // non-blocking execs as the SDK issues them, with a cycle passing for each on an enabled SM
+        for _ in 0..SIM_BLOCKING_TIMEOUT_CYCLES {
+            if self.sms[sm as usize].tx_fifo.is_empty() {
+                return Ok(());
+            }
+            self.sm_exec(sm, instr as u16, false)?;
+            if self.enabled & (1 << sm) != 0 {
+                self.step();
+            }
+        }
+        Err(PioError::Io(ETIMEDOUT))
+    }
+
+    fn clock_get_hz(&mut self, clk_index: clock_index) -> PioResult<u32> {
+        match clk_index {
+            clock_index::clk_sys => Ok(self.clk_sys_hz),
+            _ => Err(PioError::InvalidParam { which: "clk_index" }),
+        }
+    }
+
+    fn pio_gpio_init(&mut self, pin: u32) -> PioResult<()> {
+        self.gpio_set_function(pin, gpio_function::GPIO_FUNC_PIO0)
+    }
+
+    /// Hands the pad to SIO, which is not modelled and leaves the pin undriven
+    fn gpio_init(&mut self, gpio: u32) -> PioResult<()> {
+        self.gpio_set_function(gpio, gpio_function::GPIO_FUNC_SIO)
+    }
+
+    fn gpio_set_function(&mut self, gpio: u32, func: gpio_function) -> PioResult<()> {
+        Self::check_gpio(gpio)?;
+        self.pads.config_mut(gpio)?.function = func;
+        Ok(())
+    }
+
+    fn gpio_set_pulls(&mut self, gpio: u32, up: bool, down: bool) -> PioResult<()> {
+        Self::check_gpio(gpio)?;
+        self.pads.hold(self.pad_out, self.pad_oe);
+        let config = self.pads.config_mut(gpio)?;
+        config.pull_up = up;
+        config.pull_down = down;
+        Ok(())
+    }
+
+    fn gpio_set_outover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()> {
+        Self::check_gpio(gpio)?;
+        self.pads.config_mut(gpio)?.out_over = value;
+        Ok(())
+    }
+
+    fn gpio_set_inover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()> {
+        Self::check_gpio(gpio)?;
+        self.pads.config_mut(gpio)?.in_over = value;
+        Ok(())
+    }
+
+    fn gpio_set_oeover(&mut self, gpio: u32, value: gpio_override) -> PioResult<()> {
+        Self::check_gpio(gpio)?;
+        self.pads.config_mut(gpio)?.oe_over = value;
+        Ok(())
+    }
+
+    fn gpio_set_input_enabled(&mut self, gpio: u32, enabled: bool) -> PioResult<()> {
+        Self::check_gpio(gpio)?;
+        self.pads.config_mut(gpio)?.input_enabled = enabled;
+        Ok(())
+    }
+
+    fn gpio_set_drive_strength(&mut self, gpio: u32, _drive: gpio_drive_strength) -> PioResult<()> {
+        Self::check_gpio(gpio)
+    }
+}
@@ -0,0 +1,248 @@
+//! The GPIO pads around a simulated PIO block
+//!
+//! Each of the [`NUM_BANK0_GPIOS`] pads takes PIO's output and output enable when its function
+//! selects PIO, passes them through OUTOVER and OEOVER, and drives the pin while the output is
+//! enabled. A pin it does not drive takes the level driven from outside, then its pull-up or
+//! pull-down, and is otherwise low; with both pulls on, the bus keeper holds the last level. PIO
+//! reads the pin level through the input enable and INOVER.
+//!
+//! Levels driven from outside are set directly or by a [`Stimulus`], evaluated at the start of
+//! every cycle. When the pad and the outside both drive a pin, the pad wins.
+
+use std::fmt;
+
+use super::error::{PioError, PioResult};
+use crate::include::hardware::gpio::{gpio_function, gpio_override, NUM_BANK0_GPIOS};
+
+/// Settings of one pad, as the `gpio_*` calls leave them
+///
+/// Pads start out selected for PIO with no pulls or overrides, as after `pio_gpio_init`, so
+/// programs run without any GPIO setup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimPadConfig {
+    pub function: gpio_function,
+    pub pull_up: bool,
+    pub pull_down: bool,
+    pub input_enabled: bool,
+    pub out_over: gpio_override,
+    pub oe_over: gpio_override,
+    pub in_over: gpio_override,
+}
+
+impl Default for SimPadConfig {
+    fn default() -> Self {
+        SimPadConfig {
+            function: gpio_function::GPIO_FUNC_PIO0,
+            pull_up: false,
+            pull_down: false,
+            input_enabled: true,
+            out_over: gpio_override::GPIO_OVERRIDE_NORMAL,
+            oe_over: gpio_override::GPIO_OVERRIDE_NORMAL,
+            in_over: gpio_override::GPIO_OVERRIDE_NORMAL,
+        }
+    }
+}
+
+impl SimPadConfig {
+    /// Whether the function select connects the pad to PIO
+    pub fn is_pio(&self) -> bool {
+        matches!(
+            self.function,
+            gpio_function::GPIO_FUNC_PIO0 | gpio_function::GPIO_FUNC_PIO1
+        )
+    }
+}
+
+fn apply_override(over: gpio_override, signal: bool) -> bool {
+    match over {
+        gpio_override::GPIO_OVERRIDE_NORMAL => signal,
+        gpio_override::GPIO_OVERRIDE_INVERT => !signal,
+        gpio_override::GPIO_OVERRIDE_LOW => false,
+        gpio_override::GPIO_OVERRIDE_HIGH => true,
+    }
+}
+
+/// A waveform driven onto a pin from outside, given as the level for each cycle
+pub struct Stimulus(Box<dyn FnMut(u64) -> Option<bool> + Send>);
+
+impl Stimulus {
+    /// Drive the level `level_at` returns for each cycle, leaving the pin undriven for `None`
+    pub fn new(level_at: impl FnMut(u64) -> Option<bool> + Send + 'static) -> Self {
+        Stimulus(Box::new(level_at))
+    }
+
+    /// Start at `level` on cycle 0 and invert it every `period` cycles
+    pub fn toggle(period: u64, level: bool) -> Self {
+        let period = period.max(1);
+        Stimulus::new(move |cycle| Some(level ^ ((cycle / period) % 2 == 1)))
+    }
+
+    /// Hold each level from its cycle until the next, leaving the pin undriven before the first
+    pub fn edges(edges: &[(u64, bool)]) -> Self {
+        let mut edges = edges.to_vec();
+        edges.sort_by_key(|&(cycle, _)| cycle);
+        Stimulus::new(move |cycle| {
+            edges
+                .iter()
+                .take_while(|&&(at, _)| at <= cycle)
+                .last()
+                .map(|&(_, level)| level)
+        })
+    }
+}
+
+impl fmt::Debug for Stimulus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Stimulus")
+    }
+}
+
+/// The pads a simulated PIO block drives and reads, one bit per pin in each mask
+#[derive(Debug, Default)]
+pub struct SimPads {
+    config: [SimPadConfig; NUM_BANK0_GPIOS as usize],
+    driven: u32,
+    external: u32,
+    kept: u32,
+    stimuli: Vec<(u32, Stimulus)>,
+}
+
+impl SimPads {
+    pub fn new() -> Self {
+        SimPads::default()
+    }
+
+    pub fn config(&self, pin: u32) -> PioResult<&SimPadConfig> {
+        Self::check_pin(pin)?;
+        Ok(&self.config[pin as usize])
+    }
+
+    /// Turning on both pulls of a pad here starts its bus keeper from the level it had at the end
+    /// of the last cycle with a keeper on; `gpio_set_pulls` starts it from the current level
+    pub fn config_mut(&mut self, pin: u32) -> PioResult<&mut SimPadConfig> {
+        Self::check_pin(pin)?;
+        Ok(&mut self.config[pin as usize])
+    }
+
+    /// Drive `pin` to `level` from outside, or stop driving it with `None`
+    pub fn drive(&mut self, pin: u32, level: Option<bool>) -> PioResult<()> {
+        Self::check_pin(pin)?;
+        self.drive_pin(pin, level);
+        Ok(())
+    }
+
+    fn drive_pin(&mut self, pin: u32, level: Option<bool>) {
+        let bit = 1 << pin;
+        self.driven = (self.driven & !bit) | if level.is_some() { bit } else { 0 };
+        self.external = (self.external & !bit) | if level == Some(true) { bit } else { 0 };
+    }
+
+    /// Drive every pin from outside
+    pub fn drive_all(&mut self, levels: u32) {
+        self.driven = !0;
+        self.external = levels;
+    }
+
+    /// Pins driven from outside, and the levels they are driven to
+    pub fn external(&self) -> (u32, u32) {
+        (self.driven, self.external)
+    }
+
+    /// Drive `pin` with `stimulus` from the next cycle on, replacing any it had
+    pub fn set_stimulus(&mut self, pin: u32, stimulus: Stimulus) -> PioResult<()> {
+        self.clear_stimulus(pin)?;
+        self.stimuli.push((pin, stimulus));
+        Ok(())
+    }
+
+    /// Stop driving `pin` from its stimulus, leaving it at the last level driven
+    pub fn clear_stimulus(&mut self, pin: u32) -> PioResult<()> {
+        Self::check_pin(pin)?;
+        self.stimuli.retain(|&(stimulated, _)| stimulated != pin);
+        Ok(())
+    }
+
+    /// Drive the stimuli's levels for `cycle`
+    pub(crate) fn apply_stimuli(&mut self, cycle: u64) {
+        if self.stimuli.is_empty() {
+            return;
+        }
+        let levels: Vec<_> = self
+            .stimuli
+            .iter_mut()
+            .map(|(pin, stimulus)| (*pin, (stimulus.0)(cycle)))
+            .collect();
+        for (pin, level) in levels {
+            self.drive_pin(pin, level);
+        }
+    }
+
+    fn check_pin(pin: u32) -> PioResult<()> {
+        if pin < NUM_BANK0_GPIOS {
+            Ok(())
+        } else {
+            Err(PioError::InvalidParam { which: "gpio" })
+        }
+    }
+
+    fn per_pin(&self, pin_value: impl Fn(u32, &SimPadConfig) -> bool) -> u32 {
+        (0..NUM_BANK0_GPIOS)
+            .filter(|&pin| pin_value(pin, &self.config[pin as usize]))
+            .fold(0, |mask, pin| mask | 1 << pin)
+    }
+
+    /// Pins the pads drive, given PIO's output enables
+    pub fn output_enables(&self, pio_oe: u32) -> u32 {
+        self.per_pin(|pin, config| {
+            apply_override(config.oe_over, config.is_pio() && pio_oe & (1 << pin) != 0)
+        })
+    }
+
+    /// Levels the pads drive where enabled, given PIO's outputs
+    pub fn outputs(&self, pio_out: u32) -> u32 {
+        self.per_pin(|pin, config| {
+            apply_override(
+                config.out_over,
+                config.is_pio() && pio_out & (1 << pin) != 0,
+            )
+        })
+    }
+
+    /// Levels on the pins, given PIO's outputs and output enables
+    pub fn levels(&self, pio_out: u32, pio_oe: u32) -> u32 {
+        let oe = self.output_enables(pio_oe);
+        let undriven = !oe & !self.driven;
+        let pulled = self.per_pin(|pin, config| match (config.pull_up, config.pull_down) {
+            (true, false) => true,
+            (true, true) => self.kept & (1 << pin) != 0,
+            _ => false,
+        });
+        (self.outputs(pio_out) & oe) | (self.external & self.driven & !oe) | (pulled & undriven)
+    }
+
+    /// Levels PIO reads from the pins, given its outputs and output enables
+    pub fn inputs(&self, pio_out: u32, pio_oe: u32) -> u32 {
+        let levels = self.levels(pio_out, pio_oe);
+        self.per_pin(|pin, config| {
+            let level = config.input_enabled && levels & (1 << pin) != 0;
+            apply_override(config.in_over, level)
+        })
+    }
+
+    /// Let the bus keepers take the levels at the end of a cycle
+    pub(crate) fn latch(&mut self, pio_out: u32, pio_oe: u32) {
+        // skipped while no pin has a keeper; `hold` catches up before one is enabled
+        if self
+            .config
+            .iter()
+            .any(|config| config.pull_up && config.pull_down)
+        {
+            self.hold(pio_out, pio_oe);
+        }
+    }
+
+    /// Let the bus keepers take the current levels
+    pub(crate) fn hold(&mut self, pio_out: u32, pio_oe: u32) {
+        self.kept = self.levels(pio_out, pio_oe);
+    }
+}
@@ -0,0 +1,437 @@
+//! Typed views of the PROC_PIO registers described by `regs::proc_pio`
+//!
+//! Each register is a `u32` newtype and each field is read and written through its `*_BITS`
+//! mask. The accessors follow the field's access kind: RO fields only have a getter, SC and WO
+//! fields only a setter, RW fields both, and WC (write 1 to clear) fields a getter plus a
+//! `clear_*` method that sets the bits to write back. Registers repeated per state machine or
+//! per interrupt line take the index in `offset`, and fields repeated per state machine take it
+//! as their first argument.
+//!
+//! [`PROC_PIO_REGISTERS`] lists every register and field with its access kind and reset value.
+
+use std::str::FromStr;
+
+use super::error::PioError;
+pub use crate::include::hardware::regs::info::{Access, FieldInfo, RegisterInfo};
+use crate::include::hardware::regs::proc_pio::*;
+
+/// Number of state machines the per-SM registers and fields are indexed over
+pub const PROC_PIO_SM_COUNT: u32 = 4;
+
+/// Distance between the SMx_* register blocks of consecutive state machines
+pub const PROC_PIO_SM_STRIDE: u32 = PROC_PIO_SM1_CLKDIV_OFFSET - PROC_PIO_SM0_CLKDIV_OFFSET;
+
+/// Distance between the IRQ0_* and IRQ1_* interrupt registers
+pub const PROC_PIO_IRQ_STRIDE: u32 = PROC_PIO_IRQ1_INTE_OFFSET - PROC_PIO_IRQ0_INTE_OFFSET;
+
+impl FromStr for Access {
+    type Err = PioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "RO" => Access::RO,
+            "RW" => Access::RW,
+            "WO" => Access::WO,
+            "SC" => Access::SC,
+            "WC" => Access::WC,
+            "RF" => Access::RF,
+            "WF" => Access::WF,
+            _ => return Err(PioError::InvalidParam { which: "access" }),
+        })
+    }
+}
+
+/// Level of a state machine's TX or RX FIFO, combining FLEVEL with the FLEVEL2 bit that a joined
+/// FIFO deeper than 15 entries needs
+pub fn fifo_level(flevel: Flevel, flevel2: Flevel2, sm: u32, is_tx: bool) -> u32 {
+    if is_tx {
+        flevel.tx(sm) as u32 | (flevel2.tx(sm) as u32) << 4
+    } else {
+        flevel.rx(sm) as u32 | (flevel2.rx(sm) as u32) << 4
+    }
+}
+
+/// The register at `offset` from the PIO block base
+pub fn find_register(offset: u32) -> Option<&'static RegisterInfo> {
+    PROC_PIO_REGISTERS.iter().find(|reg| reg.offset == offset)
+}
+
+/// The register called `name`, with or without the `PROC_PIO_` prefix
+pub fn find_register_by_name(name: &str) -> Option<&'static RegisterInfo> {
+    let name = name.strip_prefix("PROC_PIO_").unwrap_or(name);
+    PROC_PIO_REGISTERS.iter().find(|reg| reg.name == name)
+}
+
+/// A type a register field can be read as and written from
+pub trait FieldValue: Copy {
+    fn from_field(field: u32) -> Self;
+    fn to_field(self) -> u32;
+}
+
+impl FieldValue for bool {
+    fn from_field(field: u32) -> Self {
+        field != 0
+    }
+    fn to_field(self) -> u32 {
+        self as u32
+    }
+}
+
+impl FieldValue for u8 {
+    fn from_field(field: u32) -> Self {
+        field as u8
+    }
+    fn to_field(self) -> u32 {
+        self as u32
+    }
+}
+
+impl FieldValue for u16 {
+    fn from_field(field: u32) -> Self {
+        field as u16
+    }
+    fn to_field(self) -> u32 {
+        self as u32
+    }
+}
+
+impl FieldValue for u32 {
+    fn from_field(field: u32) -> Self {
+        field
+    }
+    fn to_field(self) -> u32 {
+        self
+    }
+}
+
+fn get_field(value: u32, bits: u32) -> u32 {
+    (value & bits) >> bits.trailing_zeros()
+}
+
+fn set_field(value: &mut u32, bits: u32, field: u32) {
+    crate::valid_params_if!(PIO, field & !(bits >> bits.trailing_zeros()) == 0);
+    *value = (*value & !bits) | ((field << bits.trailing_zeros()) & bits);
+}
+
+/// Mask of state machine `sm`'s copy of a field whose SM0 copy is `bits`
+fn sm_bits(bits: u32, stride: u32, sm: u32) -> u32 {
+    crate::valid_params_if!(PIO, sm < PROC_PIO_SM_COUNT);
+    bits.checked_shl(stride * sm).unwrap_or(0)
+}
+
+macro_rules! pio_register {
+    (
+        $(#[$attr:meta])*
+        $name:ident @ $offset:expr $(, reset = $reset:expr)?;
+        $($fields:tt)*
+    ) => {
+        pio_register!(@struct $(#[$attr])* $name $(, $reset)?);
+        impl $name {
+            pub const OFFSET: u32 = $offset;
+            pio_register!(@fields $($fields)*);
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $name:ident [$index:ident < $count:expr] @ $base:expr, $stride:expr $(, reset = $reset:expr)?;
+        $($fields:tt)*
+    ) => {
+        pio_register!(@struct $(#[$attr])* $name $(, $reset)?);
+        impl $name {
+            pub const COUNT: u32 = $count;
+
+            pub fn offset($index: u32) -> u32 {
+                crate::valid_params_if!(PIO, $index < $count);
+                $base + $index * $stride
+            }
+            pio_register!(@fields $($fields)*);
+        }
+    };
+
+    (@struct $(#[$attr:meta])* $name:ident $(, $reset:expr)?) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name(pub u32);
+
+        impl $name {
+            $(pub const RESET: $name = $name($reset);)?
+
+            pub const fn bits(self) -> u32 {
+                self.0
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(value: u32) -> Self {
+                $name(value)
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+
+    (@fields) => {};
+    (@fields
+        $access:ident $first:ident $(, $second:ident)? $([$sm:ident; $sm_stride:expr])?
+            : $ty:ty = $bits:expr;
+        $($rest:tt)*
+    ) => {
+        pio_register!(@field $access [$($sm $sm_stride)?] $first $($second)? : $ty = $bits);
+        pio_register!(@fields $($rest)*);
+    };
+
+    (@field RO [] $get:ident : $ty:ty = $bits:expr) => {
+        pub fn $get(&self) -> $ty {
+            <$ty>::from_field(get_field(self.0, $bits))
+        }
+    };
+    (@field RO [$sm:ident $stride:expr] $get:ident : $ty:ty = $bits:expr) => {
+        pub fn $get(&self, $sm: u32) -> $ty {
+            <$ty>::from_field(get_field(self.0, sm_bits($bits, $stride, $sm)))
+        }
+    };
+    (@field WO [] $set:ident : $ty:ty = $bits:expr) => {
+        pub fn $set(&mut self, value: $ty) -> &mut Self {
+            set_field(&mut self.0, $bits, value.to_field());
+            self
+        }
+    };
+    (@field WO [$sm:ident $stride:expr] $set:ident : $ty:ty = $bits:expr) => {
+        pub fn $set(&mut self, $sm: u32, value: $ty) -> &mut Self {
+            set_field(&mut self.0, sm_bits($bits, $stride, $sm), value.to_field());
+            self
+        }
+    };
+    (@field SC [$($sm:tt)*] $set:ident : $ty:ty = $bits:expr) => {
+        pio_register!(@field WO [$($sm)*] $set : $ty = $bits);
+    };
+    (@field RW [$($sm:tt)*] $get:ident $set:ident : $ty:ty = $bits:expr) => {
+        pio_register!(@field RO [$($sm)*] $get : $ty = $bits);
+        pio_register!(@field WO [$($sm)*] $set : $ty = $bits);
+    };
+    (@field WC [] $get:ident $clear:ident : $ty:ty = $bits:expr) => {
+        pio_register!(@field RO [] $get : $ty = $bits);
+
+        pub fn $clear(&mut self) -> &mut Self {
+            self.0 |= $bits;
+            self
+        }
+    };
+    (@field WC [$sm:ident $stride:expr] $get:ident $clear:ident : $ty:ty = $bits:expr) => {
+        pio_register!(@field RO [$sm $stride] $get : $ty = $bits);
+
+        pub fn $clear(&mut self, $sm: u32) -> &mut Self {
+            self.0 |= sm_bits($bits, $stride, $sm);
+            self
+        }
+    };
+}
+
+pio_register! {
+    /// CTRL: state machine enables, and self-clearing restarts
+    Ctrl @ PROC_PIO_CTRL_OFFSET, reset = PROC_PIO_CTRL_RESET;
+    SC set_clkdiv_restart[sm; 1]: bool = 1 << PROC_PIO_CTRL_CLKDIV_RESTART_LSB;
+    SC set_sm_restart[sm; 1]: bool = 1 << PROC_PIO_CTRL_SM_RESTART_LSB;
+    RW sm_enable, set_sm_enable[sm; 1]: bool = 1 << PROC_PIO_CTRL_SM_ENABLE_LSB;
+}
+
+pio_register! {
+    /// FSTAT: FIFO empty/full flags
+    Fstat @ PROC_PIO_FSTAT_OFFSET, reset = PROC_PIO_FSTAT_RESET;
+    RO tx_empty[sm; 1]: bool = 1 << PROC_PIO_FSTAT_TXEMPTY_LSB;
+    RO tx_full[sm; 1]: bool = 1 << PROC_PIO_FSTAT_TXFULL_LSB;
+    RO rx_empty[sm; 1]: bool = 1 << PROC_PIO_FSTAT_RXEMPTY_LSB;
+    RO rx_full[sm; 1]: bool = 1 << PROC_PIO_FSTAT_RXFULL_LSB;
+}
+
+pio_register! {
+    /// FDEBUG: sticky FIFO stall, overflow and underflow flags
+    Fdebug @ PROC_PIO_FDEBUG_OFFSET, reset = PROC_PIO_FDEBUG_RESET;
+    WC tx_stall, clear_tx_stall[sm; 1]: bool = 1 << PROC_PIO_FDEBUG_TXSTALL_LSB;
+    WC tx_over, clear_tx_over[sm; 1]: bool = 1 << PROC_PIO_FDEBUG_TXOVER_LSB;
+    WC rx_under, clear_rx_under[sm; 1]: bool = 1 << PROC_PIO_FDEBUG_RXUNDER_LSB;
+    WC rx_stall, clear_rx_stall[sm; 1]: bool = 1 << PROC_PIO_FDEBUG_RXSTALL_LSB;
+}
+
+pio_register! {
+    /// FLEVEL: the low four bits of the FIFO levels, with bit 4 in [`Flevel2`]
+    Flevel @ PROC_PIO_FLEVEL_OFFSET, reset = PROC_PIO_FLEVEL_RESET;
+    RO rx[sm; PROC_PIO_FLEVEL_RX1_LSB - PROC_PIO_FLEVEL_RX0_LSB]: u8 = PROC_PIO_FLEVEL_RX0_BITS;
+    RO tx[sm; PROC_PIO_FLEVEL_TX1_LSB - PROC_PIO_FLEVEL_TX0_LSB]: u8 = PROC_PIO_FLEVEL_TX0_BITS;
+}
+
+pio_register! {
+    /// FLEVEL2: bit 4 of the FIFO levels, used when joined FIFOs hold more than 15 entries
+    Flevel2 @ PROC_PIO_FLEVEL2_OFFSET, reset = PROC_PIO_FLEVEL2_RESET;
+    RO rx[sm; PROC_PIO_FLEVEL2_RX1_LSB - PROC_PIO_FLEVEL2_RX0_LSB]: bool = PROC_PIO_FLEVEL2_RX0_BITS;
+    RO tx[sm; PROC_PIO_FLEVEL2_TX1_LSB - PROC_PIO_FLEVEL2_TX0_LSB]: bool = PROC_PIO_FLEVEL2_TX0_BITS;
+}
+
+pio_register! {
+    /// IRQ: the eight PIO interrupt flags
+    Irq @ PROC_PIO_IRQ_OFFSET, reset = PROC_PIO_IRQ_RESET;
+    WC irq, clear_irq: u8 = PROC_PIO_IRQ_BITS;
+}
+
+pio_register! {
+    /// IRQ_FORCE: sets PIO interrupt flags
+    IrqForce @ PROC_PIO_IRQ_FORCE_OFFSET, reset = PROC_PIO_IRQ_FORCE_RESET;
+    WO set_irq_force: u8 = PROC_PIO_IRQ_FORCE_BITS;
+}
+
+pio_register! {
+    /// INPUT_SYNC_BYPASS: one bit per GPIO skipping the input synchroniser
+    InputSyncBypass @ PROC_PIO_INPUT_SYNC_BYPASS_OFFSET, reset = PROC_PIO_INPUT_SYNC_BYPASS_RESET;
+    RW input_sync_bypass, set_input_sync_bypass: u32 = PROC_PIO_INPUT_SYNC_BYPASS_BITS;
+}
+
+pio_register! {
+    /// DBG_PADOUT: the pad output values PIO is driving
+    DbgPadout @ PROC_PIO_DBG_PADOUT_OFFSET, reset = PROC_PIO_DBG_PADOUT_RESET;
+    RO padout: u32 = PROC_PIO_DBG_PADOUT_BITS;
+}
+
+pio_register! {
+    /// DBG_PADOE: the pad output enables PIO is driving
+    DbgPadoe @ PROC_PIO_DBG_PADOE_OFFSET, reset = PROC_PIO_DBG_PADOE_RESET;
+    RO padoe: u32 = PROC_PIO_DBG_PADOE_BITS;
+}
+
+pio_register! {
+    /// DBG_CFGINFO: instruction memory size, state machine count and FIFO depth
+    DbgCfgInfo @ PROC_PIO_DBG_CFGINFO_OFFSET;
+    RO imem_size: u8 = PROC_PIO_DBG_CFGINFO_IMEM_SIZE_BITS;
+    RO sm_count: u8 = PROC_PIO_DBG_CFGINFO_SM_COUNT_BITS;
+    RO fifo_depth: u8 = PROC_PIO_DBG_CFGINFO_FIFO_DEPTH_BITS;
+}
+
+pio_register! {
+    /// INSTR_MEMn: write-only instruction memory
+    InstrMem[addr < 32] @ PROC_PIO_INSTR_MEM0_OFFSET, PROC_PIO_INSTR_MEM1_OFFSET - PROC_PIO_INSTR_MEM0_OFFSET,
+        reset = PROC_PIO_INSTR_MEM0_RESET;
+    WO set_instr: u16 = PROC_PIO_INSTR_MEM0_BITS;
+}
+
+pio_register! {
+    /// SMx_CLKDIV: 16.8 clock divider
+    SmClkdiv[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_CLKDIV_OFFSET, PROC_PIO_SM_STRIDE,
+        reset = PROC_PIO_SM0_CLKDIV_RESET;
+    RW int, set_int: u16 = PROC_PIO_SM0_CLKDIV_INT_BITS;
+    RW frac, set_frac: u8 = PROC_PIO_SM0_CLKDIV_FRAC_BITS;
+}
+
+pio_register! {
+    /// SMx_EXECCTRL: execution settings and status
+    SmExecCtrl[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_EXECCTRL_OFFSET, PROC_PIO_SM_STRIDE,
+        reset = PROC_PIO_SM0_EXECCTRL_RESET;
+    RO exec_stalled: bool = PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_BITS;
+    RW side_en, set_side_en: bool = PROC_PIO_SM0_EXECCTRL_SIDE_EN_BITS;
+    RW side_pindir, set_side_pindir: bool = PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_BITS;
+    RW jmp_pin, set_jmp_pin: u8 = PROC_PIO_SM0_EXECCTRL_JMP_PIN_BITS;
+    RW out_en_sel, set_out_en_sel: u8 = PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_BITS;
+    RW inline_out_en, set_inline_out_en: bool = PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_BITS;
+    RW out_sticky, set_out_sticky: bool = PROC_PIO_SM0_EXECCTRL_OUT_STICKY_BITS;
+    RW wrap_top, set_wrap_top: u8 = PROC_PIO_SM0_EXECCTRL_WRAP_TOP_BITS;
+    RW wrap_bottom, set_wrap_bottom: u8 = PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_BITS;
+    RW status_sel, set_status_sel: bool = PROC_PIO_SM0_EXECCTRL_STATUS_SEL_BITS;
+    RW status_n, set_status_n: u8 = PROC_PIO_SM0_EXECCTRL_STATUS_N_BITS;
+}
+
+pio_register! {
+    /// SMx_SHIFTCTRL: ISR/OSR shifting and FIFO joining; thresholds read 0 for 32
+    SmShiftCtrl[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_SHIFTCTRL_OFFSET, PROC_PIO_SM_STRIDE,
+        reset = PROC_PIO_SM0_SHIFTCTRL_RESET;
+    RW fjoin_rx, set_fjoin_rx: bool = PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS;
+    RW fjoin_tx, set_fjoin_tx: bool = PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_BITS;
+    RW pull_thresh, set_pull_thresh: u8 = PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_BITS;
+    RW push_thresh, set_push_thresh: u8 = PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_BITS;
+    RW out_shiftdir, set_out_shiftdir: bool = PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_BITS;
+    RW in_shiftdir, set_in_shiftdir: bool = PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_BITS;
+    RW autopull, set_autopull: bool = PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_BITS;
+    RW autopush, set_autopush: bool = PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_BITS;
+}
+
+pio_register! {
+    /// SMx_ADDR: current program counter
+    SmAddr[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_ADDR_OFFSET, PROC_PIO_SM_STRIDE,
+        reset = PROC_PIO_SM0_ADDR_RESET;
+    RO addr: u8 = PROC_PIO_SM0_ADDR_BITS;
+}
+
+pio_register! {
+    /// SMx_INSTR: reads the current instruction, writes execute one immediately
+    SmInstr[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_INSTR_OFFSET, PROC_PIO_SM_STRIDE;
+    RW instr, set_instr: u16 = PROC_PIO_SM0_INSTR_BITS;
+}
+
+pio_register! {
+    /// SMx_PINCTRL: pin mapping
+    SmPinCtrl[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_PINCTRL_OFFSET, PROC_PIO_SM_STRIDE,
+        reset = PROC_PIO_SM0_PINCTRL_RESET;
+    RW sideset_count, set_sideset_count: u8 = PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_BITS;
+    RW set_count, set_set_count: u8 = PROC_PIO_SM0_PINCTRL_SET_COUNT_BITS;
+    RW out_count, set_out_count: u8 = PROC_PIO_SM0_PINCTRL_OUT_COUNT_BITS;
+    RW in_base, set_in_base: u8 = PROC_PIO_SM0_PINCTRL_IN_BASE_BITS;
+    RW sideset_base, set_sideset_base: u8 = PROC_PIO_SM0_PINCTRL_SIDESET_BASE_BITS;
+    RW set_base, set_set_base: u8 = PROC_PIO_SM0_PINCTRL_SET_BASE_BITS;
+    RW out_base, set_out_base: u8 = PROC_PIO_SM0_PINCTRL_OUT_BASE_BITS;
+}
+
+pio_register! {
+    /// SMx_DMACTRL_TX: TX DMA request control
+    SmDmaCtrlTx[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_DMACTRL_TX_OFFSET, PROC_PIO_SM_STRIDE,
+        reset = PROC_PIO_SM0_DMACTRL_TX_RESET;
+    RW dreq_en, set_dreq_en: bool = PROC_PIO_SM0_DMACTRL_TX_DREQ_EN_BITS;
+    RO active: bool = PROC_PIO_SM0_DMACTRL_TX_ACTIVE_BITS;
+    RW dwell_time, set_dwell_time: u8 = PROC_PIO_SM0_DMACTRL_TX_DWELL_TIME_BITS;
+    RW fifo_threshold, set_fifo_threshold: u8 = PROC_PIO_SM0_DMACTRL_TX_FIFO_THRESHOLD_BITS;
+}
+
+pio_register! {
+    /// SMx_DMACTRL_RX: RX DMA request control
+    SmDmaCtrlRx[sm < PROC_PIO_SM_COUNT] @ PROC_PIO_SM0_DMACTRL_RX_OFFSET, PROC_PIO_SM_STRIDE,
+        reset = PROC_PIO_SM0_DMACTRL_RX_RESET;
+    RW dreq_en, set_dreq_en: bool = PROC_PIO_SM0_DMACTRL_RX_DREQ_EN_BITS;
+    RO active: bool = PROC_PIO_SM0_DMACTRL_RX_ACTIVE_BITS;
+    RW dwell_time, set_dwell_time: u8 = PROC_PIO_SM0_DMACTRL_RX_DWELL_TIME_BITS;
+    RW fifo_threshold, set_fifo_threshold: u8 = PROC_PIO_SM0_DMACTRL_RX_FIFO_THRESHOLD_BITS;
+}
+
+pio_register! {
+    /// INTR: raw interrupts, IRQ flags 0-3 and per state machine FIFO levels
+    Intr @ PROC_PIO_INTR_OFFSET, reset = PROC_PIO_INTR_RESET;
+    RO irq[sm; 1]: bool = PROC_PIO_INTR_SM0_BITS;
+    RO tx_not_full[sm; 1]: bool = PROC_PIO_INTR_SM0_TXNFULL_BITS;
+    RO rx_not_empty[sm; 1]: bool = PROC_PIO_INTR_SM0_RXNEMPTY_BITS;
+}
+
+pio_register! {
+    /// IRQx_INTE: interrupt enables for system interrupt line x
+    IrqInte[irq < 2] @ PROC_PIO_IRQ0_INTE_OFFSET, PROC_PIO_IRQ_STRIDE,
+        reset = PROC_PIO_IRQ0_INTE_RESET;
+    RW irq, set_irq[sm; 1]: bool = PROC_PIO_IRQ0_INTE_SM0_BITS;
+    RW tx_not_full, set_tx_not_full[sm; 1]: bool = PROC_PIO_IRQ0_INTE_SM0_TXNFULL_BITS;
+    RW rx_not_empty, set_rx_not_empty[sm; 1]: bool = PROC_PIO_IRQ0_INTE_SM0_RXNEMPTY_BITS;
+}
+
+pio_register! {
+    /// IRQx_INTF: interrupt forces for system interrupt line x
+    IrqIntf[irq < 2] @ PROC_PIO_IRQ0_INTF_OFFSET, PROC_PIO_IRQ_STRIDE,
+        reset = PROC_PIO_IRQ0_INTF_RESET;
+    RW irq, set_irq[sm; 1]: bool = PROC_PIO_IRQ0_INTF_SM0_BITS;
+    RW tx_not_full, set_tx_not_full[sm; 1]: bool = PROC_PIO_IRQ0_INTF_SM0_TXNFULL_BITS;
+    RW rx_not_empty, set_rx_not_empty[sm; 1]: bool = PROC_PIO_IRQ0_INTF_SM0_RXNEMPTY_BITS;
+}
+
+pio_register! {
+    /// IRQx_INTS: interrupt status after masking and forcing for system interrupt line x
+    IrqInts[irq < 2] @ PROC_PIO_IRQ0_INTS_OFFSET, PROC_PIO_IRQ_STRIDE,
+        reset = PROC_PIO_IRQ0_INTS_RESET;
+    RO irq[sm; 1]: bool = PROC_PIO_IRQ0_INTS_SM0_BITS;
+    RO tx_not_full[sm; 1]: bool = PROC_PIO_IRQ0_INTS_SM0_TXNFULL_BITS;
+    RO rx_not_empty[sm; 1]: bool = PROC_PIO_IRQ0_INTS_SM0_RXNEMPTY_BITS;
+}
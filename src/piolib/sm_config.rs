@@ -0,0 +1,556 @@
+use std::fmt;
+
+use crate::include::hardware::regs::proc_pio::*;
+use crate::include::piolib::*;
+
+use super::error::{PioError, PioResult};
+
+/// Chainable builder over [`pio_sm_config`] that checks every argument
+///
+/// Each setter packs its fields with the matching `sm_config_set_*` function, returning
+/// `InvalidParam` instead of relying on the parameter assertions:
+///
+/// ```
+/// # use piolib_rs::piolib::sm_config::SmConfig;
+/// let config = SmConfig::new()
+///     .out_pins(4, 8)?
+///     .out_shift(true, true, 32)?
+///     .clkdiv(2.5)?
+///     .build();
+/// assert_eq!(0x0002_8000, config.clkdiv());
+/// # Ok::<(), piolib_rs::piolib::error::PioError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct SmConfig {
+    config: pio_sm_config,
+}
+
+fn check(valid: bool, which: &'static str) -> PioResult<()> {
+    if valid {
+        Ok(())
+    } else {
+        Err(PioError::InvalidParam { which })
+    }
+}
+
+impl SmConfig {
+    /// Start from [`pio_get_default_sm_config`]
+    pub const fn new() -> Self {
+        SmConfig {
+            config: pio_get_default_sm_config(),
+        }
+    }
+
+    pub const fn from_config(config: pio_sm_config) -> Self {
+        SmConfig { config }
+    }
+
+    pub const fn build(self) -> pio_sm_config {
+        self.config
+    }
+
+    pub fn decode(&self) -> SmConfigFields {
+        SmConfigFields::decode(&self.config)
+    }
+
+    /// The fields that change going from `self` to `other`
+    pub fn diff(&self, other: &SmConfig) -> SmConfigDiff {
+        self.decode().diff(&other.decode())
+    }
+
+    /// `base` 0-31, `count` 0-32
+    pub fn out_pins(mut self, base: u32, count: u32) -> PioResult<Self> {
+        check(base < 32, "out_base")?;
+        check(count <= 32, "out_count")?;
+        sm_config_set_out_pins(&mut self.config, base, count);
+        Ok(self)
+    }
+
+    /// `base` 0-31, `count` 0-5
+    pub fn set_pins(mut self, base: u32, count: u32) -> PioResult<Self> {
+        check(base < 32, "set_base")?;
+        check(count <= 5, "set_count")?;
+        sm_config_set_set_pins(&mut self.config, base, count);
+        Ok(self)
+    }
+
+    pub fn in_pins(mut self, base: u32) -> PioResult<Self> {
+        check(base < 32, "in_base")?;
+        sm_config_set_in_pins(&mut self.config, base);
+        Ok(self)
+    }
+
+    pub fn sideset_pins(mut self, base: u32) -> PioResult<Self> {
+        check(base < 32, "sideset_base")?;
+        sm_config_set_sideset_pins(&mut self.config, base);
+        Ok(self)
+    }
+
+    /// `bit_count` 0-5, including the enable bit when `optional`
+    pub fn sideset(mut self, bit_count: u32, optional: bool, pindirs: bool) -> PioResult<Self> {
+        check(bit_count <= 5, "bit_count")?;
+        check(!optional || bit_count >= 1, "optional")?;
+        sm_config_set_sideset(&mut self.config, bit_count, optional, pindirs);
+        Ok(self)
+    }
+
+    /// A `div_int` of 0 means 65536 and then allows no fraction
+    pub fn clkdiv_int_frac(mut self, div_int: u16, div_frac: u8) -> PioResult<Self> {
+        check(div_int != 0 || div_frac == 0, "div_frac")?;
+        sm_config_set_clkdiv_int_frac(&mut self.config, div_int, div_frac);
+        Ok(self)
+    }
+
+    /// `div` 1.0-65536.0
+    pub fn clkdiv(mut self, div: f32) -> PioResult<Self> {
+        check((1.0..=65536.0).contains(&div), "div")?;
+        sm_config_set_clkdiv(&mut self.config, div);
+        Ok(self)
+    }
+
+    pub fn wrap(mut self, wrap_target: u32, wrap: u32) -> PioResult<Self> {
+        check(wrap_target < PIO_INSTRUCTION_COUNT, "wrap_target")?;
+        check(wrap < PIO_INSTRUCTION_COUNT, "wrap")?;
+        sm_config_set_wrap(&mut self.config, wrap_target, wrap);
+        Ok(self)
+    }
+
+    pub fn jmp_pin(mut self, pin: u32) -> PioResult<Self> {
+        check(pin < 32, "jmp_pin")?;
+        sm_config_set_jmp_pin(&mut self.config, pin);
+        Ok(self)
+    }
+
+    /// `push_threshold` 1-32; 0 is accepted as the register's encoding of 32
+    pub fn in_shift(
+        mut self,
+        shift_right: bool,
+        autopush: bool,
+        push_threshold: u32,
+    ) -> PioResult<Self> {
+        check(push_threshold <= 32, "push_threshold")?;
+        sm_config_set_in_shift(&mut self.config, shift_right, autopush, push_threshold);
+        Ok(self)
+    }
+
+    /// `pull_threshold` 1-32; 0 is accepted as the register's encoding of 32
+    pub fn out_shift(
+        mut self,
+        shift_right: bool,
+        autopull: bool,
+        pull_threshold: u32,
+    ) -> PioResult<Self> {
+        check(pull_threshold <= 32, "pull_threshold")?;
+        sm_config_set_out_shift(&mut self.config, shift_right, autopull, pull_threshold);
+        Ok(self)
+    }
+
+    pub fn fifo_join(mut self, join: pio_fifo_join) -> PioResult<Self> {
+        sm_config_set_fifo_join(&mut self.config, join);
+        Ok(self)
+    }
+
+    pub fn out_special(
+        mut self,
+        sticky: bool,
+        has_enable_pin: bool,
+        enable_pin_index: u32,
+    ) -> PioResult<Self> {
+        check(enable_pin_index < 32, "enable_pin_index")?;
+        sm_config_set_out_special(&mut self.config, sticky, has_enable_pin, enable_pin_index);
+        Ok(self)
+    }
+
+    pub fn mov_status(mut self, status_sel: pio_mov_status_type, status_n: u32) -> PioResult<Self> {
+        check(status_n < 32, "status_n")?;
+        sm_config_set_mov_status(&mut self.config, status_sel, status_n);
+        Ok(self)
+    }
+}
+
+impl Default for SmConfig {
+    fn default() -> Self {
+        SmConfig::new()
+    }
+}
+
+impl From<pio_sm_config> for SmConfig {
+    fn from(config: pio_sm_config) -> Self {
+        SmConfig::from_config(config)
+    }
+}
+
+impl From<SmConfig> for pio_sm_config {
+    fn from(config: SmConfig) -> Self {
+        config.build()
+    }
+}
+
+impl fmt::Display for SmConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.decode().fmt(f)
+    }
+}
+
+/// The fields of a [`pio_sm_config`], decoded from its register values
+///
+/// Thresholds are 1-32 rather than the register's 0 for 32. Both FJOIN bits set, which the
+/// setters never produce, decodes as `PIO_FIFO_JOIN_TX`.
+///
+/// With the `serde` feature this is also how [`pio_sm_config`] is serialized, so saved
+/// configurations stay readable and are range checked when loaded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmConfigFields {
+    pub clkdiv_int: u16,
+    pub clkdiv_frac: u8,
+
+    pub side_en: bool,
+    pub side_pindir: bool,
+    pub jmp_pin: u8,
+    pub out_en_sel: u8,
+    pub inline_out_en: bool,
+    pub out_sticky: bool,
+    pub wrap_top: u8,
+    pub wrap_bottom: u8,
+    pub status_sel: pio_mov_status_type,
+    pub status_n: u8,
+
+    pub fifo_join: pio_fifo_join,
+    pub pull_thresh: u8,
+    pub push_thresh: u8,
+    pub out_shift_right: bool,
+    pub in_shift_right: bool,
+    pub autopull: bool,
+    pub autopush: bool,
+
+    pub sideset_count: u8,
+    pub set_count: u8,
+    pub out_count: u8,
+    pub in_base: u8,
+    pub sideset_base: u8,
+    pub set_base: u8,
+    pub out_base: u8,
+}
+
+fn field(value: u32, bits: u32, lsb: u32) -> u32 {
+    (value & bits) >> lsb
+}
+
+fn flag(value: u32, bits: u32) -> bool {
+    value & bits != 0
+}
+
+fn threshold(value: u32, bits: u32, lsb: u32) -> u8 {
+    match field(value, bits, lsb) {
+        0 => 32,
+        thresh => thresh as u8,
+    }
+}
+
+impl SmConfigFields {
+    pub fn decode(config: &pio_sm_config) -> Self {
+        Self::from_registers(
+            config.clkdiv(),
+            config.execctrl(),
+            config.shiftctrl(),
+            config.pinctrl(),
+        )
+    }
+
+    /// Decode raw SMx_CLKDIV, SMx_EXECCTRL, SMx_SHIFTCTRL and SMx_PINCTRL values
+    pub fn from_registers(clkdiv: u32, execctrl: u32, shiftctrl: u32, pinctrl: u32) -> Self {
+        let fifo_join = if flag(shiftctrl, PROC_PIO_SM0_SHIFTCTRL_FJOIN_TX_BITS) {
+            pio_fifo_join::PIO_FIFO_JOIN_TX
+        } else if flag(shiftctrl, PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS) {
+            pio_fifo_join::PIO_FIFO_JOIN_RX
+        } else {
+            pio_fifo_join::PIO_FIFO_JOIN_NONE
+        };
+        let status_sel = if flag(execctrl, PROC_PIO_SM0_EXECCTRL_STATUS_SEL_BITS) {
+            pio_mov_status_type::STATUS_RX_LESSTHAN
+        } else {
+            pio_mov_status_type::STATUS_TX_LESSTHAN
+        };
+        SmConfigFields {
+            clkdiv_int: field(
+                clkdiv,
+                PROC_PIO_SM0_CLKDIV_INT_BITS,
+                PROC_PIO_SM0_CLKDIV_INT_LSB,
+            ) as u16,
+            clkdiv_frac: field(
+                clkdiv,
+                PROC_PIO_SM0_CLKDIV_FRAC_BITS,
+                PROC_PIO_SM0_CLKDIV_FRAC_LSB,
+            ) as u8,
+
+            side_en: flag(execctrl, PROC_PIO_SM0_EXECCTRL_SIDE_EN_BITS),
+            side_pindir: flag(execctrl, PROC_PIO_SM0_EXECCTRL_SIDE_PINDIR_BITS),
+            jmp_pin: field(
+                execctrl,
+                PROC_PIO_SM0_EXECCTRL_JMP_PIN_BITS,
+                PROC_PIO_SM0_EXECCTRL_JMP_PIN_LSB,
+            ) as u8,
+            out_en_sel: field(
+                execctrl,
+                PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_BITS,
+                PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_LSB,
+            ) as u8,
+            inline_out_en: flag(execctrl, PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_BITS),
+            out_sticky: flag(execctrl, PROC_PIO_SM0_EXECCTRL_OUT_STICKY_BITS),
+            wrap_top: field(
+                execctrl,
+                PROC_PIO_SM0_EXECCTRL_WRAP_TOP_BITS,
+                PROC_PIO_SM0_EXECCTRL_WRAP_TOP_LSB,
+            ) as u8,
+            wrap_bottom: field(
+                execctrl,
+                PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_BITS,
+                PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_LSB,
+            ) as u8,
+            status_sel,
+            status_n: field(
+                execctrl,
+                PROC_PIO_SM0_EXECCTRL_STATUS_N_BITS,
+                PROC_PIO_SM0_EXECCTRL_STATUS_N_LSB,
+            ) as u8,
+
+            fifo_join,
+            pull_thresh: threshold(
+                shiftctrl,
+                PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_BITS,
+                PROC_PIO_SM0_SHIFTCTRL_PULL_THRESH_LSB,
+            ),
+            push_thresh: threshold(
+                shiftctrl,
+                PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_BITS,
+                PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_LSB,
+            ),
+            out_shift_right: flag(shiftctrl, PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_BITS),
+            in_shift_right: flag(shiftctrl, PROC_PIO_SM0_SHIFTCTRL_IN_SHIFTDIR_BITS),
+            autopull: flag(shiftctrl, PROC_PIO_SM0_SHIFTCTRL_AUTOPULL_BITS),
+            autopush: flag(shiftctrl, PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_BITS),
+
+            sideset_count: field(
+                pinctrl,
+                PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_BITS,
+                PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_LSB,
+            ) as u8,
+            set_count: field(
+                pinctrl,
+                PROC_PIO_SM0_PINCTRL_SET_COUNT_BITS,
+                PROC_PIO_SM0_PINCTRL_SET_COUNT_LSB,
+            ) as u8,
+            out_count: field(
+                pinctrl,
+                PROC_PIO_SM0_PINCTRL_OUT_COUNT_BITS,
+                PROC_PIO_SM0_PINCTRL_OUT_COUNT_LSB,
+            ) as u8,
+            in_base: field(
+                pinctrl,
+                PROC_PIO_SM0_PINCTRL_IN_BASE_BITS,
+                PROC_PIO_SM0_PINCTRL_IN_BASE_LSB,
+            ) as u8,
+            sideset_base: field(
+                pinctrl,
+                PROC_PIO_SM0_PINCTRL_SIDESET_BASE_BITS,
+                PROC_PIO_SM0_PINCTRL_SIDESET_BASE_LSB,
+            ) as u8,
+            set_base: field(
+                pinctrl,
+                PROC_PIO_SM0_PINCTRL_SET_BASE_BITS,
+                PROC_PIO_SM0_PINCTRL_SET_BASE_LSB,
+            ) as u8,
+            out_base: field(
+                pinctrl,
+                PROC_PIO_SM0_PINCTRL_OUT_BASE_BITS,
+                PROC_PIO_SM0_PINCTRL_OUT_BASE_LSB,
+            ) as u8,
+        }
+    }
+
+    /// TX and RX FIFO depths on a block whose unjoined FIFOs hold `fifo_depth` entries, a joined
+    /// FIFO taking the other's entries
+    pub fn fifo_depths(&self, fifo_depth: u32) -> (u32, u32) {
+        match self.fifo_join {
+            pio_fifo_join::PIO_FIFO_JOIN_NONE => (fifo_depth, fifo_depth),
+            pio_fifo_join::PIO_FIFO_JOIN_TX => (2 * fifo_depth, 0),
+            pio_fifo_join::PIO_FIFO_JOIN_RX => (0, 2 * fifo_depth),
+        }
+    }
+
+    /// Pack the fields back into a configuration, checking each is in range
+    pub fn encode(&self) -> PioResult<pio_sm_config> {
+        Ok(
+            SmConfig::from_config(pio_sm_config::from_registers(0, 0, 0, 0))
+                .clkdiv_int_frac(self.clkdiv_int, self.clkdiv_frac)?
+                .sideset(self.sideset_count as u32, self.side_en, self.side_pindir)?
+                .jmp_pin(self.jmp_pin as u32)?
+                .out_special(self.out_sticky, self.inline_out_en, self.out_en_sel as u32)?
+                .wrap(self.wrap_bottom as u32, self.wrap_top as u32)?
+                .mov_status(self.status_sel, self.status_n as u32)?
+                .fifo_join(self.fifo_join)?
+                .out_shift(self.out_shift_right, self.autopull, self.pull_thresh as u32)?
+                .in_shift(self.in_shift_right, self.autopush, self.push_thresh as u32)?
+                .set_pins(self.set_base as u32, self.set_count as u32)?
+                .out_pins(self.out_base as u32, self.out_count as u32)?
+                .in_pins(self.in_base as u32)?
+                .sideset_pins(self.sideset_base as u32)?
+                .build(),
+        )
+    }
+
+    /// Every field as `(register, field, value)`, named as in `regs::proc_pio`
+    pub fn field_values(&self) -> [(&'static str, &'static str, u32); 27] {
+        let join = self.fifo_join;
+        [
+            ("CLKDIV", "INT", self.clkdiv_int as u32),
+            ("CLKDIV", "FRAC", self.clkdiv_frac as u32),
+            ("EXECCTRL", "SIDE_EN", self.side_en as u32),
+            ("EXECCTRL", "SIDE_PINDIR", self.side_pindir as u32),
+            ("EXECCTRL", "JMP_PIN", self.jmp_pin as u32),
+            ("EXECCTRL", "OUT_EN_SEL", self.out_en_sel as u32),
+            ("EXECCTRL", "INLINE_OUT_EN", self.inline_out_en as u32),
+            ("EXECCTRL", "OUT_STICKY", self.out_sticky as u32),
+            ("EXECCTRL", "WRAP_TOP", self.wrap_top as u32),
+            ("EXECCTRL", "WRAP_BOTTOM", self.wrap_bottom as u32),
+            ("EXECCTRL", "STATUS_SEL", self.status_sel as u32),
+            ("EXECCTRL", "STATUS_N", self.status_n as u32),
+            (
+                "SHIFTCTRL",
+                "FJOIN_RX",
+                (join == pio_fifo_join::PIO_FIFO_JOIN_RX) as u32,
+            ),
+            (
+                "SHIFTCTRL",
+                "FJOIN_TX",
+                (join == pio_fifo_join::PIO_FIFO_JOIN_TX) as u32,
+            ),
+            ("SHIFTCTRL", "PULL_THRESH", self.pull_thresh as u32),
+            ("SHIFTCTRL", "PUSH_THRESH", self.push_thresh as u32),
+            ("SHIFTCTRL", "OUT_SHIFTDIR", self.out_shift_right as u32),
+            ("SHIFTCTRL", "IN_SHIFTDIR", self.in_shift_right as u32),
+            ("SHIFTCTRL", "AUTOPULL", self.autopull as u32),
+            ("SHIFTCTRL", "AUTOPUSH", self.autopush as u32),
+            ("PINCTRL", "SIDESET_COUNT", self.sideset_count as u32),
+            ("PINCTRL", "SET_COUNT", self.set_count as u32),
+            ("PINCTRL", "OUT_COUNT", self.out_count as u32),
+            ("PINCTRL", "IN_BASE", self.in_base as u32),
+            ("PINCTRL", "SIDESET_BASE", self.sideset_base as u32),
+            ("PINCTRL", "SET_BASE", self.set_base as u32),
+            ("PINCTRL", "OUT_BASE", self.out_base as u32),
+        ]
+    }
+
+    /// The fields that change going from `self` to `other`
+    pub fn diff(&self, other: &SmConfigFields) -> SmConfigDiff {
+        let changes = self
+            .field_values()
+            .into_iter()
+            .zip(other.field_values())
+            .filter(|((_, _, old), (_, _, new))| old != new)
+            .map(
+                |((register, field, old), (_, _, new))| SmConfigFieldChange {
+                    register,
+                    field,
+                    old,
+                    new,
+                },
+            )
+            .collect();
+        SmConfigDiff { changes }
+    }
+}
+
+impl From<&pio_sm_config> for SmConfigFields {
+    fn from(config: &pio_sm_config) -> Self {
+        SmConfigFields::decode(config)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for pio_sm_config {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SmConfigFields::decode(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for pio_sm_config {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SmConfigFields::deserialize(deserializer)?
+            .encode()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// One line per register, e.g. `CLKDIV: INT=1 FRAC=0`
+impl fmt::Display for SmConfigFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut register = "";
+        for (reg, name, value) in self.field_values() {
+            if reg != register {
+                if !register.is_empty() {
+                    writeln!(f)?;
+                }
+                write!(f, "{reg}:")?;
+                register = reg;
+            }
+            write!(f, " {name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A field that differs between two configurations, displayed as e.g. `PULL_THRESH 8 -> 32`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SmConfigFieldChange {
+    pub register: &'static str,
+    pub field: &'static str,
+    pub old: u32,
+    pub new: u32,
+}
+
+impl fmt::Display for SmConfigFieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} -> {}", self.field, self.old, self.new)
+    }
+}
+
+/// Field level differences between two configurations, in register order
+///
+/// Displays as the changes separated by commas, or `no differences`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SmConfigDiff {
+    changes: Vec<SmConfigFieldChange>,
+}
+
+impl SmConfigDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn changes(&self) -> &[SmConfigFieldChange] {
+        &self.changes
+    }
+}
+
+impl fmt::Display for SmConfigDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "no differences");
+        }
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{change}")?;
+        }
+        Ok(())
+    }
+}
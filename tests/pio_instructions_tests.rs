@@ -0,0 +1,85 @@
+use piolib_rs::include::hardware::pio_instructions::*;
+
+#[test]
+fn instruction_word_round_trips_every_valid_word() {
+    let mut valid = 0;
+    for word in 0..=u16::MAX {
+        if let Some(decoded) = PioInstructionWord::decode(word) {
+            assert_eq!(word, decoded.encode(), "{decoded:?}");
+            valid += 1;
+        }
+    }
+    assert_eq!(41824, valid);
+}
+#[test]
+fn instruction_encode_matches_encode_functions() {
+    let cases = [
+        (pio_encode_jmp_x_dec(3), PioInstruction::Jmp { condition: PioJmpCondition::XDec, address: 3 }),
+        (pio_encode_wait_irq(true, true, 2), PioInstruction::Wait { polarity: true, source: PioWaitSource::Irq, index: 0x12 }),
+        (pio_encode_in(pio_src_dest::pio_isr, 32), PioInstruction::In { source: PioInSource::Isr, bit_count: 32 }),
+        (pio_encode_out(pio_src_dest::pio_exec_out, 16), PioInstruction::Out { destination: PioOutDestination::Exec, bit_count: 16 }),
+        (pio_encode_push(true, false), PioInstruction::Push { if_full: true, block: false }),
+        (pio_encode_pull(false, true), PioInstruction::Pull { if_empty: false, block: true }),
+        (pio_encode_mov_reverse(pio_src_dest::pio_isr, pio_src_dest::pio_osr), PioInstruction::Mov { destination: PioMovDestination::Isr, op: PioMovOp::BitReverse, source: PioMovSource::Osr }),
+        (pio_encode_irq_clear(false, 5), PioInstruction::Irq { clear: true, wait: false, index: 5 }),
+        (pio_encode_set(pio_src_dest::pio_pindirs, 31), PioInstruction::Set { destination: PioSetDestination::Pindirs, data: 31 }),
+    ];
+    for (encoded, instruction) in cases {
+        assert_eq!(encoded as u16, instruction.encode());
+        assert_eq!(Some(instruction), PioInstruction::decode(encoded as u16));
+    }
+}
+#[test]
+fn instruction_decode_rejects_reserved_encodings() {
+    assert_eq!(None, PioInstruction::decode(0x2060)); // wait source 3
+    assert_eq!(None, PioInstruction::decode(0x4081)); // in source 4
+    assert_eq!(None, PioInstruction::decode(0x8001)); // push with nonzero low bits
+    assert_eq!(None, PioInstruction::decode(0xa018)); // mov op 3
+    assert_eq!(None, PioInstruction::decode(0xc080)); // irq bit 7
+    assert_eq!(None, PioInstruction::decode(0xe060)); // set destination 3
+}
+#[test]
+fn instruction_word_side_set_and_delay() {
+    let nop = PioInstruction::decode(pio_encode_nop() as u16).unwrap();
+    let side_set = PioSideSet { bit_count: 2, optional: true, pindirs: false };
+    let word = PioInstructionWord::with_delay_side_set(nop, &side_set, 3, Some(2));
+    assert_eq!(
+        (pio_encode_nop() | pio_encode_sideset_opt(2, 2) | pio_encode_delay(3)) as u16,
+        word.encode()
+    );
+    assert_eq!(Some(2), word.side_set(&side_set));
+    assert_eq!(3, word.delay(&side_set));
+    let word = PioInstructionWord::with_delay_side_set(nop, &side_set, 3, None);
+    assert_eq!(None, word.side_set(&side_set));
+    assert_eq!(3, word.delay(&side_set));
+}
+#[test]
+fn try_encode_rejects_out_of_range_fields() {
+    let jmp = PioInstruction::Jmp { condition: PioJmpCondition::Always, address: 32 };
+    assert!(!jmp.is_valid());
+    assert_eq!(None, jmp.try_encode());
+    assert_eq!(None, PioInstruction::In { source: PioInSource::X, bit_count: 0 }.try_encode());
+    assert_eq!(None, PioInstruction::Set { destination: PioSetDestination::X, data: 32 }.try_encode());
+    let set = PioInstruction::Set { destination: PioSetDestination::X, data: 31 };
+    assert_eq!(Some(set.encode()), set.try_encode());
+
+    let side_set = PioSideSet { bit_count: 2, optional: true, pindirs: false };
+    assert_eq!(None, PioInstructionWord::try_with_delay_side_set(set, &side_set, 4, None));
+    assert_eq!(None, PioInstructionWord::try_with_delay_side_set(set, &side_set, 0, Some(4)));
+    let word = PioInstructionWord::try_with_delay_side_set(set, &side_set, 3, Some(2)).unwrap();
+    assert_eq!(Some(word.encode()), word.try_encode());
+    assert_eq!(None, PioInstructionWord { instruction: set, delay_side_set: 0x20 }.try_encode());
+}
+#[test]
+fn instruction_word_decodes_side_sets_past_5_bits_as_none() {
+    let word = PioInstructionWord::decode((pio_encode_nop() | pio_encode_delay(31)) as u16).unwrap();
+    for side_set in [
+        PioSideSet { bit_count: 5, optional: true, pindirs: false },
+        PioSideSet { bit_count: 6, optional: false, pindirs: false },
+        PioSideSet { bit_count: 8, optional: false, pindirs: false },
+    ] {
+        assert_eq!(None, word.side_set(&side_set));
+        assert_eq!(0, word.delay(&side_set));
+        assert_eq!(0, side_set.max_delay());
+    }
+}
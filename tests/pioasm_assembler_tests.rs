@@ -0,0 +1,144 @@
+use piolib_rs::include::hardware::pio_instructions::*;
+use piolib_rs::pioasm::assembler::*;
+use piolib_rs::pioasm::disassembler::disassemble;
+
+const WS2812: &str = "
+.program ws2812
+.side_set 1
+
+.define public T1 2
+.define public T2 5
+.define public T3 3
+
+.lang_opt python sideset_init = pico.PIO.OUT_HIGH
+
+.wrap_target
+bitloop:
+    out x, 1       side 0 [T3 - 1] ; Side-set still takes place when instruction stalls
+    jmp !x do_zero side 1 [T1 - 1] ; Branch on the bit we shifted out. Positive pulse
+do_one:
+    jmp  bitloop   side 1 [T2 - 1] ; Continue driving high, for a long pulse
+do_zero:
+    nop            side 0 [T2 - 1] ; Or drive low, for a short pulse
+.wrap
+
+% c-sdk {
+static inline void ws2812_program_init(PIO pio, uint sm, uint offset, uint pin, float freq, bool rgbw) {
+}
+%}
+";
+
+#[test]
+fn assemble_ws2812_matches_pioasm() {
+    let program = assemble_program(WS2812).unwrap();
+    assert_eq!("ws2812", program.name);
+    assert_eq!(vec![0x6221, 0x1123, 0x1400, 0xa442], program.instructions);
+    assert_eq!((0, 3), (program.wrap_target, program.wrap));
+    assert_eq!(PioSideSet { bit_count: 1, optional: false, pindirs: false }, program.side_set);
+    assert_eq!(None, program.origin);
+    assert_eq!(
+        vec![("T1".to_string(), 2), ("T2".to_string(), 5), ("T3".to_string(), 3)],
+        program.public_symbols
+    );
+}
+#[test]
+fn assemble_every_instruction_kind() {
+    let source = "
+        .program all
+        .origin 4
+        .side_set 2 opt pindirs
+        public start:
+            jmp x-- start side 3 [1]
+            jmp x!=y, 0
+            wait 1 gpio 4
+            wait 0 irq 2 rel
+            in pins, 32
+            out exec, 16
+            push iffull noblock
+            pull ifempty
+            mov x, ~status
+            mov isr, ::osr
+        .wrap_target
+            irq wait 1 rel
+            irq clear 5
+            set pindirs, 0x1f
+            .word 0xe001
+        .wrap
+    ";
+    let program = assemble_program(source).unwrap();
+    let expected = [
+        pio_encode_jmp_x_dec(0) | pio_encode_sideset_opt(2, 3) | pio_encode_delay(1),
+        pio_encode_jmp_x_ne_y(0),
+        pio_encode_wait_gpio(true, 4),
+        pio_encode_wait_irq(false, true, 2),
+        pio_encode_in(pio_src_dest::pio_pins, 32),
+        pio_encode_out(pio_src_dest::pio_exec_out, 16),
+        pio_encode_push(true, false),
+        pio_encode_pull(true, true),
+        pio_encode_mov_not(pio_src_dest::pio_x, pio_src_dest::pio_status),
+        pio_encode_mov_reverse(pio_src_dest::pio_isr, pio_src_dest::pio_osr),
+        pio_encode_irq_wait(true, 1),
+        pio_encode_irq_clear(false, 5),
+        pio_encode_set(pio_src_dest::pio_pindirs, 31),
+        0xe001,
+    ];
+    assert_eq!(expected.map(|i| i as u16).to_vec(), program.instructions);
+    assert_eq!(Some(4), program.origin);
+    assert_eq!((10, 13), (program.wrap_target, program.wrap));
+    assert_eq!(vec![("start".to_string(), 0)], program.public_symbols);
+    assert_eq!(vec![("start".to_string(), 0)], program.public_labels);
+}
+#[test]
+fn assemble_round_trips_disassembly() {
+    let program = assemble_program(WS2812).unwrap();
+    let text = format!(".program again\n{}", disassemble(&program.instructions, &program.side_set));
+    assert_eq!(program.instructions, assemble_program(&text).unwrap().instructions);
+}
+#[test]
+fn assemble_multiple_programs_with_global_defines() {
+    let source = "
+        .define N 3
+        .program a
+            set x, N
+        .program b
+            .define N 7 ; programs may shadow global defines
+            set y, N
+    ";
+    let programs = assemble(source).unwrap();
+    assert_eq!(2, programs.len());
+    assert_eq!(vec![pio_encode_set(pio_src_dest::pio_x, 3) as u16], programs[0].instructions);
+    assert_eq!(vec![pio_encode_set(pio_src_dest::pio_y, 7) as u16], programs[1].instructions);
+}
+#[test]
+fn assemble_accepts_pio_versions_0_and_1() {
+    let version = |v: u32| assemble_program(&format!(".program p\n.pio_version {v}\nnop\n"));
+    assert_eq!(0, version(0).unwrap().pio_version);
+    assert_eq!(1, version(1).unwrap().pio_version);
+    assert!(version(2).is_err());
+}
+#[test]
+fn assemble_rejects_public_defines_past_32_bits() {
+    let err = assemble_program(".program p\n.define public BIG 0x100000000\nnop\n").unwrap_err();
+    assert_eq!(
+        PioAsmError { line: 2, column: 1, message: "public symbol 'BIG' value 4294967296 does not fit in 32 bits".to_string() },
+        err
+    );
+    let program = assemble_program(".program p\n.define public SMALL -5\nnop\n").unwrap();
+    assert_eq!(vec![("SMALL".to_string(), -5)], program.public_symbols);
+}
+#[test]
+fn assemble_errors_report_position() {
+    let err = |source: &str| assemble_program(source).unwrap_err();
+    assert_eq!(
+        PioAsmError { line: 3, column: 9, message: "undefined symbol 'nowhere'".to_string() },
+        err(".program p\n    nop\n    jmp nowhere\n")
+    );
+    assert_eq!((2, 8), {
+        let e = err(".program p\nset x, 32\n");
+        (e.line, e.column)
+    });
+    assert_eq!("instruction requires a side set value", err(".program p\n.side_set 1\nnop\n").message);
+    assert_eq!("delay 4 is out of range 0-3", err(".program p\n.side_set 2 opt\nnop [4]\n").message);
+    assert_eq!("program exceeds 32 instructions", err(&format!(".program p\n{}", "nop\n".repeat(33))).message);
+    assert_eq!("unknown instruction 'mv'", err(".program p\nmv x, y\n").message);
+}
@@ -0,0 +1,67 @@
+// Generated by piolib-rs from .pio sources; do not edit
+
+pub mod blink {
+    #![allow(dead_code, non_upper_case_globals)]
+    use ::piolib_rs::include::piolib::{pio_get_default_sm_config, pio_program, pio_sm_config, sm_config_set_wrap};
+
+    // ----- //
+    // blink //
+    // ----- //
+
+    pub const blink_wrap_target: u32 = 0;
+    pub const blink_wrap: u32 = 2;
+    pub const blink_pio_version: u32 = 0;
+
+    pub const blink_offset_top: u32 = 0;
+
+    pub const blink_program_instructions: [u16; 3] = [
+        //     .wrap_target
+        0xff01, //  0: set pins, 1 [31]
+        0xff00, //  1: set pins, 0 [31]
+        0x0000, //  2: jmp 0
+        //     .wrap
+    ];
+
+    pub const blink_program: pio_program<'static> = pio_program::from_parts(&blink_program_instructions, -1, 0);
+
+    pub fn blink_program_get_default_config(offset: u32) -> pio_sm_config {
+        let mut c = pio_get_default_sm_config();
+        sm_config_set_wrap(&mut c, offset + blink_wrap_target, offset + blink_wrap);
+        c
+    }
+}
+
+pub mod ws2812 {
+    #![allow(dead_code, non_upper_case_globals)]
+    use ::piolib_rs::include::piolib::{pio_get_default_sm_config, pio_program, pio_sm_config, sm_config_set_sideset, sm_config_set_wrap};
+
+    // ------ //
+    // ws2812 //
+    // ------ //
+
+    pub const ws2812_wrap_target: u32 = 0;
+    pub const ws2812_wrap: u32 = 3;
+    pub const ws2812_pio_version: u32 = 0;
+
+    pub const ws2812_T1: i32 = 2;
+    pub const ws2812_T2: i32 = 5;
+    pub const ws2812_T3: i32 = 3;
+
+    pub const ws2812_program_instructions: [u16; 4] = [
+        //     .wrap_target
+        0x6221, //  0: out x, 1 side 0 [2]
+        0x1123, //  1: jmp !x 3 side 1 [1]
+        0x1400, //  2: jmp 0 side 1 [4]
+        0xa442, //  3: nop side 0 [4]
+        //     .wrap
+    ];
+
+    pub const ws2812_program: pio_program<'static> = pio_program::from_parts(&ws2812_program_instructions, -1, 0);
+
+    pub fn ws2812_program_get_default_config(offset: u32) -> pio_sm_config {
+        let mut c = pio_get_default_sm_config();
+        sm_config_set_wrap(&mut c, offset + ws2812_wrap_target, offset + ws2812_wrap);
+        sm_config_set_sideset(&mut c, 1, false, false);
+        c
+    }
+}
@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use piolib_rs::include::piolib::{
+    pio_get_default_sm_config, sm_config_set_sideset, sm_config_set_wrap,
+};
+use piolib_rs::pioasm::build::{compile_pio_dir, PioBuildError};
+
+/// Output of `compile_pio_dir` for tests/pioasm_build, compiled here with warnings denied
+#[deny(warnings)]
+mod generated {
+    include!("pioasm_build/pio_programs.rs");
+}
+
+const WS2812: &str = "
+.program ws2812
+.side_set 1
+
+.define public T1 2
+.define public T2 5
+.define public T3 3
+
+.wrap_target
+bitloop:
+    out x, 1       side 0 [T3 - 1]
+    jmp !x do_zero side 1 [T1 - 1]
+do_one:
+    jmp  bitloop   side 1 [T2 - 1]
+do_zero:
+    nop            side 0 [T2 - 1]
+.wrap
+";
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("piolib-rs-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn compile_pio_dir_generates_module_per_file() {
+    let dir = scratch_dir("build");
+    fs::write(dir.join("ws2812.pio"), WS2812).unwrap();
+    fs::write(
+        dir.join("blink-led.pio"),
+        ".program blink\n.origin 3\npublic top:\n set pins, 1\n jmp top\n",
+    )
+    .unwrap();
+    fs::write(dir.join("notes.txt"), "not a program").unwrap();
+    let out = dir.join("pio_programs.rs");
+    let files = compile_pio_dir(&dir, &out).unwrap();
+    assert_eq!(
+        vec![dir.join("blink-led.pio"), dir.join("ws2812.pio")],
+        files
+    );
+
+    let generated = fs::read_to_string(&out).unwrap();
+    for line in [
+        "pub mod blink_led {",
+        "pub const blink_offset_top: u32 = 0;",
+        "pub const blink_program: pio_program<'static> = pio_program::from_parts(&blink_program_instructions, 3, 0);",
+        "pub mod ws2812 {",
+        "pub const ws2812_wrap_target: u32 = 0;",
+        "pub const ws2812_wrap: u32 = 3;",
+        "pub const ws2812_T1: i32 = 2;",
+        "pub const ws2812_program_instructions: [u16; 4] = [",
+        "0x6221, //  0: out x, 1 side 0 [2]",
+        "pub const ws2812_program: pio_program<'static> = pio_program::from_parts(&ws2812_program_instructions, -1, 0);",
+        "pub fn ws2812_program_get_default_config(offset: u32) -> pio_sm_config {",
+        "sm_config_set_wrap(&mut c, offset + ws2812_wrap_target, offset + ws2812_wrap);",
+        "sm_config_set_sideset(&mut c, 1, false, false);",
+    ] {
+        assert!(generated.contains(line), "missing {line:?} in\n{generated}");
+    }
+    // only ws2812 has a .side_set
+    assert_eq!(1, generated.matches("sm_config_set_sideset(&mut c").count());
+    fs::remove_dir_all(&dir).unwrap();
+}
+#[test]
+fn compile_pio_dir_reports_file_and_position() {
+    let dir = scratch_dir("build-error");
+    fs::write(dir.join("bad.pio"), ".program bad\n    set x, 32\n").unwrap();
+    let err = compile_pio_dir(&dir, dir.join("out.rs")).unwrap_err();
+    assert!(matches!(err, PioBuildError::Asm(ref path, _) if path.ends_with("bad.pio")));
+    assert!(
+        err.to_string()
+            .ends_with("bad.pio:2:12: set value 32 is out of range 0-31"),
+        "{err}"
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
+#[test]
+fn compile_pio_dir_rejects_clashing_modules() {
+    let dir = scratch_dir("build-clash");
+    let blink = ".program blink\n set pins, 1\n";
+    fs::write(dir.join("blink-led.pio"), blink).unwrap();
+    fs::write(dir.join("blink_led.pio"), blink).unwrap();
+    let err = compile_pio_dir(&dir, dir.join("out.rs")).unwrap_err();
+    assert!(
+        matches!(err, PioBuildError::ModuleClash(ref path, ref other, ref module)
+            if path.ends_with("blink_led.pio") && other.ends_with("blink-led.pio") && module == "blink_led"),
+        "{err}"
+    );
+    assert!(!dir.join("out.rs").exists());
+    fs::remove_dir_all(&dir).unwrap();
+}
+#[test]
+fn compile_pio_dir_escapes_keyword_modules() {
+    let dir = scratch_dir("build-keyword");
+    fs::write(dir.join("loop.pio"), ".program spin\n jmp 0\n").unwrap();
+    fs::write(dir.join("type.pio"), ".program kind\n nop\n").unwrap();
+    let out = dir.join("out.rs");
+    compile_pio_dir(&dir, &out).unwrap();
+    let generated = fs::read_to_string(&out).unwrap();
+    assert!(generated.contains("pub mod r#loop {"), "{generated}");
+    assert!(generated.contains("pub mod r#type {"), "{generated}");
+
+    fs::write(dir.join("self.pio"), ".program me\n nop\n").unwrap();
+    let err = compile_pio_dir(&dir, &out).unwrap_err();
+    assert!(
+        matches!(err, PioBuildError::ModuleName(ref path, _) if path.ends_with("self.pio")),
+        "{err}"
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
+#[test]
+fn generated_modules_compile_and_match_sources() {
+    let sources = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/pioasm_build");
+    let dir = scratch_dir("build-compile");
+    let out = dir.join("pio_programs.rs");
+    compile_pio_dir(&sources, &out).unwrap();
+    assert_eq!(
+        fs::read_to_string(sources.join("pio_programs.rs")).unwrap(),
+        fs::read_to_string(&out).unwrap(),
+        "tests/pioasm_build/pio_programs.rs is stale"
+    );
+    fs::remove_dir_all(&dir).unwrap();
+
+    use generated::{blink, ws2812};
+    assert_eq!(3, blink::blink_program.length());
+    assert_eq!(0, blink::blink_offset_top);
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_wrap(&mut c, 4, 6);
+    assert_eq!(c, blink::blink_program_get_default_config(4));
+    assert_eq!(5, ws2812::ws2812_T2);
+    sm_config_set_wrap(&mut c, 4, 7);
+    sm_config_set_sideset(&mut c, 1, false, false);
+    assert_eq!(c, ws2812::ws2812_program_get_default_config(4));
+}
@@ -0,0 +1,52 @@
+use piolib_rs::include::hardware::pio_instructions::*;
+use piolib_rs::pioasm::disassembler::*;
+
+#[test]
+fn disassemble_jmp_with_side_set_and_delay() {
+    let side_set = PioSideSet { bit_count: 1, optional: false, pindirs: false };
+    let instr = pio_encode_jmp_x_dec(3) | pio_encode_sideset(1, 1) | pio_encode_delay(2);
+    assert_eq!("jmp x-- 3 side 1 [2]", disassemble_instruction(instr as u16, &side_set));
+}
+#[test]
+fn disassemble_optional_side_set() {
+    let side_set = PioSideSet { bit_count: 1, optional: true, pindirs: false };
+    let with_side = pio_encode_out(pio_src_dest::pio_pins, 1) | pio_encode_sideset_opt(1, 0) | pio_encode_delay(1);
+    let without_side = pio_encode_nop() | pio_encode_delay(7);
+    assert_eq!("out pins, 1 side 0 [1]", disassemble_instruction(with_side as u16, &side_set));
+    assert_eq!("nop [7]", disassemble_instruction(without_side as u16, &side_set));
+}
+#[test]
+fn disassemble_every_opcode() {
+    let side_set = PioSideSet::default();
+    let cases = [
+        (pio_encode_jmp(0), "jmp 0"),
+        (pio_encode_jmp_not_osre(31), "jmp !osre 31"),
+        (pio_encode_wait_gpio(true, 4), "wait 1 gpio 4"),
+        (pio_encode_wait_irq(false, true, 2), "wait 0 irq 2 rel"),
+        (pio_encode_in(pio_src_dest::pio_pins, 32), "in pins, 32"),
+        (pio_encode_out(pio_src_dest::pio_pindirs, 2), "out pindirs, 2"),
+        (pio_encode_push(true, true), "push iffull block"),
+        (pio_encode_pull(false, false), "pull noblock"),
+        (pio_encode_mov_not(pio_src_dest::pio_x, pio_src_dest::pio_status), "mov x, !status"),
+        (pio_encode_mov_reverse(pio_src_dest::pio_pins, pio_src_dest::pio_isr), "mov pins, ::isr"),
+        (pio_encode_irq_wait(true, 1), "irq wait 1 rel"),
+        (pio_encode_irq_set(false, 7), "irq nowait 7"),
+        (pio_encode_set(pio_src_dest::pio_y, 9), "set y, 9"),
+    ];
+    for (instr, text) in cases {
+        assert_eq!(text, disassemble_instruction(instr as u16, &side_set));
+    }
+    assert_eq!(".word 0xe060", disassemble_instruction(0xe060, &side_set));
+}
+#[test]
+fn disassemble_program_emits_side_set_directive() {
+    let side_set = PioSideSet { bit_count: 2, optional: true, pindirs: true };
+    let program = [
+        (pio_encode_set(pio_src_dest::pio_x, 1) | pio_encode_sideset_opt(2, 3)) as u16,
+        pio_encode_jmp(0) as u16,
+    ];
+    assert_eq!(
+        ".side_set 2 opt pindirs\n    set x, 1 side 3\n    jmp 0\n",
+        disassemble(&program, &side_set)
+    );
+}
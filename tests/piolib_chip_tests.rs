@@ -0,0 +1,203 @@
+use piolib_rs::include::hardware::clocks::clock_index;
+use piolib_rs::include::hardware::gpio::{gpio_drive_strength, gpio_function, gpio_override};
+use piolib_rs::include::piolib::{pio_program, pio_sm_config, pio_xfer_dir};
+use piolib_rs::piolib::chip::{PioChip, PioChipInfo};
+use piolib_rs::piolib::error::{PioError, PioResult};
+
+const MOCK_INFO: PioChipInfo = PioChipInfo {
+    name: "mock",
+    compatible: "test,mock-pio",
+    instr_count: 32,
+    sm_count: 4,
+    fifo_depth: 4,
+};
+
+/// Records the calls the default trait methods forward to; the rest are `Unsupported`
+#[derive(Default)]
+struct MockChip {
+    claimed: u32,
+    calls: Vec<String>,
+}
+
+impl PioChip for MockChip {
+    fn info(&self) -> &PioChipInfo {
+        &MOCK_INFO
+    }
+    fn sm_config_xfer(&mut self, _: u32, _: pio_xfer_dir, _: u32, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_xfer_data(&mut self, _: u32, _: pio_xfer_dir, _: &mut [u8]) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn can_add_program_at_offset(&mut self, _: &pio_program, _: Option<u8>) -> PioResult<bool> {
+        Err(PioError::Unsupported)
+    }
+    fn add_program_at_offset(&mut self, _: &pio_program, _: Option<u8>) -> PioResult<u8> {
+        Err(PioError::Unsupported)
+    }
+    fn remove_program(&mut self, _: &pio_program, _: u8) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn clear_instruction_memory(&mut self) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_claim_mask(&mut self, mask: u32) -> PioResult<()> {
+        if self.claimed & mask != 0 {
+            return Err(PioError::SmAlreadyClaimed);
+        }
+        self.claimed |= mask;
+        Ok(())
+    }
+    fn sm_unclaim(&mut self, sm: u32) -> PioResult<()> {
+        self.claimed &= !(1 << sm);
+        Ok(())
+    }
+    fn sm_is_claimed(&mut self, sm: u32) -> PioResult<bool> {
+        Ok(self.claimed & (1 << sm) != 0)
+    }
+    fn sm_init(&mut self, _: u32, _: u32, _: &pio_sm_config) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_set_config(&mut self, _: u32, _: &pio_sm_config) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_exec(&mut self, _: u32, _: u16, _: bool) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_clear_fifos(&mut self, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_set_clkdiv_int_frac(&mut self, sm: u32, div_int: u16, div_frac: u8) -> PioResult<()> {
+        self.calls.push(format!("clkdiv {sm} {div_int} {div_frac}"));
+        Ok(())
+    }
+    fn sm_set_pins_with_mask(&mut self, sm: u32, values: u32, mask: u32) -> PioResult<()> {
+        self.calls.push(format!("pins {sm} {values:#x} {mask:#x}"));
+        Ok(())
+    }
+    fn sm_set_pindirs_with_mask(&mut self, sm: u32, dirs: u32, mask: u32) -> PioResult<()> {
+        self.calls.push(format!("pindirs {sm} {dirs:#x} {mask:#x}"));
+        Ok(())
+    }
+    fn sm_set_enabled_mask(&mut self, mask: u32, enabled: bool) -> PioResult<()> {
+        self.calls.push(format!("enabled {mask:#x} {enabled}"));
+        Ok(())
+    }
+    fn sm_restart_mask(&mut self, mask: u32) -> PioResult<()> {
+        self.calls.push(format!("restart {mask:#x}"));
+        Ok(())
+    }
+    fn sm_clkdiv_restart_mask(&mut self, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_enable_sync(&mut self, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_put(&mut self, _: u32, _: u32, _: bool) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_get(&mut self, _: u32, _: bool) -> PioResult<u32> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_set_dmactrl(&mut self, _: u32, _: bool, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_is_rx_fifo_empty(&mut self, _: u32) -> PioResult<bool> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_is_rx_fifo_full(&mut self, _: u32) -> PioResult<bool> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_get_rx_fifo_level(&mut self, _: u32) -> PioResult<u32> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_is_tx_fifo_empty(&mut self, _: u32) -> PioResult<bool> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_is_tx_fifo_full(&mut self, _: u32) -> PioResult<bool> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_get_tx_fifo_level(&mut self, _: u32) -> PioResult<u32> {
+        Err(PioError::Unsupported)
+    }
+    fn sm_drain_tx_fifo(&mut self, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn clock_get_hz(&mut self, _: clock_index) -> PioResult<u32> {
+        Err(PioError::Unsupported)
+    }
+    fn pio_gpio_init(&mut self, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_init(&mut self, _: u32) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_set_function(&mut self, _: u32, _: gpio_function) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_set_pulls(&mut self, _: u32, _: bool, _: bool) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_set_outover(&mut self, _: u32, _: gpio_override) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_set_inover(&mut self, _: u32, _: gpio_override) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_set_oeover(&mut self, _: u32, _: gpio_override) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_set_input_enabled(&mut self, _: u32, _: bool) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+    fn gpio_set_drive_strength(&mut self, _: u32, _: gpio_drive_strength) -> PioResult<()> {
+        Err(PioError::Unsupported)
+    }
+}
+
+#[test]
+fn pio_chip_claims_through_mask() {
+    let mut chip = MockChip::default();
+    chip.sm_claim(1).unwrap();
+    assert_eq!(Err(PioError::SmAlreadyClaimed), chip.sm_claim(1));
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "sm" }),
+        chip.sm_claim(4)
+    );
+    assert_eq!(Ok(0), chip.sm_claim_unused());
+    assert_eq!(Ok(2), chip.sm_claim_unused());
+    assert_eq!(Ok(3), chip.sm_claim_unused());
+    assert_eq!(Err(PioError::SmAlreadyClaimed), chip.sm_claim_unused());
+    chip.sm_unclaim(2).unwrap();
+    assert_eq!(Ok(2), chip.sm_claim_unused());
+}
+#[test]
+fn pio_chip_default_methods_forward() {
+    let mut chip = MockChip::default();
+    chip.sm_set_clkdiv(0, 2.5).unwrap();
+    chip.sm_set_clkdiv(0, 0.0).unwrap();
+    chip.sm_set_pins(1, 0x5).unwrap();
+    chip.sm_set_consecutive_pindirs(2, 30, 4, true).unwrap();
+    chip.sm_set_consecutive_pindirs(2, 4, 2, false).unwrap();
+    chip.sm_set_enabled(3, true).unwrap();
+    chip.sm_restart(1).unwrap();
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "pin_base" }),
+        chip.sm_set_consecutive_pindirs(0, 32, 1, true)
+    );
+    assert_eq!(
+        vec![
+            "clkdiv 0 2 128",
+            "clkdiv 0 0 0",
+            "pins 1 0x5 0xffffffff",
+            "pindirs 2 0xc0000003 0xc0000003",
+            "pindirs 2 0x0 0x30",
+            "enabled 0x8 true",
+            "restart 0x2",
+        ],
+        chip.calls
+    );
+    let mut chip: Box<dyn PioChip> = Box::new(chip);
+    assert_eq!("mock", chip.info().name);
+    chip.close().unwrap();
+}
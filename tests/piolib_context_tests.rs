@@ -0,0 +1,109 @@
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use piolib_rs::include::hardware::clocks::clock_index;
+use piolib_rs::include::hardware::gpio::gpio_function;
+use piolib_rs::include::piolib::*;
+use piolib_rs::include::rp1_pio_if::*;
+use piolib_rs::piolib::instance::Pio;
+use piolib_rs::piolib::pio_rp1::{Rp1Pio, Rp1PioDevice};
+
+type Log = Arc<Mutex<Vec<(u32, Vec<u8>)>>>;
+
+/// Device that accepts every request and logs it
+struct RecordingDevice(Log);
+
+impl Rp1PioDevice for RecordingDevice {
+    fn ioctl(&mut self, request: u32, arg: &mut [u8]) -> io::Result<i32> {
+        self.0.lock().unwrap().push((request, arg.to_vec()));
+        Ok(0)
+    }
+}
+
+fn recording_pio(index: u32) -> (Pio, Log) {
+    let log = Log::default();
+    let pio = Pio::from_chip(Rp1Pio::with_device(RecordingDevice(log.clone())), index);
+    (pio, log)
+}
+
+#[test]
+fn gpio_helpers_use_the_pio_passed_in() {
+    let (mut pio0, log0) = recording_pio(0);
+    let (mut pio1, log1) = recording_pio(1);
+    gpio_init(&mut pio0, 5).unwrap();
+    pio_gpio_init(&mut pio1, 6).unwrap();
+    gpio_pull_up(&mut pio1, 6).unwrap();
+    gpio_set_function(&mut pio0, 7, gpio_function::GPIO_FUNC_SIO).unwrap();
+
+    let expected0 = vec![
+        (
+            PIO_IOC_GPIO_INIT,
+            rp1_gpio_init_args { gpio: 5 }.as_bytes().to_vec(),
+        ),
+        (
+            PIO_IOC_GPIO_SET_FUNCTION,
+            rp1_gpio_set_function_args {
+                gpio: 7,
+                func: gpio_function::GPIO_FUNC_SIO as u16,
+            }
+            .as_bytes()
+            .to_vec(),
+        ),
+    ];
+    let expected1 = vec![
+        (
+            PIO_IOC_GPIO_SET_FUNCTION,
+            rp1_gpio_set_function_args {
+                gpio: 6,
+                func: RP1_GPIO_FUNC_PIO,
+            }
+            .as_bytes()
+            .to_vec(),
+        ),
+        (
+            PIO_IOC_GPIO_SET_PULLS,
+            rp1_gpio_set_pulls_args {
+                gpio: 6,
+                up: 1,
+                down: 0,
+            }
+            .as_bytes()
+            .to_vec(),
+        ),
+    ];
+    assert_eq!(expected0, *log0.lock().unwrap());
+    assert_eq!(expected1, *log1.lock().unwrap());
+    assert_eq!(
+        Ok(200_000_000),
+        clock_get_hz(&mut pio0, clock_index::clk_sys)
+    );
+    assert_eq!(
+        pio_get_default_sm_config(),
+        pio_get_default_sm_config_for_pio(&pio1)
+    );
+}
+#[test]
+fn pio_instances_on_separate_threads() {
+    let handles: Vec<_> = (0..2)
+        .map(|index| {
+            let (mut pio, log) = recording_pio(index);
+            let handle = thread::spawn(move || {
+                for gpio in 0..10 {
+                    gpio_init(&mut pio, gpio + 10 * index).unwrap();
+                }
+            });
+            (handle, log, index)
+        })
+        .collect();
+    for (handle, log, index) in handles {
+        handle.join().unwrap();
+        let gpios: Vec<_> = log
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, arg)| rp1_gpio_init_args::from_bytes(arg).unwrap().gpio as u32)
+            .collect();
+        assert_eq!((10 * index..10 * index + 10).collect::<Vec<_>>(), gpios);
+    }
+}
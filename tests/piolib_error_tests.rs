@@ -0,0 +1,114 @@
+use std::error::Error;
+use std::io;
+
+use piolib_rs::include::piolib::*;
+use piolib_rs::include::rp1_pio_if::*;
+use piolib_rs::piolib::error::PioError;
+use piolib_rs::piolib::instance::Pio;
+use piolib_rs::piolib::pio_rp1::{Rp1Pio, Rp1PioDevice};
+
+const EBUSY: i32 = 16;
+const ENOTTY: i32 = 25;
+
+/// Driver that succeeds with `ret` unless the request is `failing`, which fails with `errno`
+struct ScriptedDevice {
+    failing: u32,
+    errno: i32,
+    ret: i32,
+}
+
+impl Rp1PioDevice for ScriptedDevice {
+    fn ioctl(&mut self, request: u32, _arg: &mut [u8]) -> io::Result<i32> {
+        if request == self.failing {
+            Err(io::Error::from_raw_os_error(self.errno))
+        } else {
+            Ok(self.ret)
+        }
+    }
+}
+
+fn scripted_pio(failing: u32, errno: i32, ret: i32) -> Pio {
+    Pio::from_chip(
+        Rp1Pio::with_device(ScriptedDevice {
+            failing,
+            errno,
+            ret,
+        }),
+        0,
+    )
+}
+
+const PROGRAM: pio_program = pio_program::from_parts(&[0xe081, 0x0001], -1, 0);
+
+#[test]
+fn errors_are_returned_and_sticky() {
+    let mut pio = scripted_pio(PIO_IOC_ADD_PROGRAM, EBUSY, 0);
+    assert!(!pio_get_error(&pio));
+    assert_eq!(
+        Err(PioError::NoProgramSpace),
+        pio_add_program(&mut pio, &PROGRAM)
+    );
+    assert!(pio_get_error(&pio));
+    assert_eq!(Ok(()), pio_sm_put(&mut pio, 0, 1));
+    assert_eq!(Some(PioError::NoProgramSpace), pio.last_error());
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "sm" }),
+        pio_sm_claim(&mut pio, 4)
+    );
+    assert_eq!(
+        Some(PioError::InvalidParam { which: "sm" }),
+        pio.last_error()
+    );
+    pio_clear_error(&mut pio);
+    assert!(!pio_get_error(&pio));
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "offset" }),
+        pio_add_program_at_offset(&mut pio, &PROGRAM, 256)
+    );
+    assert!(pio_get_error(&pio));
+}
+
+#[test]
+fn unknown_ioctl_is_a_driver_version_error() {
+    let mut pio = scripted_pio(PIO_IOC_SM_DRAIN_TX, ENOTTY, 0);
+    assert_eq!(
+        Err(PioError::DriverVersion),
+        pio_sm_drain_tx_fifo(&mut pio, 1)
+    );
+    assert_eq!(
+        "driver version mismatch",
+        PioError::DriverVersion.to_string()
+    );
+}
+
+#[test]
+fn claim_unused_sm_only_fails_when_required() {
+    let mut pio = scripted_pio(PIO_IOC_SM_CLAIM, EBUSY, 1);
+    assert_eq!(Ok(None), pio_claim_unused_sm(&mut pio, false));
+    assert!(!pio_get_error(&pio));
+    assert_eq!(
+        Err(PioError::SmAlreadyClaimed),
+        pio_claim_unused_sm(&mut pio, true)
+    );
+    assert!(pio_get_error(&pio));
+}
+
+#[test]
+#[should_panic(expected = "PIOLIB fatal error: state machine already claimed")]
+fn fatal_errors_panic() {
+    let mut pio = scripted_pio(PIO_IOC_SM_CLAIM, EBUSY, 0);
+    pio_enable_fatal_errors(&mut pio, true);
+    assert!(pio.errors_are_fatal());
+    let _ = pio_sm_claim(&mut pio, 0);
+}
+
+#[test]
+fn program_errors_convert() {
+    let err = PioError::from(PioProgramError::Empty);
+    assert_eq!(PioError::InvalidProgram(PioProgramError::Empty), err);
+    assert_eq!(
+        format!("invalid program: {}", PioProgramError::Empty),
+        err.to_string()
+    );
+    assert!(err.source().is_some());
+}
@@ -0,0 +1,51 @@
+use std::io;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use piolib_rs::piolib::error::PioError;
+use piolib_rs::piolib::instance::Pio;
+use piolib_rs::piolib::pio_rp1::{Rp1Pio, Rp1PioDevice};
+
+/// Device that accepts every request and counts how often it is released
+struct CountingDevice(Arc<AtomicUsize>);
+
+impl Rp1PioDevice for CountingDevice {
+    fn ioctl(&mut self, _: u32, _: &mut [u8]) -> io::Result<i32> {
+        Ok(0)
+    }
+}
+
+impl Drop for CountingDevice {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn pio_reports_chip_descriptor() {
+    let released = Arc::new(AtomicUsize::new(0));
+    let pio = Pio::from_chip(Rp1Pio::with_device(CountingDevice(released.clone())), 0);
+    assert_eq!(0, pio.index());
+    assert_eq!("rp1", pio.info().name);
+    assert_eq!(4, pio.sm_count());
+    assert_eq!(32, pio.instruction_count());
+    assert_eq!(8, pio.fifo_depth());
+    assert_eq!("Pio { chip: \"rp1\", index: 0 }", format!("{pio:?}"));
+    drop(pio);
+    assert_eq!(1, released.load(Ordering::SeqCst));
+}
+#[test]
+fn pio_close_releases_once() {
+    let released = Arc::new(AtomicUsize::new(0));
+    let mut pio = Pio::from_chip(Rp1Pio::with_device(CountingDevice(released.clone())), 1);
+    pio.chip_mut().sm_claim(0).unwrap();
+    pio.close().unwrap();
+    assert_eq!(1, released.load(Ordering::SeqCst));
+}
+#[test]
+fn pio_open_missing_device() {
+    assert_eq!(PioError::Io(2), Pio::open(99).unwrap_err());
+    if !std::path::Path::new("/dev/pio0").exists() {
+        assert_eq!(PioError::Io(2), Pio::open_by_name("rp1").unwrap_err());
+    }
+}
@@ -0,0 +1,51 @@
+use piolib_rs::include::piolib::pio_program;
+use piolib_rs::piolib::instr_mem::PioInstrMem;
+
+const FOUR: [u16; 4] = [0xe001; 4];
+
+#[test]
+fn instr_mem_allocates_from_the_top() {
+    let program = pio_program::new(&FOUR, None, 0).unwrap();
+    let mut mem = PioInstrMem::new();
+    assert_eq!(Some(28), mem.add_program_at_offset(&program, None));
+    assert_eq!(Some(24), mem.add_program_at_offset(&program, None));
+    assert_eq!(0xff00_0000, mem.used_mask());
+    assert!(mem.remove_program(&program, 28));
+    assert_eq!(Some(28), mem.find_offset(&program));
+    assert!(!mem.remove_program(&program, 28));
+    mem.clear_instruction_memory();
+    assert_eq!(PioInstrMem::new(), mem);
+}
+#[test]
+fn instr_mem_skips_used_ranges() {
+    let program = pio_program::new(&FOUR, None, 0).unwrap();
+    let mut mem = PioInstrMem::from_used_mask(0xf0f0_f0f1);
+    assert_eq!(Some(24), mem.find_offset(&program));
+    assert!(!mem.can_add_program_at_offset(&program, Some(26)));
+    assert!(mem.can_add_program_at_offset(&program, Some(8)));
+    assert_eq!(Some(8), mem.add_program_at_offset(&program, Some(8)));
+    mem = PioInstrMem::from_used_mask(0x7777_7777);
+    assert!(!mem.can_add_program_at_offset(&program, None));
+    assert_eq!(None, mem.add_program_at_offset(&program, None));
+    assert_eq!(0x7777_7777, mem.used_mask());
+}
+#[test]
+fn instr_mem_honours_fixed_origin() {
+    let program = pio_program::new(&FOUR, Some(2), 0).unwrap();
+    let mut mem = PioInstrMem::new();
+    assert!(!mem.can_add_program_at_offset(&program, Some(3)));
+    assert_eq!(None, mem.add_program_at_offset(&program, Some(3)));
+    assert_eq!(Some(2), mem.add_program_at_offset(&program, None));
+    assert_eq!(0x3c, mem.used_mask());
+    assert_eq!(None, mem.add_program_at_offset(&program, None));
+}
+#[test]
+fn instr_mem_full_size_program() {
+    let program = pio_program::new(&[0xa042; 32], None, 0).unwrap();
+    let mut mem = PioInstrMem::new();
+    assert_eq!(Some(0), mem.add_program_at_offset(&program, None));
+    assert_eq!(u32::MAX, mem.used_mask());
+    assert!(!mem.can_add_program_at_offset(&pio_program::new(&[0], None, 0).unwrap(), None));
+    assert!(mem.remove_program(&program, 0));
+    assert_eq!(0, mem.used_mask());
+}
@@ -0,0 +1,182 @@
+use std::collections::VecDeque;
+
+use piolib_rs::include::hardware::pio_instructions::{
+    pio_encode_jmp, pio_encode_set, pio_src_dest,
+};
+use piolib_rs::include::hardware::regs::proc_pio::*;
+use piolib_rs::include::piolib::*;
+use piolib_rs::piolib::chip::PioChip;
+use piolib_rs::piolib::error::PioError;
+use piolib_rs::piolib::pio_mmio::{MemoryRegion, MmioPio, PROC_PIO_REGS_SIZE};
+use piolib_rs::piolib::regs::*;
+
+/// Register file standing in for the PIO block, with FIFOs behind TXFn/RXFn
+struct FakeRegisters {
+    words: Vec<u32>,
+    tx_fifos: [VecDeque<u32>; 4],
+    rx_fifos: [VecDeque<u32>; 4],
+    executed: Vec<(u32, u16)>,
+}
+
+impl Default for FakeRegisters {
+    fn default() -> Self {
+        FakeRegisters {
+            words: vec![0; PROC_PIO_REGS_SIZE / 4],
+            tx_fifos: Default::default(),
+            rx_fifos: Default::default(),
+            executed: Vec::new(),
+        }
+    }
+}
+
+impl FakeRegisters {
+    fn reg(&self, offset: u32) -> u32 {
+        self.words[offset as usize / 4]
+    }
+}
+
+impl MemoryRegion for FakeRegisters {
+    fn read32(&mut self, offset: u32) -> u32 {
+        match offset {
+            PROC_PIO_FSTAT_OFFSET => (0..4).fold(0, |fstat, sm| {
+                let tx = self.tx_fifos[sm].len();
+                let rx = self.rx_fifos[sm].len();
+                fstat
+                    | ((tx == 0) as u32) << (PROC_PIO_FSTAT_TXEMPTY_LSB as usize + sm)
+                    | ((tx >= 4) as u32) << (PROC_PIO_FSTAT_TXFULL_LSB as usize + sm)
+                    | ((rx == 0) as u32) << (PROC_PIO_FSTAT_RXEMPTY_LSB as usize + sm)
+                    | ((rx >= 4) as u32) << (PROC_PIO_FSTAT_RXFULL_LSB as usize + sm)
+            }),
+            PROC_PIO_FLEVEL_OFFSET | PROC_PIO_FLEVEL2_OFFSET => (0..4).fold(0, |flevel, sm| {
+                let (tx, rx) = (self.tx_fifos[sm].len(), self.rx_fifos[sm].len());
+                let (tx, rx) = match offset {
+                    PROC_PIO_FLEVEL_OFFSET => (tx & 0xf, rx & 0xf),
+                    _ => (tx >> 4, rx >> 4),
+                };
+                flevel | ((tx | rx << 4) as u32) << (8 * sm)
+            }),
+            PROC_PIO_RXF0_OFFSET..=PROC_PIO_RXF3_OFFSET => {
+                let sm = (offset - PROC_PIO_RXF0_OFFSET) as usize / 4;
+                self.rx_fifos[sm].pop_front().unwrap_or(0)
+            }
+            _ => self.reg(offset),
+        }
+    }
+
+    fn write32(&mut self, offset: u32, value: u32) {
+        let sm = offset.wrapping_sub(PROC_PIO_SM0_CLKDIV_OFFSET) / PROC_PIO_SM_STRIDE;
+        match offset {
+            PROC_PIO_TXF0_OFFSET..=PROC_PIO_TXF3_OFFSET => {
+                let sm = (offset - PROC_PIO_TXF0_OFFSET) as usize / 4;
+                self.tx_fifos[sm].push_back(value);
+            }
+            _ if sm < 4 && offset == SmInstr::offset(sm) => self.executed.push((sm, value as u16)),
+            _ if sm < 4 && offset == SmShiftCtrl::offset(sm) => {
+                if (self.reg(offset) ^ value) & PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS != 0 {
+                    self.tx_fifos[sm as usize].clear();
+                    self.rx_fifos[sm as usize].clear();
+                }
+                self.words[offset as usize / 4] = value;
+            }
+            _ => self.words[offset as usize / 4] = value,
+        }
+    }
+}
+
+fn fake_pio() -> MmioPio<FakeRegisters> {
+    MmioPio::with_region(FakeRegisters::default())
+}
+
+#[test]
+fn mmio_programs_are_written_relocated() {
+    let mut pio = fake_pio();
+    let program = pio_program::new(&[0xe001, 0x0000, 0x0001], None, 0).unwrap();
+    assert_eq!(Ok(29), pio.add_program_at_offset(&program, None));
+    let regs = pio.region();
+    let loaded: Vec<_> = (29..32)
+        .map(|addr| regs.reg(InstrMem::offset(addr)))
+        .collect();
+    assert_eq!(vec![0xe001, 29, 30], loaded);
+    assert_eq!(
+        Err(PioError::NoProgramSpace),
+        pio.add_program_at_offset(&program, Some(30))
+    );
+    pio.remove_program(&program, 29).unwrap();
+    assert_eq!(Ok(true), pio.can_add_program_at_offset(&program, Some(29)));
+
+    pio.sm_claim(1).unwrap();
+    assert_eq!(Err(PioError::SmAlreadyClaimed), pio.sm_claim_mask(0b11));
+    assert_eq!(Ok(0), pio.sm_claim_unused());
+}
+
+#[test]
+fn mmio_sm_init_writes_config_and_jumps() {
+    let mut pio = fake_pio();
+    pio.sm_set_enabled_mask(0b1010, true).unwrap();
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_wrap(&mut c, 3, 9);
+    sm_config_set_clkdiv_int_frac(&mut c, 5, 128);
+    pio.sm_put(1, 0x1234, false).unwrap();
+    pio.sm_init(1, 3, &c).unwrap();
+
+    let regs = pio.region();
+    assert_eq!(c.clkdiv(), regs.reg(SmClkdiv::offset(1)));
+    assert_eq!(c.execctrl(), regs.reg(SmExecCtrl::offset(1)));
+    assert_eq!(c.shiftctrl(), regs.reg(SmShiftCtrl::offset(1)));
+    assert_eq!(c.pinctrl(), regs.reg(SmPinCtrl::offset(1)));
+    assert!(regs.tx_fifos[1].is_empty());
+    let ctrl = Ctrl(regs.reg(Ctrl::OFFSET));
+    assert!(!ctrl.sm_enable(1) && ctrl.sm_enable(3));
+    assert_eq!(0x0202_0202 & PROC_PIO_FDEBUG_BITS, regs.reg(Fdebug::OFFSET));
+    assert_eq!(vec![(1, pio_encode_jmp(3) as u16)], regs.executed);
+}
+
+#[test]
+fn mmio_fifos_pins_and_gpio() {
+    let mut pio = fake_pio();
+    pio.sm_put(2, 7, true).unwrap();
+    pio.region_mut().rx_fifos[2].extend(0..17);
+    assert_eq!(Ok(1), pio.sm_get_tx_fifo_level(2));
+    assert_eq!(Ok(17), pio.sm_get_rx_fifo_level(2));
+    assert_eq!(Ok(true), pio.sm_is_rx_fifo_full(2));
+    assert_eq!(Ok(0), pio.sm_get(2, true));
+    let mut data = [0; 8];
+    pio.sm_xfer_data(2, pio_xfer_dir::PIO_DIR_FROM_SM, &mut data)
+        .unwrap();
+    assert_eq!([1, 0, 0, 0, 2, 0, 0, 0], data);
+
+    pio.region_mut().words[SmPinCtrl::offset(0) as usize / 4] = 0x1234_5678;
+    pio.sm_set_pins_with_mask(0, 0b1000, 0b1001).unwrap();
+    assert_eq!(0x1234_5678, pio.region().reg(SmPinCtrl::offset(0)));
+    assert_eq!(
+        vec![
+            (0, pio_encode_set(pio_src_dest::pio_pins, 0) as u16),
+            (0, pio_encode_set(pio_src_dest::pio_pins, 1) as u16),
+        ],
+        pio.region().executed
+    );
+
+    assert_eq!(Err(PioError::Unsupported), pio.pio_gpio_init(4));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn mmio_region_maps_offsets_past_4gib() {
+    use piolib_rs::piolib::pio_mmio::MmioRegion;
+    use std::io::{Seek, SeekFrom, Write};
+
+    // RP1 sits above 4 GiB too, so this needs the 64-bit mmap offset on 32-bit targets
+    let phys_base = 0x1_0000_1010;
+    let path = std::env::temp_dir().join(format!("piolib-rs-mmio-{}", std::process::id()));
+    let mut file = std::fs::File::create(&path).unwrap();
+    file.set_len(phys_base + 0x1000).unwrap();
+    file.seek(SeekFrom::Start(phys_base)).unwrap();
+    file.write_all(&0x1234_5678u32.to_ne_bytes()).unwrap();
+    drop(file);
+    let mut region = MmioRegion::map(&path, phys_base, 8).unwrap();
+    assert_eq!(0x1234_5678, region.read32(0));
+    region.write32(4, 0xabcd);
+    assert_eq!(0xabcd, region.read32(4));
+    drop(region);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,109 @@
+use piolib_rs::include::piolib::*;
+
+const PROGRAM: [u16; 4] = [0x6221, 0x1123, 0x1400, 0xa442];
+
+#[test]
+fn pio_program_new_any_origin() {
+    let program = pio_program::new(&PROGRAM, None, 0).unwrap();
+    assert_eq!(4, program.length());
+    assert_eq!(None, program.origin());
+    assert_eq!(PIO_ORIGIN_ANY, program.origin_raw());
+    assert_eq!(0, program.pio_version());
+    assert_eq!(&PROGRAM, program.instructions());
+    assert_eq!(PROGRAM.to_vec(), program.iter().collect::<Vec<_>>());
+    assert_eq!(PROGRAM.to_vec(), (&program).into_iter().collect::<Vec<_>>());
+    assert_eq!(pio_program::from_parts(&PROGRAM, -1, 0), program);
+}
+#[test]
+fn pio_program_new_fixed_origin() {
+    const FIXED: pio_program = match pio_program::new(&PROGRAM, Some(28), 1) {
+        Ok(program) => program,
+        Err(_) => panic!(),
+    };
+    assert_eq!(Some(28), FIXED.origin());
+    assert_eq!(28, FIXED.origin_raw());
+    assert_eq!(1, FIXED.pio_version());
+}
+#[test]
+fn pio_program_new_rejects() {
+    assert_eq!(Err(PioProgramError::Empty), pio_program::new(&[], None, 0));
+    assert_eq!(
+        Err(PioProgramError::TooLong { length: 33 }),
+        pio_program::new(&[0; 33], None, 0)
+    );
+    assert!(pio_program::new(&[0; 32], Some(0), 0).is_ok());
+    assert_eq!(
+        Err(PioProgramError::OriginOutOfRange {
+            origin: 29,
+            length: 4
+        }),
+        pio_program::new(&PROGRAM, Some(29), 0)
+    );
+    assert_eq!(
+        Err(PioProgramError::UnsupportedPioVersion(2)),
+        pio_program::new(&PROGRAM, None, 2)
+    );
+}
+#[test]
+fn pio_program_relocated_shifts_jmp_targets() {
+    use piolib_rs::include::hardware::pio_instructions::*;
+    let instructions = [
+        pio_encode_jmp_not_x(3) | pio_encode_sideset(1, 1) | pio_encode_delay(1),
+        pio_encode_jmp(0),
+        pio_encode_set(pio_src_dest::pio_x, 1),
+        pio_encode_out(pio_src_dest::pio_pc, 5),
+    ]
+    .map(|i| i as u16);
+    let program = pio_program::new(&instructions, None, 0).unwrap();
+    assert_eq!(instructions.to_vec(), program.relocated(0).unwrap());
+    let expected = [
+        pio_encode_jmp_not_x(31) | pio_encode_sideset(1, 1) | pio_encode_delay(1),
+        pio_encode_jmp(28),
+        pio_encode_set(pio_src_dest::pio_x, 1),
+        pio_encode_out(pio_src_dest::pio_pc, 5),
+    ];
+    assert_eq!(
+        expected.map(|i| i as u16).to_vec(),
+        program.relocated(28).unwrap()
+    );
+    assert_eq!(
+        Err(PioProgramError::OriginOutOfRange {
+            origin: 29,
+            length: 4
+        }),
+        program.relocated(29)
+    );
+}
+#[test]
+fn pio_program_relocated_honours_origin() {
+    let program = pio_program::new(&PROGRAM, Some(8), 0).unwrap();
+    assert!(program.relocated(8).is_ok());
+    assert_eq!(
+        Err(PioProgramError::OriginMismatch {
+            origin: 8,
+            offset: 4
+        }),
+        program.relocated(4)
+    );
+}
+#[test]
+fn pio_relocate_program_moves_wrap() {
+    let program = pio_program::new(&PROGRAM, None, 0).unwrap();
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_wrap(&mut c, 1, 3);
+    sm_config_set_sideset(&mut c, 1, false, false);
+    let image = pio_relocate_program(&program, 10, &mut c).unwrap();
+    assert_eq!(vec![0x6221, 0x112d, 0x140a, 0xa442], image);
+    let mut expected = pio_get_default_sm_config();
+    sm_config_set_wrap(&mut expected, 11, 13);
+    sm_config_set_sideset(&mut expected, 1, false, false);
+    assert_eq!(expected, c);
+
+    // the default wrap of 31 has nowhere to move to
+    let mut c = pio_get_default_sm_config();
+    assert_eq!(
+        Err(PioProgramError::WrapOutOfRange { wrap: 31, offset: 1 }),
+        pio_relocate_program(&program, 1, &mut c)
+    );
+    assert_eq!(pio_get_default_sm_config(), c);
+}
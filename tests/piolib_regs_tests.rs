@@ -0,0 +1,92 @@
+use piolib_rs::include::hardware::regs::proc_pio::*;
+use piolib_rs::piolib::regs::*;
+
+#[test]
+fn per_sm_registers_are_offset_by_sm() {
+    assert_eq!(SmClkdiv::offset(0), PROC_PIO_SM0_CLKDIV_OFFSET);
+    assert_eq!(SmExecCtrl::offset(1), PROC_PIO_SM1_EXECCTRL_OFFSET);
+    assert_eq!(SmPinCtrl::offset(3), PROC_PIO_SM3_PINCTRL_OFFSET);
+    assert_eq!(SmDmaCtrlRx::offset(2), PROC_PIO_SM2_DMACTRL_RX_OFFSET);
+    assert_eq!(IrqInts::offset(1), PROC_PIO_IRQ1_INTS_OFFSET);
+    assert_eq!(InstrMem::offset(31), PROC_PIO_INSTR_MEM31_OFFSET);
+    assert_eq!(Fstat::OFFSET, PROC_PIO_FSTAT_OFFSET);
+}
+
+#[test]
+fn fields_round_trip_through_their_masks() {
+    let mut exec = SmExecCtrl::RESET;
+    exec.set_wrap_top(30).set_wrap_bottom(2).set_side_en(true);
+    assert_eq!(exec.wrap_top(), 30);
+    assert_eq!(exec.wrap_bottom(), 2);
+    assert!(exec.side_en());
+    assert!(!SmExecCtrl(PROC_PIO_SM0_EXECCTRL_RESET).exec_stalled());
+    assert!(SmExecCtrl(PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_BITS).exec_stalled());
+
+    let mut ctrl = Ctrl::default();
+    ctrl.set_sm_enable(2, true).set_sm_restart(1, true);
+    assert_eq!(
+        ctrl.bits(),
+        (1 << 2) | (1 << (PROC_PIO_CTRL_SM_RESTART_LSB + 1))
+    );
+    assert!(ctrl.sm_enable(2));
+    assert!(!ctrl.sm_enable(0));
+
+    let flevel = Flevel(0x0000_5a30);
+    assert_eq!((flevel.tx(0), flevel.rx(0)), (0, 3));
+    assert_eq!((flevel.tx(1), flevel.rx(1)), (0xa, 5));
+    assert!(Flevel2(1 << PROC_PIO_FLEVEL2_RX1_LSB).rx(1));
+
+    let mut fdebug = Fdebug::default();
+    fdebug.clear_tx_over(3).clear_rx_stall(0);
+    assert_eq!(fdebug.bits(), (1 << (PROC_PIO_FDEBUG_TXOVER_LSB + 3)) | 1);
+
+    assert_eq!(Fstat::RESET.bits(), PROC_PIO_FSTAT_RESET);
+    assert!(Fstat::RESET.tx_empty(3) && Fstat::RESET.rx_empty(0));
+    assert!(!Fstat::RESET.tx_full(3));
+    assert!(SmShiftCtrl::RESET.in_shiftdir());
+
+    let cfg = DbgCfgInfo(0x0020_0408);
+    assert_eq!(
+        (cfg.imem_size(), cfg.sm_count(), cfg.fifo_depth()),
+        (32, 4, 8)
+    );
+}
+
+#[test]
+fn register_table_describes_every_register() {
+    assert_eq!(PROC_PIO_SM0_EXECCTRL_EXEC_STALLED_ACCESS, Access::RO);
+    assert_eq!("WC".parse::<Access>(), Ok(Access::WC));
+    assert!(PROC_PIO_REGISTERS
+        .windows(2)
+        .all(|w| w[0].offset < w[1].offset));
+    for reg in PROC_PIO_REGISTERS {
+        let fields = reg.fields.iter().fold(0, |bits, field| bits | field.bits);
+        assert_eq!(fields, reg.bits, "{}", reg.name);
+        if let Some(reset) = reg.reset {
+            assert_eq!(reg.reset_mismatches(reset).count(), 0, "{}", reg.name);
+        }
+    }
+
+    let exec = find_register(SmExecCtrl::offset(2)).unwrap();
+    assert_eq!(exec.name, "SM2_EXECCTRL");
+    assert_eq!(
+        exec.writable_bits(),
+        PROC_PIO_SM2_EXECCTRL_BITS & !(1 << 31)
+    );
+    let stalled: Vec<_> = exec.unwritable_fields(u32::MAX).map(|f| f.name).collect();
+    assert_eq!(stalled, ["EXEC_STALLED"]);
+    assert_eq!(
+        exec.field("WRAP_TOP").unwrap().get(SmExecCtrl::RESET.0),
+        0x1f
+    );
+
+    let rxf = find_register_by_name("PROC_PIO_RXF1").unwrap();
+    assert_eq!((rxf.reset, rxf.fields[0].access), (None, Access::RF));
+    assert_eq!(
+        find_register_by_name("FSTAT")
+            .unwrap()
+            .reset_mismatches(0)
+            .count(),
+        2
+    );
+}
@@ -0,0 +1,224 @@
+use std::collections::VecDeque;
+use std::io;
+
+use piolib_rs::include::hardware::gpio::gpio_override;
+use piolib_rs::include::piolib::*;
+use piolib_rs::include::rp1_pio_if::*;
+use piolib_rs::piolib::chip::PioChip;
+use piolib_rs::piolib::error::PioError;
+use piolib_rs::piolib::instr_mem::PioInstrMem;
+use piolib_rs::piolib::pio_rp1::{Rp1Pio, Rp1PioDevice};
+
+/// Stand-in for the rp1-pio driver behind `/dev/pio0`
+#[derive(Default)]
+struct FakeRp1Device {
+    instr_mem: PioInstrMem,
+    claimed: u16,
+    configs: [rp1_pio_sm_config; 4],
+    tx_fifos: [VecDeque<u32>; 4],
+    gpio_functions: Vec<(u16, u16)>,
+    xfer: Vec<u8>,
+    requests: Vec<u32>,
+}
+
+fn errno(errno: i32) -> io::Error {
+    io::Error::from_raw_os_error(errno)
+}
+
+fn args<T: Rp1PioArgs>(arg: &[u8]) -> T {
+    T::from_bytes(arg).expect("argument size matches request")
+}
+
+impl Rp1PioDevice for FakeRp1Device {
+    fn ioctl(&mut self, request: u32, arg: &mut [u8]) -> io::Result<i32> {
+        self.requests.push(request);
+        match request {
+            PIO_IOC_ADD_PROGRAM | PIO_IOC_CAN_ADD_PROGRAM => {
+                let a: rp1_pio_add_program_args = args(arg);
+                let instrs = &a.instrs[..a.num_instrs as usize];
+                let origin = (a.origin != RP1_PIO_ORIGIN_ANY).then_some(a.origin as u8);
+                let program = pio_program::new(instrs, None, 0).unwrap();
+                if request == PIO_IOC_CAN_ADD_PROGRAM {
+                    return Ok(self.instr_mem.can_add_program_at_offset(&program, origin) as i32);
+                }
+                match self.instr_mem.add_program_at_offset(&program, origin) {
+                    Some(offset) => Ok(offset as i32),
+                    None => Err(errno(16)),
+                }
+            }
+            PIO_IOC_REMOVE_PROGRAM => {
+                let a: rp1_pio_remove_program_args = args(arg);
+                let instrs = [0; 32];
+                let program = pio_program::new(&instrs[..a.num_instrs as usize], None, 0).unwrap();
+                match self.instr_mem.remove_program(&program, a.origin as u8) {
+                    true => Ok(0),
+                    false => Err(errno(22)),
+                }
+            }
+            PIO_IOC_CLEAR_INSTR_MEM => {
+                assert!(arg.is_empty());
+                self.instr_mem.clear_instruction_memory();
+                Ok(0)
+            }
+            PIO_IOC_SM_CLAIM => {
+                let a: rp1_pio_sm_claim_args = args(arg);
+                if self.claimed & a.mask != 0 {
+                    return Err(errno(16));
+                }
+                self.claimed |= a.mask;
+                Ok(0)
+            }
+            PIO_IOC_SM_UNCLAIM => {
+                let a: rp1_pio_sm_claim_args = args(arg);
+                self.claimed &= !a.mask;
+                Ok(0)
+            }
+            PIO_IOC_SM_IS_CLAIMED => {
+                let a: rp1_pio_sm_claim_args = args(arg);
+                Ok((self.claimed & a.mask != 0) as i32)
+            }
+            PIO_IOC_SM_INIT => {
+                let a: rp1_pio_sm_init_args = args(arg);
+                self.configs[a.sm as usize] = a.config;
+                Ok(0)
+            }
+            PIO_IOC_SM_PUT => {
+                let a: rp1_pio_sm_put_args = args(arg);
+                self.tx_fifos[a.sm as usize].push_back(a.data);
+                Ok(0)
+            }
+            PIO_IOC_SM_GET => {
+                // loop the TX FIFO back round to RX
+                let mut a: rp1_pio_sm_get_args = args(arg);
+                a.data = self.tx_fifos[a.sm as usize].pop_front().ok_or(errno(11))?;
+                arg.copy_from_slice(a.as_bytes());
+                Ok(0)
+            }
+            PIO_IOC_SM_FIFO_STATE => {
+                let mut a: rp1_pio_sm_fifo_state_args = args(arg);
+                let level = if a.tx != 0 {
+                    self.tx_fifos[a.sm as usize].len()
+                } else {
+                    0
+                };
+                a.level = level as u16;
+                a.empty = (level == 0) as u8;
+                a.full = (level == 8) as u8;
+                arg.copy_from_slice(a.as_bytes());
+                Ok(0)
+            }
+            PIO_IOC_SM_XFER_DATA => {
+                let a: rp1_pio_sm_xfer_data_args = args(arg);
+                // SAFETY: the pointer comes from the slice passed to sm_xfer_data in this process
+                let data = unsafe {
+                    std::slice::from_raw_parts(a.data as *const u8, a.data_bytes as usize)
+                };
+                self.xfer.extend_from_slice(data);
+                Ok(0)
+            }
+            PIO_IOC_GPIO_SET_FUNCTION => {
+                let a: rp1_gpio_set_function_args = args(arg);
+                self.gpio_functions.push((a.gpio, a.func));
+                Ok(0)
+            }
+            PIO_IOC_GPIO_SET_OUTOVER => Ok(0),
+            _ => Err(errno(25)),
+        }
+    }
+}
+
+fn fake_pio() -> Rp1Pio<FakeRp1Device> {
+    Rp1Pio::with_device(FakeRp1Device::default())
+}
+
+#[test]
+fn rp1_programs_allocate_through_driver() {
+    let mut pio = fake_pio();
+    let four = pio_program::new(&[0xe001; 4], None, 0).unwrap();
+    let fixed = pio_program::new(&[0xa042; 2], Some(4), 0).unwrap();
+    assert_eq!(Ok(28), pio.add_program_at_offset(&four, None));
+    assert_eq!(Ok(4), pio.add_program_at_offset(&fixed, None));
+    assert_eq!(Ok(false), pio.can_add_program_at_offset(&fixed, Some(4)));
+    assert_eq!(Ok(false), pio.can_add_program_at_offset(&fixed, Some(6)));
+    assert_eq!(
+        Err(PioError::InvalidProgram(PioProgramError::OriginMismatch {
+            origin: 4,
+            offset: 6
+        })),
+        pio.add_program_at_offset(&fixed, Some(6))
+    );
+    assert_eq!(
+        Err(PioError::NoProgramSpace),
+        pio.add_program_at_offset(&four, Some(28))
+    );
+    assert_eq!(0xf000_0030, pio.device().instr_mem.used_mask());
+    pio.remove_program(&four, 28).unwrap();
+    assert_eq!(Err(PioError::Io(22)), pio.remove_program(&four, 28));
+    pio.clear_instruction_memory().unwrap();
+    assert_eq!(0, pio.device().instr_mem.used_mask());
+}
+#[test]
+fn rp1_claims_and_config() {
+    let mut pio = fake_pio();
+    pio.sm_claim(2).unwrap();
+    assert_eq!(Err(PioError::SmAlreadyClaimed), pio.sm_claim(2));
+    assert_eq!(Ok(0), pio.sm_claim_unused());
+    assert_eq!(Ok(true), pio.sm_is_claimed(2));
+    pio.sm_unclaim(2).unwrap();
+    assert_eq!(Ok(false), pio.sm_is_claimed(2));
+
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_wrap(&mut c, 3, 9);
+    pio.sm_init(1, 3, &c).unwrap();
+    assert_eq!(rp1_pio_sm_config::from(&c), pio.device().configs[1]);
+    assert_eq!(c.execctrl(), pio.device().configs[1].execctrl);
+}
+#[test]
+fn rp1_fifo_and_xfer() {
+    let mut pio = fake_pio();
+    pio.sm_put(3, 0xdead_beef, true).unwrap();
+    pio.sm_put(3, 7, true).unwrap();
+    assert_eq!(Ok(2), pio.sm_get_tx_fifo_level(3));
+    assert_eq!(Ok(false), pio.sm_is_tx_fifo_empty(3));
+    assert_eq!(Ok(0xdead_beef), pio.sm_get(3, true));
+    assert_eq!(Ok(7), pio.sm_get(3, true));
+    assert_eq!(Err(PioError::Io(11)), pio.sm_get(3, false));
+    assert_eq!(Ok(true), pio.sm_is_tx_fifo_empty(3));
+
+    let mut data = *b"rp1 dma";
+    pio.sm_xfer_data(0, pio_xfer_dir::PIO_DIR_TO_SM, &mut data)
+        .unwrap();
+    assert_eq!(b"rp1 dma".to_vec(), pio.device().xfer);
+}
+#[test]
+fn rp1_rejects_bad_params_before_ioctl() {
+    let mut pio = fake_pio();
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "sm" }),
+        pio.sm_put(4, 0, true)
+    );
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "gpio" }),
+        pio.pio_gpio_init(28)
+    );
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "mask" }),
+        pio.sm_set_enabled_mask(0x10, true)
+    );
+    assert!(pio.device().requests.is_empty());
+    pio.pio_gpio_init(4).unwrap();
+    pio.gpio_set_outover(4, gpio_override::GPIO_OVERRIDE_HIGH)
+        .unwrap();
+    assert_eq!(vec![(4, RP1_GPIO_FUNC_PIO)], pio.device().gpio_functions);
+    // requests the fake driver does not know fail like an old kernel would
+    assert_eq!(Err(PioError::DriverVersion), pio.sm_enable_sync(1));
+}
+#[test]
+fn rp1_ioctl_numbers() {
+    assert_eq!(0x4044_660b, PIO_IOC_ADD_PROGRAM);
+    assert_eq!(0x0000_660d, PIO_IOC_CLEAR_INSTR_MEM);
+    assert_eq!(0x4002_6614, PIO_IOC_SM_CLAIM);
+    assert_eq!(0x4014_661e, PIO_IOC_SM_INIT);
+    assert_eq!(0xc008_662a, PIO_IOC_SM_GET);
+    assert_eq!(0x4008_662c, PIO_IOC_SM_FIFO_STATE);
+}
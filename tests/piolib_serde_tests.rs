@@ -0,0 +1,74 @@
+#![cfg(feature = "serde")]
+
+use piolib_rs::include::hardware::gpio::*;
+use piolib_rs::include::piolib::*;
+use piolib_rs::piolib::sm_config::SmConfig;
+
+#[test]
+fn programs_save_and_load() {
+    let program = pio_program::new(&[0xe081, 0x0001], Some(4), 0).unwrap();
+    let json = serde_json::to_string(&program).unwrap();
+    assert_eq!(
+        r#"{"instructions":[57473,1],"origin":4,"pio_version":0}"#,
+        json
+    );
+    let loaded: PioProgramBuf = serde_json::from_str(&json).unwrap();
+    assert_eq!(PioProgramBuf::from(&program), loaded);
+    assert_eq!(Ok(program), loaded.as_program());
+
+    let anywhere: PioProgramBuf = serde_json::from_str(r#"{"instructions":[1]}"#).unwrap();
+    assert_eq!(None, anywhere.as_program().unwrap().origin());
+    let too_long: PioProgramBuf =
+        serde_json::from_str(r#"{"instructions":[0,0],"origin":31}"#).unwrap();
+    assert_eq!(
+        Err(PioProgramError::OriginOutOfRange {
+            origin: 31,
+            length: 2
+        }),
+        too_long.as_program()
+    );
+}
+
+#[test]
+fn configs_save_and_load_as_fields() {
+    let config = SmConfig::new()
+        .out_pins(4, 8)
+        .and_then(|c| c.out_shift(false, true, 8))
+        .and_then(|c| c.fifo_join(pio_fifo_join::PIO_FIFO_JOIN_TX))
+        .unwrap()
+        .build();
+    let json = serde_json::to_value(config).unwrap();
+    assert_eq!(8, json["pull_thresh"]);
+    assert_eq!(32, json["push_thresh"]);
+    assert_eq!(4, json["out_base"]);
+    assert_eq!("PIO_FIFO_JOIN_TX", json["fifo_join"]);
+    assert_eq!("STATUS_TX_LESSTHAN", json["status_sel"]);
+    let loaded: pio_sm_config = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(config, loaded);
+    let builder: SmConfig = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(config, builder.build());
+
+    let mut bad = json;
+    bad["set_count"] = 6.into();
+    let err = serde_json::from_value::<pio_sm_config>(bad).unwrap_err();
+    assert_eq!("invalid set_count", err.to_string());
+}
+
+#[test]
+fn enums_use_their_names() {
+    assert_eq!(
+        r#"["GPIO_FUNC_PIO0","GPIO_OVERRIDE_HIGH","GPIO_DRIVE_STRENGTH_8MA","GPIO_SLEW_RATE_FAST","GPIO_IRQ_EDGE_RISE"]"#,
+        serde_json::to_string(&(
+            gpio_function::GPIO_FUNC_PIO0,
+            gpio_override::GPIO_OVERRIDE_HIGH,
+            gpio_drive_strength::GPIO_DRIVE_STRENGTH_8MA,
+            gpio_slew_rate::GPIO_SLEW_RATE_FAST,
+            gpio_irq_level::GPIO_IRQ_EDGE_RISE,
+        ))
+        .unwrap()
+    );
+    let join: pio_fifo_join = serde_json::from_str(r#""PIO_FIFO_JOIN_RX""#).unwrap();
+    assert_eq!(pio_fifo_join::PIO_FIFO_JOIN_RX, join);
+    let status: pio_mov_status_type = serde_json::from_str(r#""STATUS_RX_LESSTHAN""#).unwrap();
+    assert_eq!(pio_mov_status_type::STATUS_RX_LESSTHAN, status);
+}
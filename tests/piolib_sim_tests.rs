@@ -0,0 +1,565 @@
+use piolib_rs::include::hardware::clocks::clock_index;
+use piolib_rs::include::hardware::gpio::gpio_override;
+use piolib_rs::include::hardware::pio_instructions::{pio_src_dest, *};
+use piolib_rs::include::hardware::regs::proc_pio::*;
+use piolib_rs::include::piolib::*;
+use piolib_rs::piolib::chip::PioChip;
+use piolib_rs::piolib::error::PioError;
+use piolib_rs::piolib::instance::Pio;
+use piolib_rs::piolib::pio_sim::{PioSim, SIM_MAX_FIFO_DEPTH};
+use piolib_rs::piolib::pio_sim_pads::Stimulus;
+use piolib_rs::piolib::regs::fifo_level;
+
+/// Load `instrs` wherever they fit and start `sm` on them with `c`, wrapping over the whole program
+fn start(sim: &mut PioSim, sm: u32, instrs: &[u32], mut c: pio_sm_config) -> u8 {
+    let instrs: Vec<u16> = instrs.iter().map(|&instr| instr as u16).collect();
+    let program = pio_program::new(&instrs, None, 0).unwrap();
+    let offset = sim.add_program_at_offset(&program, None).unwrap();
+    let image = pio_relocate_program(&program, offset, &mut c).unwrap();
+    assert_eq!(image.len(), instrs.len());
+    sim.sm_init(sm, offset as u32, &c).unwrap();
+    sim.sm_set_enabled(sm, true).unwrap();
+    offset
+}
+
+fn wrapped_config(len: u32) -> pio_sm_config {
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_wrap(&mut c, 0, len - 1);
+    c
+}
+
+#[test]
+fn sim_delay_and_side_set_timing() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(2);
+    sm_config_set_sideset(&mut c, 1, false, false);
+    sm_config_set_sideset_pins(&mut c, 31);
+    let high = pio_encode_nop() | pio_encode_sideset(1, 1) | pio_encode_delay(2);
+    let low = pio_encode_nop() | pio_encode_sideset(1, 0);
+    sim.sm_set_consecutive_pindirs(0, 31, 1, true).unwrap();
+    start(&mut sim, 0, &[high, low], c);
+
+    let mut trace = Vec::new();
+    for _ in 0..8 {
+        sim.step();
+        trace.push(sim.pin_levels() >> 31);
+    }
+    assert_eq!(vec![1, 1, 1, 0, 1, 1, 1, 0], trace);
+    assert_eq!(8, sim.cycle());
+}
+
+#[test]
+fn sim_shift_registers_through_fifos() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(5);
+    sm_config_set_out_shift(&mut c, true, false, 32);
+    sm_config_set_in_shift(&mut c, false, false, 32);
+    let program = [
+        pio_encode_pull(false, true),
+        pio_encode_out(pio_src_dest::pio_x, 8),
+        pio_encode_in(pio_src_dest::pio_x, 8),
+        pio_encode_jmp_not_osre(1),
+        pio_encode_push(false, true),
+    ];
+    let offset = start(&mut sim, 2, &program, c);
+    sim.sm_put(2, 0x1122_3344, true).unwrap();
+    sim.sm_put(2, 0xa0b0_c0d0, true).unwrap();
+    assert_eq!(Ok(0x4433_2211), sim.sm_get(2, true));
+    assert_eq!(Ok(0xd0c0_b0a0), sim.sm_get(2, true));
+    sim.run(4);
+    assert!(sim.sm(2).is_stalled());
+    assert_eq!((offset, 32), (sim.sm(2).pc(), sim.sm(2).osr_shift_count()));
+    assert_eq!(0xa0, sim.sm(2).x());
+}
+
+#[test]
+fn sim_irq_handshake_between_state_machines() {
+    let mut sim = PioSim::new();
+    start(
+        &mut sim,
+        0,
+        &[
+            pio_encode_irq_wait(false, 5),
+            pio_encode_set(pio_src_dest::pio_x, 1),
+        ],
+        wrapped_config(2),
+    );
+    sim.run(3);
+    assert!(sim.sm(0).is_stalled());
+    assert_eq!(1 << 5, sim.irq_flags());
+
+    start(
+        &mut sim,
+        3,
+        &[
+            pio_encode_wait_irq(true, false, 5),
+            pio_encode_irq_set(true, 1),
+        ],
+        wrapped_config(2),
+    );
+    sim.run(3);
+    assert!(!sim.sm(0).is_stalled());
+    assert_eq!(1, sim.sm(0).x());
+    // relative IRQ 1 from SM3 is flag 0
+    assert_eq!(1 << 0, sim.irq_flags());
+}
+
+/// Cycles until `waiter`, on `WAIT 1 IRQ 0`, sees `setter` raise the flag
+fn irq_handshake_cycles(setter: u32, waiter: u32) -> u64 {
+    let mut sim = PioSim::new();
+    start(
+        &mut sim,
+        waiter,
+        &[
+            pio_encode_wait_irq(true, false, 0),
+            pio_encode_set(pio_src_dest::pio_x, 1),
+        ],
+        wrapped_config(2),
+    );
+    start(
+        &mut sim,
+        setter,
+        &[pio_encode_irq_set(false, 0), pio_encode_jmp(1)],
+        wrapped_config(2),
+    );
+    assert!(sim.run_until(10, |sim| sim.sm(waiter).x() == 1));
+    sim.cycle()
+}
+
+#[test]
+fn sim_state_machines_see_each_other_a_cycle_later() {
+    // flag set in cycle 0, seen in cycle 1, SET X in cycle 2
+    assert_eq!(3, irq_handshake_cycles(0, 1));
+    assert_eq!(3, irq_handshake_cycles(1, 0));
+
+    // SM0 follows a pin SM1 drives a cycle behind, as SM1 does SM0
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(1);
+    sm_config_set_set_pins(&mut c, 0, 1);
+    sm_config_set_in_pins(&mut c, 1);
+    start(&mut sim, 1, &[pio_encode_set(pio_src_dest::pio_pins, 1)], c);
+    let mut c = wrapped_config(1);
+    sm_config_set_in_pins(&mut c, 0);
+    start(
+        &mut sim,
+        0,
+        &[pio_encode_mov(pio_src_dest::pio_x, pio_src_dest::pio_pins)],
+        c,
+    );
+    sim.sm_set_pindirs_with_mask(1, 1, 1).unwrap();
+    sim.step();
+    assert_eq!(0, sim.sm(0).x() & 1);
+    sim.step();
+    assert_eq!(1, sim.sm(0).x() & 1);
+}
+
+#[test]
+fn sim_out_pins_writes_all_out_count_pins() {
+    let mut sim = PioSim::new();
+    sim.sm_set_pins_with_mask(0, 0b1111, 0b1111).unwrap();
+    let mut c = wrapped_config(1);
+    sm_config_set_out_pins(&mut c, 0, 4);
+    start(&mut sim, 0, &[pio_encode_out(pio_src_dest::pio_pins, 1)], c);
+    sim.step();
+    // the single bit shifted out is zero-extended over OUT_COUNT
+    assert_eq!(0, sim.pad_out());
+}
+
+#[test]
+fn sim_rejects_side_set_counts_it_cannot_decode() {
+    let mut sim = PioSim::new();
+    let c = wrapped_config(1);
+    let with = |pinctrl: u32, execctrl: u32| {
+        pio_sm_config::from_registers(c.clkdiv(), c.execctrl() | execctrl, c.shiftctrl(), pinctrl)
+    };
+    let invalid = Err(PioError::InvalidParam { which: "config" });
+    for count in [6, 7] {
+        let config = with(
+            c.pinctrl() | count << PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_LSB,
+            0,
+        );
+        assert_eq!(invalid, sim.sm_init(0, 0, &config));
+        assert_eq!(invalid, sim.sm_set_config(0, &config));
+    }
+    let enable_only = with(c.pinctrl(), PROC_PIO_SM0_EXECCTRL_SIDE_EN_BITS);
+    assert_eq!(invalid, sim.sm_set_config(0, &enable_only));
+
+    let five = with(c.pinctrl() | 5 << PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_LSB, 0);
+    sim.sm_init(0, 0, &five).unwrap();
+    sim.sm_set_enabled(0, true).unwrap();
+    sim.step();
+}
+
+#[test]
+fn sim_exec_and_noblock_pull() {
+    let mut sim = PioSim::new();
+    sim.sm_exec(1, pio_encode_set(pio_src_dest::pio_x, 21) as u16, true)
+        .unwrap();
+    sim.sm_exec(1, pio_encode_pull(false, false) as u16, true)
+        .unwrap();
+    assert_eq!(21, sim.sm(1).osr());
+    sim.sm_exec(1, pio_encode_pull(false, true) as u16, false)
+        .unwrap();
+    assert!(sim.sm(1).execctrl().exec_stalled());
+    let set_y = pio_encode_set(pio_src_dest::pio_y, 7);
+    sim.sm_put(1, set_y, false).unwrap();
+    sim.sm_set_enabled(1, true).unwrap();
+    sim.step();
+    assert!(!sim.sm(1).is_stalled());
+    assert_eq!(set_y, sim.sm(1).osr());
+    let out_exec = pio_encode_out(pio_src_dest::pio_exec_out, 16);
+    sim.sm_exec(1, out_exec as u16, true).unwrap();
+    assert_eq!(7, sim.sm(1).y());
+
+    let mut pio = Pio::from_chip(PioSim::new(), 0);
+    assert_eq!("sim", pio.info().name);
+    assert_eq!(Err(PioError::Io(110)), pio.chip_mut().sm_get(0, true));
+}
+
+/// `clk_sys` cycles at which `sm`, looping on `JMP X--`, ran over the next `cycles` cycles
+fn sm_clocks(sim: &mut PioSim, sm: u32, cycles: u64) -> Vec<u64> {
+    let mut clocks = Vec::new();
+    for _ in 0..cycles {
+        let x = sim.sm(sm).x();
+        let cycle = sim.cycle();
+        sim.step();
+        if sim.sm(sm).x() != x {
+            clocks.push(cycle);
+        }
+    }
+    clocks
+}
+
+#[test]
+fn sim_fractional_clock_divider() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(1);
+    sm_config_set_clkdiv_int_frac(&mut c, 2, 128);
+    let offset = start(&mut sim, 0, &[pio_encode_jmp_x_dec(0)], c);
+    let mut c = wrapped_config(1);
+    sm_config_set_wrap(&mut c, offset as u32, offset as u32);
+    sm_config_set_clkdiv_int_frac(&mut c, 3, 0);
+    sim.sm_init(1, offset as u32, &c).unwrap();
+
+    let clocks = sm_clocks(&mut sim, 0, 100);
+    assert_eq!(40, clocks.len());
+    let periods: Vec<_> = clocks.windows(2).map(|w| w[1] - w[0]).take(4).collect();
+    assert_eq!(vec![2, 3, 2, 3], periods);
+
+    // SM1 only starts now, out of step with SM0 until both dividers restart together
+    sim.sm_set_clkdiv_int_frac(0, 3, 0).unwrap();
+    sim.run(1);
+    sim.sm_enable_sync(0b11).unwrap();
+    let start = sim.cycle();
+    let sm0 = sm_clocks(&mut sim, 0, 9);
+    assert_eq!(vec![start, start + 3, start + 6], sm0);
+    assert_eq!(3, sim.sm(1).x().wrapping_neg());
+
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "hz" }),
+        sim.set_clk_sys_hz(0)
+    );
+    sim.set_clk_sys_hz(100_000_000).unwrap();
+    assert_eq!(Ok(100_000_000), sim.clock_get_hz(clock_index::clk_sys));
+    assert_eq!(
+        std::time::Duration::from_nanos(sim.cycle() * 10),
+        sim.elapsed()
+    );
+}
+
+#[test]
+fn sim_autopull_refills_the_osr() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(1);
+    sm_config_set_out_shift(&mut c, true, true, 16);
+    start(&mut sim, 3, &[pio_encode_out(pio_src_dest::pio_x, 8)], c);
+    sim.sm_put(3, 0x1234_5678, false).unwrap();
+    sim.sm_put(3, 0xaabb_ccdd, false).unwrap();
+
+    // the first OUT waits a cycle for the OSR to fill; the second word is pulled in the background
+    let mut trace = Vec::new();
+    for _ in 0..6 {
+        sim.step();
+        trace.push((sim.sm(3).x(), sim.sm(3).is_stalled()));
+    }
+    let expected = [
+        (0, true),
+        (0x78, false),
+        (0x56, false),
+        (0xdd, false),
+        (0xcc, false),
+        (0xcc, true),
+    ];
+    assert_eq!(expected.to_vec(), trace);
+    assert!(sim.fdebug().tx_stall(3));
+    assert!(!sim.fdebug().tx_stall(0));
+}
+
+#[test]
+fn sim_drain_tx_fifo_of_a_disabled_sm_runs_nothing_else() {
+    let mut sim = PioSim::new();
+    start(&mut sim, 0, &[pio_encode_jmp_x_dec(0)], wrapped_config(1));
+    sim.sm_set_enabled(0, false).unwrap();
+    sim.sm_exec(0, pio_encode_set(pio_src_dest::pio_x, 31) as u16, true)
+        .unwrap();
+    sim.sm_set_enabled(0, true).unwrap();
+
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_out_shift(&mut c, true, true, 32);
+    sim.sm_init(2, 0, &c).unwrap();
+    for word in 0..4 {
+        sim.sm_put(2, word, false).unwrap();
+    }
+    // the OSR starts empty, so each OUT NULL first waits for autopull to refill it
+    sim.sm_drain_tx_fifo(2).unwrap();
+    assert!(sim.sm(2).tx_fifo().is_empty());
+    sim.sm_exec(2, pio_encode_out(pio_src_dest::pio_null, 32) as u16, true)
+        .unwrap();
+    assert_eq!(0, sim.cycle());
+    assert_eq!(31, sim.sm(0).x());
+}
+
+#[test]
+fn sim_autopush_stalls_on_a_full_rx_fifo() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(2);
+    // a threshold of 32 is stored as 0
+    sm_config_set_in_shift(&mut c, false, true, 32);
+    let program = [
+        pio_encode_set(pio_src_dest::pio_x, 3),
+        pio_encode_in(pio_src_dest::pio_x, 16),
+    ];
+    start(&mut sim, 1, &program, c);
+    assert_eq!(0, sim.sm(1).shiftctrl().push_thresh());
+
+    sim.run(4);
+    assert_eq!(vec![0x0003_0003], Vec::from(sim.sm(1).rx_fifo().clone()));
+    sim.run(28);
+    assert_eq!(8, sim.sm(1).rx_fifo().len());
+    assert!(!sim.fdebug().rx_stall(1));
+    sim.run(4);
+    assert!(sim.sm(1).is_stalled());
+    assert!(sim.fdebug().rx_stall(1));
+    assert_eq!(32, sim.sm(1).isr_shift_count());
+
+    // the stalled IN pushes as soon as there is room, without shifting again
+    assert_eq!(Ok(0x0003_0003), sim.sm_get(1, false));
+    sim.step();
+    assert!(!sim.sm(1).is_stalled());
+    assert_eq!(
+        (8, 0),
+        (sim.sm(1).rx_fifo().len(), sim.sm(1).isr_shift_count())
+    );
+}
+
+#[test]
+fn sim_joined_fifos_report_deep_levels() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(1);
+    sm_config_set_fifo_join(&mut c, pio_fifo_join::PIO_FIFO_JOIN_TX);
+    start(&mut sim, 2, &[pio_encode_pull(false, true)], c);
+    sim.sm_set_enabled(2, false).unwrap();
+    for word in 0..16 {
+        sim.sm_put(2, word, false).unwrap();
+    }
+    assert_eq!((0, true), (sim.flevel().tx(2), sim.flevel2().tx(2)));
+    assert!(sim.fstat().tx_full(2) && sim.fstat().rx_full(2));
+    assert_eq!(Ok(true), sim.sm_is_rx_fifo_full(2));
+    sim.sm_put(2, 16, false).unwrap();
+    assert!(sim.fdebug().tx_over(2));
+
+    // changing the join empties both FIFOs
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_fifo_join(&mut c, pio_fifo_join::PIO_FIFO_JOIN_RX);
+    sim.sm_set_config(2, &c).unwrap();
+    assert_eq!(Ok(0), sim.sm_get_tx_fifo_level(2));
+    assert!(sim.fstat().tx_full(2) && sim.fstat().tx_empty(2));
+    sim.sm_exec(2, pio_encode_push(false, false) as u16, false)
+        .unwrap();
+    assert_eq!(Ok(1), sim.sm_get_rx_fifo_level(2));
+
+    let mut c = wrapped_config(1);
+    sm_config_set_fifo_join(&mut c, pio_fifo_join::PIO_FIFO_JOIN_TX);
+    start(&mut sim, 0, &[pio_encode_pull(false, true)], c);
+    sim.sm_set_enabled(0, false).unwrap();
+    let mut pio = Pio::from_chip(sim, 0);
+    for word in 0..13 {
+        pio_sm_put(&mut pio, 0, word).unwrap();
+    }
+    assert_eq!(Ok(13), pio_sm_get_tx_fifo_level(&mut pio, 0));
+    assert_eq!(Ok(false), pio_sm_is_tx_fifo_full(&mut pio, 0));
+}
+
+#[test]
+fn sim_fifo_depth_is_limited_to_what_flevel_reports() {
+    let invalid = Err(PioError::InvalidParam {
+        which: "fifo_depth",
+    });
+    assert_eq!(invalid, PioSim::with_fifo_depth(0).map(drop));
+    assert_eq!(
+        invalid,
+        PioSim::with_fifo_depth(SIM_MAX_FIFO_DEPTH + 1).map(drop)
+    );
+    assert_eq!(1, PioSim::with_fifo_depth(1).unwrap().info().fifo_depth);
+
+    let mut sim = PioSim::with_fifo_depth(SIM_MAX_FIFO_DEPTH).unwrap();
+    assert_eq!(15, sim.info().fifo_depth);
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_fifo_join(&mut c, pio_fifo_join::PIO_FIFO_JOIN_TX);
+    sim.sm_init(1, 0, &c).unwrap();
+    for word in 0..30 {
+        sim.sm_put(1, word, false).unwrap();
+    }
+    assert!(sim.fstat().tx_full(1));
+    assert_eq!(30, fifo_level(sim.flevel(), sim.flevel2(), 1, true));
+}
+
+#[test]
+fn sim_stimulus_drives_the_in_pins() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(1);
+    // IN_BASE 31 puts pin 3 at bit 4, wrapping round after pin 31
+    sm_config_set_in_pins(&mut c, 31);
+    start(
+        &mut sim,
+        0,
+        &[pio_encode_mov(pio_src_dest::pio_x, pio_src_dest::pio_pins)],
+        c,
+    );
+    sim.pads_mut()
+        .set_stimulus(3, Stimulus::toggle(10, false))
+        .unwrap();
+    sim.pads_mut()
+        .set_stimulus(31, Stimulus::edges(&[(5, true), (25, false)]))
+        .unwrap();
+
+    let mut pin3 = Vec::new();
+    let mut pin31 = Vec::new();
+    for _ in 0..30 {
+        sim.step();
+        pin3.push((sim.sm(0).x() >> 4) & 1);
+        pin31.push(sim.sm(0).x() & 1);
+    }
+    let expected: Vec<u32> = (0..30).map(|cycle| (cycle / 10) % 2).collect();
+    assert_eq!(expected, pin3);
+    let expected: Vec<u32> = (0..30)
+        .map(|cycle| (5..25).contains(&cycle) as u32)
+        .collect();
+    assert_eq!(expected, pin31);
+}
+
+#[test]
+fn sim_pin_mappings_wrap_round() {
+    let mut sim = PioSim::new();
+    let mut c = wrapped_config(3);
+    sm_config_set_set_pins(&mut c, 30, 4);
+    sm_config_set_out_pins(&mut c, 29, 3);
+    sm_config_set_sideset(&mut c, 2, false, false);
+    sm_config_set_sideset_pins(&mut c, 31);
+    let program = [
+        pio_encode_set(pio_src_dest::pio_pindirs, 0b1111) | pio_encode_sideset(2, 0),
+        pio_encode_set(pio_src_dest::pio_pins, 0b0101) | pio_encode_sideset(2, 0b10),
+        pio_encode_out(pio_src_dest::pio_pins, 3) | pio_encode_sideset(2, 0b11),
+    ];
+    start(&mut sim, 1, &program, c);
+    sim.sm_put(1, 0b110, false).unwrap();
+    sim.sm_exec(1, pio_encode_pull(false, false) as u16, false)
+        .unwrap();
+
+    // the forced PULL takes the first cycle
+    sim.run(3);
+    // SET drives 30 and 0 high, then side-set drives 31 low and 0 high over it
+    assert_eq!(0b11 << 30 | 0b11, sim.pad_oe());
+    assert_eq!(1 << 30 | 1, sim.pad_levels());
+    sim.step();
+    // OUT_BASE 29 reaches pins 29 to 31, but PINDIRS left 29 an input and side-set wins on 31
+    assert_eq!(0b110 << 29, sim.pad_out() & (0b111 << 29));
+    assert_eq!(0b11 << 30 | 1, sim.pad_levels());
+}
+
+#[test]
+fn sim_pad_overrides_and_pulls() {
+    let mut sim = PioSim::new();
+    sim.sm_set_pins_with_mask(0, 0b1111, 0b1111).unwrap();
+    sim.sm_set_pindirs_with_mask(0, 0b0111, 0b1111).unwrap();
+    assert_eq!(0b0111, sim.pad_levels() & 0xff);
+
+    sim.gpio_set_outover(0, gpio_override::GPIO_OVERRIDE_INVERT)
+        .unwrap();
+    sim.gpio_set_oeover(1, gpio_override::GPIO_OVERRIDE_LOW)
+        .unwrap();
+    sim.gpio_init(2).unwrap();
+    sim.gpio_set_oeover(3, gpio_override::GPIO_OVERRIDE_HIGH)
+        .unwrap();
+    assert_eq!(0b1000, sim.pad_levels() & 0xff);
+
+    // pin 1 is undriven, so it follows its pulls, then the outside
+    sim.gpio_set_pulls(1, true, false).unwrap();
+    assert_eq!(0b1010, sim.pad_levels() & 0xff);
+    sim.pads_mut().drive(1, Some(false)).unwrap();
+    assert_eq!(0b1000, sim.pad_levels() & 0xff);
+
+    // with both pulls on, the bus keeper holds the last level
+    sim.pads_mut().drive(2, Some(true)).unwrap();
+    sim.gpio_set_pulls(2, true, true).unwrap();
+    sim.step();
+    sim.pads_mut().drive(2, None).unwrap();
+    assert_eq!(0b1100, sim.pad_levels() & 0xff);
+    sim.pads_mut().drive(2, Some(false)).unwrap();
+    sim.step();
+    sim.pads_mut().drive(2, None).unwrap();
+    assert_eq!(0b1000, sim.pad_levels() & 0xff);
+
+    sim.gpio_set_inover(4, gpio_override::GPIO_OVERRIDE_HIGH)
+        .unwrap();
+    sim.gpio_set_input_enabled(3, false).unwrap();
+    assert_eq!(0b1_0000, sim.pin_levels() & 0xff);
+    sim.pio_gpio_init(2).unwrap();
+    assert!(sim.pads().config(2).unwrap().is_pio());
+    assert_eq!(
+        Err(PioError::InvalidParam { which: "gpio" }),
+        sim.gpio_init(32)
+    );
+}
+
+#[test]
+fn sim_rejects_invalid_programs_on_load() {
+    let mut sim = PioSim::new();
+    let empty = pio_program::from_parts(&[], -1, 0);
+    assert_eq!(
+        Err(PioError::InvalidProgram(PioProgramError::Empty)),
+        sim.add_program_at_offset(&empty, None)
+    );
+    let newer = pio_program::from_parts(&[0xa042], -1, 2);
+    assert_eq!(
+        Err(PioError::InvalidProgram(
+            PioProgramError::UnsupportedPioVersion(2)
+        )),
+        sim.add_program_at_offset(&newer, None)
+    );
+    let fixed = pio_program::new(&[0xa042], Some(4), 0).unwrap();
+    assert_eq!(
+        Err(PioError::InvalidProgram(PioProgramError::OriginMismatch {
+            origin: 4,
+            offset: 6
+        })),
+        sim.add_program_at_offset(&fixed, Some(6))
+    );
+    assert_eq!(Ok(true), sim.can_add_program_at_offset(&fixed, Some(4)));
+}
+
+#[test]
+fn sim_pads_reject_pins_past_the_bank() {
+    let mut sim = PioSim::new();
+    let invalid = Err(PioError::InvalidParam { which: "gpio" });
+    assert_eq!(invalid, sim.pads_mut().drive(32, Some(true)));
+    assert_eq!(invalid, sim.pads().config(32).map(drop));
+    assert_eq!(invalid, sim.pads_mut().config_mut(32).map(drop));
+    assert_eq!(
+        invalid,
+        sim.pads_mut().set_stimulus(32, Stimulus::toggle(1, true))
+    );
+    assert_eq!(invalid, sim.pads_mut().clear_stimulus(32));
+    assert_eq!(invalid, sim.gpio_set_pulls(32, true, false));
+    assert_eq!((0, 0), sim.pads().external());
+}
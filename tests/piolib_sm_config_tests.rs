@@ -0,0 +1,183 @@
+use piolib_rs::include::hardware::regs::proc_pio::*;
+use piolib_rs::include::piolib::*;
+use piolib_rs::piolib::error::PioError;
+use piolib_rs::piolib::sm_config::{SmConfig, SmConfigFields};
+
+#[test]
+fn setters_pack_register_fields() {
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_out_pins(&mut c, 3, 32);
+    sm_config_set_set_pins(&mut c, 31, 5);
+    sm_config_set_in_pins(&mut c, 7);
+    sm_config_set_sideset_pins(&mut c, 9);
+    sm_config_set_sideset(&mut c, 2, true, false);
+    assert_eq!(
+        (2 << PROC_PIO_SM0_PINCTRL_SIDESET_COUNT_LSB)
+            | (5 << PROC_PIO_SM0_PINCTRL_SET_COUNT_LSB)
+            | (32 << PROC_PIO_SM0_PINCTRL_OUT_COUNT_LSB)
+            | (7 << PROC_PIO_SM0_PINCTRL_IN_BASE_LSB)
+            | (9 << PROC_PIO_SM0_PINCTRL_SIDESET_BASE_LSB)
+            | (31 << PROC_PIO_SM0_PINCTRL_SET_BASE_LSB)
+            | 3,
+        c.pinctrl()
+    );
+
+    sm_config_set_jmp_pin(&mut c, 12);
+    sm_config_set_out_special(&mut c, true, true, 17);
+    sm_config_set_mov_status(&mut c, pio_mov_status_type::STATUS_RX_LESSTHAN, 4);
+    sm_config_set_wrap(&mut c, 2, 6);
+    assert_eq!(
+        PROC_PIO_SM0_EXECCTRL_SIDE_EN_BITS
+            | (12 << PROC_PIO_SM0_EXECCTRL_JMP_PIN_LSB)
+            | (17 << PROC_PIO_SM0_EXECCTRL_OUT_EN_SEL_LSB)
+            | PROC_PIO_SM0_EXECCTRL_INLINE_OUT_EN_BITS
+            | PROC_PIO_SM0_EXECCTRL_OUT_STICKY_BITS
+            | (6 << PROC_PIO_SM0_EXECCTRL_WRAP_TOP_LSB)
+            | (2 << PROC_PIO_SM0_EXECCTRL_WRAP_BOTTOM_LSB)
+            | PROC_PIO_SM0_EXECCTRL_STATUS_SEL_BITS
+            | 4,
+        c.execctrl()
+    );
+
+    sm_config_set_in_shift(&mut c, false, true, 8);
+    sm_config_set_out_shift(&mut c, true, false, 32);
+    sm_config_set_fifo_join(&mut c, pio_fifo_join::PIO_FIFO_JOIN_RX);
+    assert_eq!(
+        PROC_PIO_SM0_SHIFTCTRL_FJOIN_RX_BITS
+            | (8 << PROC_PIO_SM0_SHIFTCTRL_PUSH_THRESH_LSB)
+            | PROC_PIO_SM0_SHIFTCTRL_OUT_SHIFTDIR_BITS
+            | PROC_PIO_SM0_SHIFTCTRL_AUTOPUSH_BITS,
+        c.shiftctrl()
+    );
+
+    sm_config_set_clkdiv_int_frac(&mut c, 3, 0x40);
+    assert_eq!(0x0003_4000, c.clkdiv());
+    sm_config_set_clkdiv(&mut c, 65536.0);
+    assert_eq!(0, c.clkdiv());
+}
+
+#[test]
+fn default_sm_config_setters() {
+    let mut c = pio_get_default_sm_config();
+    sm_config_set_wrap(&mut c, 0, 31);
+    assert_eq!(pio_get_default_sm_config(), c);
+    sm_config_set_wrap(&mut c, 4, 7);
+    sm_config_set_sideset(&mut c, 2, true, false);
+    assert_ne!(pio_get_default_sm_config(), c);
+    sm_config_set_wrap(&mut c, 0, 31);
+    sm_config_set_sideset(&mut c, 0, false, false);
+    assert_eq!(pio_get_default_sm_config(), c);
+}
+
+#[test]
+fn builder_chains_and_validates() -> Result<(), PioError> {
+    let config = SmConfig::new()
+        .out_pins(4, 8)?
+        .set_pins(4, 2)?
+        .sideset(1, false, true)?
+        .in_shift(true, true, 16)?
+        .fifo_join(pio_fifo_join::PIO_FIFO_JOIN_TX)?
+        .clkdiv(1.5)?
+        .build();
+    let mut expected = pio_get_default_sm_config();
+    sm_config_set_out_pins(&mut expected, 4, 8);
+    sm_config_set_set_pins(&mut expected, 4, 2);
+    sm_config_set_sideset(&mut expected, 1, false, true);
+    sm_config_set_in_shift(&mut expected, true, true, 16);
+    sm_config_set_fifo_join(&mut expected, pio_fifo_join::PIO_FIFO_JOIN_TX);
+    sm_config_set_clkdiv_int_frac(&mut expected, 1, 0x80);
+    assert_eq!(expected, config);
+    assert_eq!(SmConfig::from(config), SmConfig::from_config(expected));
+
+    let invalid = |which| Err(PioError::InvalidParam { which });
+    assert_eq!(invalid("out_count"), SmConfig::new().out_pins(0, 33));
+    assert_eq!(invalid("set_count"), SmConfig::new().set_pins(0, 6));
+    assert_eq!(invalid("in_base"), SmConfig::new().in_pins(32));
+    assert_eq!(invalid("optional"), SmConfig::new().sideset(0, true, false));
+    assert_eq!(invalid("div"), SmConfig::new().clkdiv(0.5));
+    assert_eq!(invalid("div"), SmConfig::new().clkdiv(f32::NAN));
+    assert_eq!(invalid("div_frac"), SmConfig::new().clkdiv_int_frac(0, 1));
+    assert_eq!(invalid("wrap"), SmConfig::new().wrap(0, 32));
+    assert_eq!(
+        invalid("pull_threshold"),
+        SmConfig::new().out_shift(false, true, 33)
+    );
+    assert_eq!(
+        invalid("status_n"),
+        SmConfig::new().mov_status(pio_mov_status_type::STATUS_TX_LESSTHAN, 32)
+    );
+    Ok(())
+}
+
+#[test]
+fn decode_round_trips_and_displays() -> Result<(), PioError> {
+    let config = SmConfig::new()
+        .out_pins(4, 8)?
+        .in_pins(2)?
+        .sideset_pins(30)?
+        .sideset(2, true, false)?
+        .wrap(3, 9)?
+        .out_shift(false, true, 8)?
+        .fifo_join(pio_fifo_join::PIO_FIFO_JOIN_TX)?
+        .mov_status(pio_mov_status_type::STATUS_RX_LESSTHAN, 2)?
+        .clkdiv_int_frac(10, 0x80)?;
+    let fields = config.decode();
+    assert_eq!((10, 0x80), (fields.clkdiv_int, fields.clkdiv_frac));
+    assert_eq!((3, 9), (fields.wrap_bottom, fields.wrap_top));
+    assert_eq!(
+        (2, true, false),
+        (fields.sideset_count, fields.side_en, fields.side_pindir)
+    );
+    assert_eq!((8, 32), (fields.pull_thresh, fields.push_thresh));
+    assert_eq!(pio_fifo_join::PIO_FIFO_JOIN_TX, fields.fifo_join);
+    assert_eq!((16, 0), fields.fifo_depths(8));
+    assert_eq!(pio_mov_status_type::STATUS_RX_LESSTHAN, fields.status_sel);
+    assert_eq!(
+        (4, 8, 2, 30),
+        (
+            fields.out_base,
+            fields.out_count,
+            fields.in_base,
+            fields.sideset_base
+        )
+    );
+    assert_eq!(Ok(config.build()), fields.encode());
+    assert_eq!(
+        SmConfigFields::from(&config.build()),
+        SmConfigFields::from_registers(
+            config.build().clkdiv(),
+            config.build().execctrl(),
+            config.build().shiftctrl(),
+            config.build().pinctrl()
+        )
+    );
+
+    assert_eq!(
+        "CLKDIV: INT=1 FRAC=0\n\
+         EXECCTRL: SIDE_EN=0 SIDE_PINDIR=0 JMP_PIN=0 OUT_EN_SEL=0 INLINE_OUT_EN=0 OUT_STICKY=0 \
+         WRAP_TOP=31 WRAP_BOTTOM=0 STATUS_SEL=0 STATUS_N=0\n\
+         SHIFTCTRL: FJOIN_RX=0 FJOIN_TX=0 PULL_THRESH=32 PUSH_THRESH=32 OUT_SHIFTDIR=1 \
+         IN_SHIFTDIR=1 AUTOPULL=0 AUTOPUSH=0\n\
+         PINCTRL: SIDESET_COUNT=0 SET_COUNT=0 OUT_COUNT=0 IN_BASE=0 SIDESET_BASE=0 SET_BASE=0 \
+         OUT_BASE=0",
+        SmConfig::new().to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn diff_reports_fields() -> Result<(), PioError> {
+    let intended = SmConfig::new().out_pins(4, 1)?.out_shift(true, true, 8)?;
+    let read_back = SmConfig::from(intended.build())
+        .out_pins(5, 1)?
+        .out_shift(true, true, 32)?;
+    let diff = intended.diff(&read_back);
+    assert_eq!("PULL_THRESH 8 -> 32, OUT_BASE 4 -> 5", diff.to_string());
+    assert_eq!(
+        ("SHIFTCTRL", "PULL_THRESH"),
+        (diff.changes()[0].register, diff.changes()[0].field)
+    );
+    assert!(intended.diff(&intended).is_empty());
+    assert_eq!("no differences", intended.diff(&intended).to_string());
+    Ok(())
+}